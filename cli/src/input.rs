@@ -0,0 +1,68 @@
+use std::{fs, io, path::Path};
+
+use crate::zip;
+
+/// Calls `visit` with the name and bytes of every class file under `path`,
+/// which may be a class file, a JAR, or a directory to search for either.
+/// JAR entries are named `archive.jar!/entry.class`.
+///
+/// A directory, archive or entry that cannot be read is passed to `visit` as
+/// an error under its own name, and the search carries on with the rest.
+pub fn for_each_class(path: &Path, visit: &mut dyn FnMut(&str, io::Result<&[u8]>)) {
+    let name = path.display().to_string();
+    if path.is_dir() {
+        let children = fs::read_dir(path).and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<io::Result<Vec<_>>>()
+        });
+        let mut children = match children {
+            Ok(children) => children,
+            Err(err) => return visit(&name, Err(err)),
+        };
+        children.sort();
+        for child in children {
+            if child.is_dir() || is_class(&child) || is_archive(&child) {
+                for_each_class(&child, visit);
+            }
+        }
+    } else if is_archive(path) {
+        let archive = match fs::read(path) {
+            Ok(archive) => archive,
+            Err(err) => return visit(&name, Err(err)),
+        };
+        let entries = match zip::entries(&archive) {
+            Ok(entries) => entries,
+            Err(err) => return visit(&name, Err(err)),
+        };
+        for entry in entries {
+            if entry.name.ends_with(".class") {
+                let name = format!("{}!/{}", name, entry.name);
+                visit_contents(&name, entry.contents(), visit);
+            }
+        }
+    } else {
+        visit_contents(&name, fs::read(path), visit);
+    }
+}
+
+fn visit_contents(
+    name: &str,
+    contents: io::Result<Vec<u8>>,
+    visit: &mut dyn FnMut(&str, io::Result<&[u8]>),
+) {
+    match contents {
+        Ok(bytes) => visit(name, Ok(&bytes)),
+        Err(err) => visit(name, Err(err)),
+    }
+}
+
+fn is_class(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "class")
+}
+
+fn is_archive(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "jar" || extension == "zip")
+}
@@ -0,0 +1,244 @@
+mod input;
+mod zip;
+
+use std::{borrow::Cow, io::Write, path::Path, process::ExitCode};
+
+use class_file_parser::{
+    bytecode::instructions, constant_pool::ConstantPoolType, disassembler, mutf8, Attribute,
+    ClassFile, ErrorKind,
+};
+use serde_json::{json, Value};
+
+const USAGE: &str = "\
+Usage: classfile <command> <path>...
+
+Paths may be class files, JARs, or directories, which are searched for both.
+
+Commands:
+  dump      disassemble like `javap -c -v -p`
+  json      print a JSON summary of each class, one per line
+  cp        list the constant pool
+  members   list fields and methods with their descriptors
+  strings   list string constants
+  validate  check that each class parses, its flags are legal and its bytecode decodes";
+
+#[derive(Clone, Copy)]
+enum Command {
+    Dump,
+    Json,
+    ConstantPool,
+    Members,
+    Strings,
+    Validate,
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let command = match args.first().map(String::as_str) {
+        Some("dump") => Command::Dump,
+        Some("json") => Command::Json,
+        Some("cp") => Command::ConstantPool,
+        Some("members") => Command::Members,
+        Some("strings") => Command::Strings,
+        Some("validate") => Command::Validate,
+        Some("-h" | "--help") => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    if args.len() < 2 {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    }
+
+    let mut stdout = std::io::stdout().lock();
+    let mut failures = 0;
+    let mut classes = 0;
+    for path in &args[1..] {
+        input::for_each_class(Path::new(path), &mut |name, bytes| {
+            let bytes = match bytes {
+                Ok(bytes) => bytes,
+                Err(err) => {
+                    failures += 1;
+                    eprintln!("classfile: {}: {}", name, err);
+                    return;
+                }
+            };
+            classes += 1;
+            let output = match command {
+                Command::Validate => validate(bytes).map(|()| format!("ok {}\n", name)),
+                _ => class_file_parser::parse(bytes)
+                    .map_err(|err| err.to_string())
+                    .and_then(|class_file| {
+                        render(command, name, &class_file).map_err(|err| err.to_string())
+                    }),
+            };
+            match output {
+                Ok(output) => {
+                    // Stop quietly when the reader goes away, e.g. `| head`.
+                    if stdout.write_all(output.as_bytes()).is_err() {
+                        std::process::exit(0);
+                    }
+                }
+                Err(err) => {
+                    failures += 1;
+                    eprintln!("classfile: {}: {}", name, err);
+                }
+            }
+        });
+    }
+    if let Command::Validate = command {
+        eprintln!("{} classes, {} failed", classes, failures);
+    }
+    if failures == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn render(command: Command, name: &str, class_file: &ClassFile) -> Result<String, ErrorKind> {
+    let pool = &class_file.constant_pool;
+    let this_class = pool.get_class_name(class_file.this_class)?;
+    Ok(match command {
+        Command::Dump => format!(
+            "Classfile {}\n{}",
+            name,
+            disassembler::disassemble(class_file)?
+        ),
+        Command::Json => format!("{}\n", summary(name, class_file)?),
+        Command::ConstantPool => format!(
+            "{}\n{}",
+            this_class,
+            disassembler::constant_pool(class_file)?
+        ),
+        Command::Members => {
+            let mut output = String::new();
+            let view = class_file.view();
+            for field in view.fields() {
+                output.push_str(&format!(
+                    "field  0x{:04x} {}.{}:{}\n",
+                    field.access_flags().0,
+                    this_class,
+                    field.name()?,
+                    field.descriptor_str()?
+                ));
+            }
+            for method in view.methods() {
+                output.push_str(&format!(
+                    "method 0x{:04x} {}.{}:{}\n",
+                    method.access_flags().0,
+                    this_class,
+                    method.name()?,
+                    method.descriptor_str()?
+                ));
+            }
+            output
+        }
+        Command::Strings => {
+            let mut output = String::new();
+            for (_, entry) in pool.iter() {
+                if let ConstantPoolType::String { string_index } = entry {
+                    let value = mutf8::decode_lossy(pool.get_utf8(*string_index)?);
+                    output.push_str(&format!("{}\t{}\n", this_class, Value::from(value)));
+                }
+            }
+            output
+        }
+        Command::Validate => unreachable!("validate does not render"),
+    })
+}
+
+/// Parses strictly, checks the access flags and decodes every method body.
+fn validate(bytes: &[u8]) -> Result<(), String> {
+    let class_file = class_file_parser::parse(bytes).map_err(|err| err.to_string())?;
+    let pool = &class_file.constant_pool;
+    let class_access = class_file.access_flags;
+    class_access.validate().map_err(|err| err.to_string())?;
+    for field in &class_file.fields {
+        field
+            .access_flags
+            .validate(class_access)
+            .map_err(|err| err.to_string())?;
+    }
+    for method in &class_file.methods {
+        let name = pool
+            .get_str(method.name_index)
+            .map_err(|err| err.to_string())?;
+        method
+            .access_flags
+            .validate(class_access, &name, class_file.version.major)
+            .map_err(|err| err.to_string())?;
+        for attribute in &method.attributes {
+            if let Attribute::Code { code, .. } = attribute {
+                for instruction in instructions(code) {
+                    instruction.map_err(|err| err.to_string())?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn summary(name: &str, class_file: &ClassFile) -> Result<Value, ErrorKind> {
+    let pool = &class_file.constant_pool;
+    let attribute_names = |attributes: &[Attribute]| {
+        attributes
+            .iter()
+            .map(|attribute| match attribute {
+                Attribute::Unknown { name_index, .. } => pool.get_str(*name_index),
+                attribute => Ok(Cow::Borrowed(attribute.tag().name().unwrap_or_default())),
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let view = class_file.view();
+    let member = |access_flags, name, descriptor, attributes: &[Attribute]| {
+        Ok(json!({
+            "name": name,
+            "descriptor": descriptor,
+            "access_flags": access_flags,
+            "attributes": attribute_names(attributes)?,
+        }))
+    };
+    let fields = view
+        .fields()
+        .map(|field| {
+            member(
+                field.access_flags().0,
+                field.name()?,
+                field.descriptor_str()?,
+                &field.field_info().attributes,
+            )
+        })
+        .collect::<Result<Vec<_>, ErrorKind>>()?;
+    let methods = view
+        .methods()
+        .map(|method| {
+            member(
+                method.access_flags().0,
+                method.name()?,
+                method.descriptor_str()?,
+                &method.method_info().attributes,
+            )
+        })
+        .collect::<Result<Vec<_>, ErrorKind>>()?;
+    Ok(json!({
+        "path": name,
+        "version": {
+            "major": class_file.version.major,
+            "minor": class_file.version.minor,
+        },
+        "access_flags": class_file.access_flags.0,
+        "this_class": view.name()?,
+        "super_class": view.super_name()?,
+        "interfaces": view.interface_names()?,
+        "constant_pool_count": pool.count(),
+        "fields": fields,
+        "methods": methods,
+        "attributes": attribute_names(&class_file.attributes)?,
+    }))
+}
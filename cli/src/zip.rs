@@ -0,0 +1,92 @@
+use std::io;
+
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x0201_4b50;
+const LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+
+/// A file in a zip archive, located through the central directory.
+pub struct Entry<'a> {
+    pub name: String,
+    method: u16,
+    data: &'a [u8],
+}
+
+impl Entry<'_> {
+    pub fn contents(&self) -> io::Result<Vec<u8>> {
+        match self.method {
+            STORED => Ok(self.data.to_vec()),
+            DEFLATED => miniz_oxide::inflate::decompress_to_vec(self.data)
+                .map_err(|err| invalid(format!("{}: {:?}", self.name, err.status))),
+            method => Err(invalid(format!(
+                "{}: unsupported compression method {}",
+                self.name, method
+            ))),
+        }
+    }
+}
+
+/// Lists the entries of a zip archive such as a JAR. Zip64 archives are not
+/// supported.
+pub fn entries(archive: &[u8]) -> io::Result<Vec<Entry<'_>>> {
+    // The end of central directory record is 22 bytes plus a comment of up
+    // to 65535 bytes.
+    let search_from = archive.len().saturating_sub(22 + 0xffff);
+    let end = (search_from..archive.len().saturating_sub(21))
+        .rev()
+        .find(|&at| u4(archive, at) == Some(END_OF_CENTRAL_DIRECTORY))
+        .ok_or_else(|| invalid("no end of central directory record".to_string()))?;
+    let count = u2(archive, end + 10).ok_or_else(truncated)?;
+    let mut at = u4(archive, end + 16).ok_or_else(truncated)? as usize;
+
+    let mut entries = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        if u4(archive, at) != Some(CENTRAL_DIRECTORY_HEADER) {
+            return Err(invalid("bad central directory header".to_string()));
+        }
+        let field = |offset| u2(archive, at + offset).ok_or_else(truncated);
+        let method = field(10)?;
+        let compressed_size = u4(archive, at + 20).ok_or_else(truncated)? as usize;
+        let name_len = field(28)? as usize;
+        let extra_len = field(30)? as usize;
+        let comment_len = field(32)? as usize;
+        let local_header = u4(archive, at + 42).ok_or_else(truncated)? as usize;
+        let name = archive
+            .get(at + 46..at + 46 + name_len)
+            .ok_or_else(truncated)?;
+        let name = String::from_utf8_lossy(name).into_owned();
+        at += 46 + name_len + extra_len + comment_len;
+
+        if u4(archive, local_header) != Some(LOCAL_FILE_HEADER) {
+            return Err(invalid(format!("{}: bad local file header", name)));
+        }
+        let local_name_len = u2(archive, local_header + 26).ok_or_else(truncated)? as usize;
+        let local_extra_len = u2(archive, local_header + 28).ok_or_else(truncated)? as usize;
+        let start = local_header + 30 + local_name_len + local_extra_len;
+        let data = archive
+            .get(start..start + compressed_size)
+            .ok_or_else(truncated)?;
+        entries.push(Entry { name, method, data });
+    }
+    Ok(entries)
+}
+
+fn u2(bytes: &[u8], at: usize) -> Option<u16> {
+    let bytes = bytes.get(at..at + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn u4(bytes: &[u8], at: usize) -> Option<u32> {
+    let bytes = bytes.get(at..at + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn truncated() -> io::Error {
+    invalid("truncated zip archive".to_string())
+}
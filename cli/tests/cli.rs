@@ -0,0 +1,205 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+const SUBCOMMANDS: [&str; 6] = ["dump", "json", "cp", "members", "strings", "validate"];
+
+fn corpus() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus/classes/release-17")
+}
+
+fn classfile(args: &[&Path], command: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_classfile"))
+        .arg(command)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn scratch(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Every class file under `dir` as a path relative to it and its bytes.
+fn classes(dir: &Path) -> Vec<(String, Vec<u8>)> {
+    fn walk(root: &Path, dir: &Path, classes: &mut Vec<(String, Vec<u8>)>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                walk(root, &path, classes);
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "class")
+            {
+                let name = path.strip_prefix(root).unwrap().to_str().unwrap();
+                classes.push((name.replace('\\', "/"), fs::read(&path).unwrap()));
+            }
+        }
+    }
+
+    let mut classes = Vec::new();
+    walk(dir, dir, &mut classes);
+    classes.sort();
+    classes
+}
+
+/// Writes a zip archive with the given entries and compression methods.
+fn write_zip(path: &Path, entries: &[(&str, &[u8], u16)]) {
+    let mut archive = Vec::new();
+    let mut central_directory = Vec::new();
+    for &(name, contents, method) in entries {
+        let data = match method {
+            8 => miniz_oxide::deflate::compress_to_vec(contents, 6),
+            _ => contents.to_vec(),
+        };
+        let mut header = Vec::new();
+        header.extend_from_slice(&20u16.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&method.to_le_bytes());
+        header.extend_from_slice(&[0; 4]);
+        header.extend_from_slice(&crc32(contents).to_le_bytes());
+        header.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        header.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+        header.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+
+        central_directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central_directory.extend_from_slice(&20u16.to_le_bytes());
+        central_directory.extend_from_slice(&header);
+        central_directory.extend_from_slice(&[0; 10]);
+        central_directory.extend_from_slice(&(archive.len() as u32).to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+
+        archive.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        archive.extend_from_slice(&header);
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(&data);
+    }
+    let offset = archive.len() as u32;
+    archive.extend_from_slice(&central_directory);
+    archive.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    archive.extend_from_slice(&[0; 4]);
+    archive.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&(central_directory.len() as u32).to_le_bytes());
+    archive.extend_from_slice(&offset.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes());
+    fs::write(path, archive).unwrap();
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Packs the corpus into a JAR, deflating every other entry.
+fn corpus_jar(dir: &Path) -> PathBuf {
+    let classes = classes(&corpus());
+    let entries = classes
+        .iter()
+        .enumerate()
+        .map(|(i, (name, bytes))| {
+            (
+                name.as_str(),
+                bytes.as_slice(),
+                if i % 2 == 0 { 8 } else { 0 },
+            )
+        })
+        .collect::<Vec<_>>();
+    let jar = dir.join("corpus.jar");
+    write_zip(&jar, &entries);
+    jar
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+#[test]
+fn every_subcommand_reads_directories_and_jars_alike() {
+    let dir = scratch("every_subcommand");
+    let jar = corpus_jar(&dir);
+    let corpus = corpus();
+    let classes = classes(&corpus).len();
+    for command in SUBCOMMANDS {
+        let from_dir = classfile(&[&corpus], command);
+        let from_jar = classfile(&[&jar], command);
+        for output in [&from_dir, &from_jar] {
+            assert!(output.status.success(), "{}: {}", command, stderr(output));
+            assert!(!output.stdout.is_empty(), "{} printed nothing", command);
+        }
+        // The outputs differ only in the names of the classes.
+        let from_dir = stdout(&from_dir).replace(&format!("{}/", corpus.display()), "");
+        let from_jar = stdout(&from_jar).replace(&format!("{}!/", jar.display()), "");
+        assert_eq!(from_dir, from_jar, "{}", command);
+    }
+
+    let validated = classfile(&[&corpus, &jar], "validate");
+    assert_eq!(
+        stderr(&validated),
+        format!("{} classes, 0 failed\n", 2 * classes)
+    );
+}
+
+#[test]
+fn unreadable_inputs_are_reported_one_by_one() {
+    let dir = scratch("unreadable_inputs");
+    let classes = classes(&corpus());
+    let (_, bytes) = &classes[0];
+    fs::write(dir.join("Good.class"), bytes).unwrap();
+    fs::write(dir.join("Truncated.class"), &bytes[..bytes.len() / 2]).unwrap();
+    fs::write(dir.join("broken.jar"), b"not a zip archive").unwrap();
+    write_zip(
+        &dir.join("mixed.jar"),
+        &[
+            ("First.class", bytes, 0),
+            ("Imploded.class", bytes, 6),
+            ("Last.class", bytes, 8),
+        ],
+    );
+
+    for command in SUBCOMMANDS {
+        let output = classfile(&[&dir], command);
+        assert_eq!(output.status.code(), Some(1), "{}", command);
+        let stderr = stderr(&output);
+        for failure in [
+            "Truncated.class: ",
+            "broken.jar: no end of central directory record",
+            "mixed.jar!/Imploded.class: Imploded.class: unsupported compression method 6",
+        ] {
+            assert!(stderr.contains(failure), "{}: {}", command, stderr);
+        }
+        assert!(!stderr.contains("Good.class"), "{}: {}", command, stderr);
+        assert!(!stderr.contains("First.class"), "{}: {}", command, stderr);
+        assert!(!stderr.contains("Last.class"), "{}: {}", command, stderr);
+    }
+
+    let validated = classfile(&[&dir], "validate");
+    let stdout = stdout(&validated);
+    for good in [
+        "Good.class",
+        "mixed.jar!/First.class",
+        "mixed.jar!/Last.class",
+    ] {
+        assert!(stdout.contains(good), "{} was not validated", good);
+    }
+    assert_eq!(
+        stderr(&validated).lines().last(),
+        Some("4 classes, 3 failed")
+    );
+}
@@ -0,0 +1,526 @@
+use std::ops::BitOr;
+
+use crate::{error::ErrorKind, U2};
+
+/// Defines a set of flags over a `U2`, with a constant and a predicate for
+/// each flag.
+macro_rules! flags {
+    (
+        $(#[$attr:meta])*
+        $ty:ident {
+            $($flag:ident = $bit:literal, $predicate:ident;)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $ty(pub U2);
+
+        impl $ty {
+            $(pub const $flag: Self = Self($bit);)*
+
+            /// Every flag with its name in the JVMS, e.g. `ACC_PUBLIC`, in bit
+            /// order.
+            pub const NAMES: &'static [(Self, &'static str)] =
+                &[$((Self::$flag, concat!("ACC_", stringify!($flag))),)*];
+
+            /// Whether all of `flags` are set.
+            pub fn contains(self, flags: Self) -> bool {
+                self.0 & flags.0 == flags.0
+            }
+
+            /// Whether any of `flags` is set.
+            pub fn intersects(self, flags: Self) -> bool {
+                self.0 & flags.0 != 0
+            }
+
+            $(
+                pub fn $predicate(self) -> bool {
+                    self.contains(Self::$flag)
+                }
+            )*
+        }
+
+        impl BitOr for $ty {
+            type Output = Self;
+
+            fn bitor(self, flags: Self) -> Self {
+                Self(self.0 | flags.0)
+            }
+        }
+
+        impl From<U2> for $ty {
+            fn from(bits: U2) -> Self {
+                Self(bits)
+            }
+        }
+
+        impl From<$ty> for U2 {
+            fn from(flags: $ty) -> Self {
+                flags.0
+            }
+        }
+    };
+}
+
+flags! {
+    /// The `access_flags` of a class file (JVMS §4.1).
+    ClassAccess {
+        PUBLIC = 0x0001, is_public;
+        FINAL = 0x0010, is_final;
+        SUPER = 0x0020, is_super;
+        INTERFACE = 0x0200, is_interface;
+        ABSTRACT = 0x0400, is_abstract;
+        SYNTHETIC = 0x1000, is_synthetic;
+        ANNOTATION = 0x2000, is_annotation;
+        ENUM = 0x4000, is_enum;
+        MODULE = 0x8000, is_module;
+    }
+}
+
+flags! {
+    /// The `access_flags` of a field (JVMS §4.5).
+    FieldAccess {
+        PUBLIC = 0x0001, is_public;
+        PRIVATE = 0x0002, is_private;
+        PROTECTED = 0x0004, is_protected;
+        STATIC = 0x0008, is_static;
+        FINAL = 0x0010, is_final;
+        VOLATILE = 0x0040, is_volatile;
+        TRANSIENT = 0x0080, is_transient;
+        SYNTHETIC = 0x1000, is_synthetic;
+        ENUM = 0x4000, is_enum;
+    }
+}
+
+flags! {
+    /// The `access_flags` of a method (JVMS §4.6).
+    MethodAccess {
+        PUBLIC = 0x0001, is_public;
+        PRIVATE = 0x0002, is_private;
+        PROTECTED = 0x0004, is_protected;
+        STATIC = 0x0008, is_static;
+        FINAL = 0x0010, is_final;
+        SYNCHRONIZED = 0x0020, is_synchronized;
+        BRIDGE = 0x0040, is_bridge;
+        VARARGS = 0x0080, is_varargs;
+        NATIVE = 0x0100, is_native;
+        ABSTRACT = 0x0400, is_abstract;
+        STRICT = 0x0800, is_strict;
+        SYNTHETIC = 0x1000, is_synthetic;
+    }
+}
+
+flags! {
+    /// The `inner_class_access_flags` of an `InnerClasses` entry.
+    InnerClassAccess {
+        PUBLIC = 0x0001, is_public;
+        PRIVATE = 0x0002, is_private;
+        PROTECTED = 0x0004, is_protected;
+        STATIC = 0x0008, is_static;
+        FINAL = 0x0010, is_final;
+        INTERFACE = 0x0200, is_interface;
+        ABSTRACT = 0x0400, is_abstract;
+        SYNTHETIC = 0x1000, is_synthetic;
+        ANNOTATION = 0x2000, is_annotation;
+        ENUM = 0x4000, is_enum;
+    }
+}
+
+flags! {
+    /// The `access_flags` of a `MethodParameters` entry.
+    ParameterAccess {
+        FINAL = 0x0010, is_final;
+        SYNTHETIC = 0x1000, is_synthetic;
+        MANDATED = 0x8000, is_mandated;
+    }
+}
+
+flags! {
+    /// The `module_flags` of a `Module` attribute.
+    ModuleFlags {
+        OPEN = 0x0020, is_open;
+        SYNTHETIC = 0x1000, is_synthetic;
+        MANDATED = 0x8000, is_mandated;
+    }
+}
+
+flags! {
+    /// The `requires_flags` of a module dependence.
+    RequiresFlags {
+        TRANSITIVE = 0x0020, is_transitive;
+        STATIC_PHASE = 0x0040, is_static_phase;
+        SYNTHETIC = 0x1000, is_synthetic;
+        MANDATED = 0x8000, is_mandated;
+    }
+}
+
+flags! {
+    /// The `exports_flags` or `opens_flags` of a module's package.
+    ExportsFlags {
+        SYNTHETIC = 0x1000, is_synthetic;
+        MANDATED = 0x8000, is_mandated;
+    }
+}
+
+impl ClassAccess {
+    /// Renders the modifiers in Java syntax, in the order javap prints them,
+    /// e.g. `public final`. Interfaces are implicitly abstract.
+    pub fn to_java(self) -> String {
+        let flags = if self.is_interface() {
+            self.0 & !Self::ABSTRACT.0
+        } else {
+            self.0
+        };
+        keywords(
+            flags,
+            &[(0x0001, "public"), (0x0010, "final"), (0x0400, "abstract")],
+        )
+    }
+
+    /// Checks the combination of flags against JVMS §4.1.
+    pub fn validate(self) -> Result<(), ErrorKind> {
+        if self.is_module() && self != Self::MODULE {
+            return Err(illegal(self.0, "a module has no other flags"));
+        }
+        if self.is_interface() {
+            if !self.is_abstract() {
+                return Err(illegal(self.0, "an interface must be abstract"));
+            }
+            if self.intersects(Self::FINAL | Self::SUPER | Self::ENUM) {
+                return Err(illegal(
+                    self.0,
+                    "an interface cannot be final, super or an enum",
+                ));
+            }
+        } else {
+            if self.is_annotation() {
+                return Err(illegal(self.0, "an annotation must be an interface"));
+            }
+            if self.contains(Self::FINAL | Self::ABSTRACT) {
+                return Err(illegal(self.0, "a class cannot be final and abstract"));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FieldAccess {
+    /// Renders the modifiers in Java syntax, in the order javap prints them,
+    /// e.g. `public static final`.
+    pub fn to_java(self) -> String {
+        keywords(
+            self.0,
+            &[
+                (0x0001, "public"),
+                (0x0002, "private"),
+                (0x0004, "protected"),
+                (0x0008, "static"),
+                (0x0010, "final"),
+                (0x0040, "volatile"),
+                (0x0080, "transient"),
+            ],
+        )
+    }
+
+    /// Checks the combination of flags against JVMS §4.5, for a field of a
+    /// class with the flags `class`.
+    pub fn validate(self, class: ClassAccess) -> Result<(), ErrorKind> {
+        check_visibility(self.0)?;
+        if self.contains(Self::FINAL | Self::VOLATILE) {
+            return Err(illegal(self.0, "a field cannot be final and volatile"));
+        }
+        let constant = Self::PUBLIC | Self::STATIC | Self::FINAL;
+        if class.is_interface()
+            && (!self.contains(constant) || self.intersects(Self(!(constant | Self::SYNTHETIC).0)))
+        {
+            return Err(illegal(
+                self.0,
+                "an interface field must be public static final",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl MethodAccess {
+    /// Renders the modifiers in Java syntax, in the order javap prints them,
+    /// e.g. `public static synchronized`.
+    pub fn to_java(self) -> String {
+        keywords(
+            self.0,
+            &[
+                (0x0001, "public"),
+                (0x0002, "private"),
+                (0x0004, "protected"),
+                (0x0008, "static"),
+                (0x0010, "final"),
+                (0x0020, "synchronized"),
+                (0x0100, "native"),
+                (0x0400, "abstract"),
+                (0x0800, "strictfp"),
+            ],
+        )
+    }
+
+    /// Checks the combination of flags against JVMS §4.6, for a method named
+    /// `name` of a class with the flags `class` and the major version
+    /// `major_version`.
+    pub fn validate(
+        self,
+        class: ClassAccess,
+        name: &str,
+        major_version: U2,
+    ) -> Result<(), ErrorKind> {
+        // the JVM ignores the flags of class initializers
+        if name == "<clinit>" {
+            return Ok(());
+        }
+        check_visibility(self.0)?;
+        if name == "<init>" {
+            let allowed = Self::PUBLIC
+                | Self::PRIVATE
+                | Self::PROTECTED
+                | Self::VARARGS
+                | Self::STRICT
+                | Self::SYNTHETIC;
+            if self.intersects(Self(!allowed.0)) {
+                return Err(illegal(
+                    self.0,
+                    "an instance initializer can only be varargs, strict or synthetic",
+                ));
+            }
+        }
+        if class.is_interface() {
+            if major_version < 52 {
+                let allowed =
+                    Self::PUBLIC | Self::ABSTRACT | Self::BRIDGE | Self::VARARGS | Self::SYNTHETIC;
+                if !self.contains(Self::PUBLIC | Self::ABSTRACT)
+                    || self.intersects(Self(!allowed.0))
+                {
+                    return Err(illegal(
+                        self.0,
+                        "an interface method before version 52 must be public abstract",
+                    ));
+                }
+            } else {
+                if self
+                    .intersects(Self::PROTECTED | Self::FINAL | Self::SYNCHRONIZED | Self::NATIVE)
+                {
+                    return Err(illegal(
+                        self.0,
+                        "an interface method cannot be protected, final, synchronized or native",
+                    ));
+                }
+                if !self.intersects(Self::PUBLIC | Self::PRIVATE) {
+                    return Err(illegal(
+                        self.0,
+                        "an interface method must be public or private",
+                    ));
+                }
+            }
+        }
+        if self.is_abstract() {
+            let mut forbidden =
+                Self::PRIVATE | Self::STATIC | Self::FINAL | Self::SYNCHRONIZED | Self::NATIVE;
+            if (46..=60).contains(&major_version) {
+                forbidden = forbidden | Self::STRICT;
+            }
+            if self.intersects(forbidden) {
+                return Err(illegal(
+                    self.0,
+                    "an abstract method cannot be private, static, final, synchronized, native or strict",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InnerClassAccess {
+    /// Renders the modifiers in Java syntax, in the order javap prints them,
+    /// e.g. `public static final`. Interfaces are implicitly abstract.
+    pub fn to_java(self) -> String {
+        let flags = if self.is_interface() {
+            self.0 & !Self::ABSTRACT.0
+        } else {
+            self.0
+        };
+        keywords(
+            flags,
+            &[
+                (0x0001, "public"),
+                (0x0002, "private"),
+                (0x0004, "protected"),
+                (0x0008, "static"),
+                (0x0010, "final"),
+                (0x0400, "abstract"),
+            ],
+        )
+    }
+}
+
+fn keywords(flags: U2, table: &[(U2, &str)]) -> String {
+    table
+        .iter()
+        .filter(|&&(flag, _)| flags & flag != 0)
+        .map(|&(_, keyword)| keyword)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Fields and methods share the bits of `ACC_PUBLIC`, `ACC_PRIVATE` and
+/// `ACC_PROTECTED`, of which at most one may be set.
+fn check_visibility(flags: U2) -> Result<(), ErrorKind> {
+    if (flags & 0x0007).count_ones() > 1 {
+        return Err(illegal(
+            flags,
+            "at most one of public, private and protected may be set",
+        ));
+    }
+    Ok(())
+}
+
+fn illegal(flags: U2, reason: &'static str) -> ErrorKind {
+    ErrorKind::IllegalAccessFlags { flags, reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLASS: ClassAccess = ClassAccess(0x0021);
+    const INTERFACE: ClassAccess = ClassAccess(0x0601);
+
+    #[test]
+    fn class_flags() {
+        for (flags, legal) in [
+            (0x0021, true),  // public super
+            (0x0031, true),  // public final super
+            (0x0421, true),  // public abstract super
+            (0x4031, true),  // public final super enum
+            (0x0601, true),  // public interface abstract
+            (0x2601, true),  // public annotation
+            (0x8000, true),  // module
+            (0x0000, true),  // package-private, as before Java 1.1
+            (0x0431, false), // final abstract
+            (0x0201, false), // interface without abstract
+            (0x0611, false), // final interface
+            (0x0621, false), // super interface
+            (0x4601, false), // enum interface
+            (0x2421, false), // annotation that is a class
+            (0x8001, false), // public module
+        ] {
+            assert_eq!(
+                ClassAccess(flags).validate().is_ok(),
+                legal,
+                "{:#06x}",
+                flags
+            );
+        }
+    }
+
+    #[test]
+    fn field_flags() {
+        for (class, flags, legal) in [
+            (CLASS, 0x0000, true),
+            (CLASS, 0x0002, true),  // private
+            (CLASS, 0x001a, true),  // private static final
+            (CLASS, 0x0044, true),  // protected volatile
+            (CLASS, 0x4019, true),  // public static final enum
+            (CLASS, 0x1010, true),  // final synthetic
+            (CLASS, 0x0003, false), // public private
+            (CLASS, 0x0006, false), // private protected
+            (CLASS, 0x0007, false),
+            (CLASS, 0x0050, false),     // final volatile
+            (INTERFACE, 0x0019, true),  // public static final
+            (INTERFACE, 0x1019, true),  // public static final synthetic
+            (INTERFACE, 0x0009, false), // not final
+            (INTERFACE, 0x001a, false), // private
+            (INTERFACE, 0x0099, false), // transient
+        ] {
+            assert_eq!(
+                FieldAccess(flags).validate(class).is_ok(),
+                legal,
+                "{:?} {:#06x}",
+                class,
+                flags
+            );
+        }
+    }
+
+    #[test]
+    fn method_flags() {
+        for (class, name, major, flags, legal) in [
+            (CLASS, "run", 52, 0x0001, true),
+            (CLASS, "run", 52, 0x0029, true), // public static synchronized
+            (CLASS, "run", 52, 0x0401, true), // public abstract
+            (CLASS, "run", 52, 0x0101, true), // public native
+            (CLASS, "run", 52, 0x1041, true), // public bridge synthetic
+            (CLASS, "run", 52, 0x0003, false), // public private
+            (CLASS, "run", 52, 0x0402, false), // private abstract
+            (CLASS, "run", 52, 0x0408, false), // static abstract
+            (CLASS, "run", 52, 0x0410, false), // final abstract
+            (CLASS, "run", 52, 0x0420, false), // synchronized abstract
+            (CLASS, "run", 52, 0x0500, false), // native abstract
+            // strictfp is only meaningful from version 46 to 60.
+            (CLASS, "run", 45, 0x0c01, true),
+            (CLASS, "run", 46, 0x0c01, false),
+            (CLASS, "run", 52, 0x0c01, false),
+            (CLASS, "run", 60, 0x0c01, false),
+            (CLASS, "run", 61, 0x0c01, true),
+            (CLASS, "run", 52, 0x0801, true),
+            // Instance initializers.
+            (CLASS, "<init>", 52, 0x0001, true),
+            (CLASS, "<init>", 52, 0x1882, true), // private varargs strict synthetic
+            (CLASS, "<init>", 52, 0x0009, false),
+            (CLASS, "<init>", 52, 0x0011, false),
+            (CLASS, "<init>", 52, 0x0401, false),
+            (CLASS, "<init>", 52, 0x0003, false),
+            // The JVM ignores the flags of class initializers.
+            (CLASS, "<clinit>", 52, 0x0008, true),
+            (CLASS, "<clinit>", 52, 0x0413, true),
+            // Interface methods before version 52 are public abstract.
+            (INTERFACE, "run", 51, 0x0401, true),
+            (INTERFACE, "run", 51, 0x04c1, true), // bridge varargs
+            (INTERFACE, "run", 51, 0x0001, false),
+            (INTERFACE, "run", 51, 0x0009, false),
+            (INTERFACE, "run", 51, 0x0402, false),
+            (INTERFACE, "run", 51, 0x0c01, false),
+            // From version 52 they may have bodies, be static or be private.
+            (INTERFACE, "run", 52, 0x0401, true),
+            (INTERFACE, "run", 52, 0x0001, true), // default method
+            (INTERFACE, "run", 52, 0x0009, true),
+            (INTERFACE, "run", 52, 0x0002, true),
+            (INTERFACE, "run", 52, 0x000a, true),
+            (INTERFACE, "run", 52, 0x0000, false),
+            (INTERFACE, "run", 52, 0x0004, false),
+            (INTERFACE, "run", 52, 0x0011, false),
+            (INTERFACE, "run", 52, 0x0021, false),
+            (INTERFACE, "run", 52, 0x0101, false),
+            (INTERFACE, "run", 52, 0x0402, false),
+            (INTERFACE, "run", 52, 0x0c01, false),
+            (INTERFACE, "run", 61, 0x0c01, true),
+        ] {
+            assert_eq!(
+                MethodAccess(flags).validate(class, name, major).is_ok(),
+                legal,
+                "{:?} {} {} {:#06x}",
+                class,
+                name,
+                major,
+                flags
+            );
+        }
+    }
+
+    #[test]
+    fn errors_carry_the_flags_and_a_reason() {
+        assert_eq!(
+            FieldAccess(0x0050).validate(CLASS),
+            Err(ErrorKind::IllegalAccessFlags {
+                flags: 0x0050,
+                reason: "a field cannot be final and volatile",
+            })
+        );
+    }
+}
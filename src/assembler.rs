@@ -0,0 +1,706 @@
+use std::collections::HashMap;
+
+use crate::{
+    bytecode::{instructions, switch_padding, Instruction},
+    error::{ClassFileError, ErrorKind},
+    Attribute, ExceptionHandler, LineNumber, LocalVariable, U1, U2,
+};
+
+/// A symbolic position in the code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Label(pub u32);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    /// Marks the position of the next instruction.
+    Label(Label),
+    Instruction(Instruction<Label>),
+}
+
+/// The result of [`assemble`].
+#[derive(Debug, Clone)]
+pub struct Assembled {
+    pub code: Vec<u8>,
+    labels: HashMap<Label, u32>,
+}
+
+/// Decodes `code` into items that [`assemble`] accepts. Every instruction is
+/// preceded by a label naming its original offset, and a final label names
+/// the end of the code, so `Label(n)` always refers to original offset `n`.
+pub fn items_from_code(code: &[u8]) -> Result<Vec<Item>, ClassFileError> {
+    let mut items = Vec::new();
+    for decoded in instructions(code) {
+        let (pc, instruction) = decoded?;
+        let instruction =
+            instruction.map_targets(|target| Ok::<_, ClassFileError>(Label(target)))?;
+        items.push(Item::Label(Label(pc)));
+        items.push(Item::Instruction(instruction));
+    }
+    items.push(Item::Label(Label(code.len() as u32)));
+    Ok(items)
+}
+
+/// Lays out `items` and encodes them into a code array.
+///
+/// Loads, stores and `iinc` get the `wide` prefix and `ldc` becomes `ldc_w`
+/// when their operands need it. A `goto` or `jsr` whose target is out of
+/// 16-bit range becomes `goto_w` or `jsr_w`, and a conditional branch becomes
+/// the inverted condition jumping over a `goto_w` to the target.
+pub fn assemble(items: &[Item]) -> Result<Assembled, ErrorKind> {
+    for item in items {
+        if let Item::Instruction(instruction) = item {
+            check_switch(instruction)?;
+        }
+    }
+    let mut widened = vec![false; items.len()];
+    loop {
+        let Layout {
+            offsets,
+            labels,
+            len,
+        } = layout(items, &widened)?;
+        let mut changed = false;
+        for (i, item) in items.iter().enumerate() {
+            let target = match item {
+                Item::Instruction(instruction) if !widened[i] => short_branch_target(instruction),
+                _ => None,
+            };
+            if let Some(target) = target {
+                let offset = resolve(&labels, *target)? as i64 - offsets[i] as i64;
+                if i16::try_from(offset).is_err() {
+                    widened[i] = true;
+                    changed = true;
+                }
+            }
+        }
+        if changed {
+            continue;
+        }
+        if len > U2::MAX as u32 {
+            return Err(ErrorKind::CodeTooLarge(len));
+        }
+        let mut code = Vec::with_capacity(len as usize);
+        for (i, item) in items.iter().enumerate() {
+            if let Item::Instruction(instruction) = item {
+                let instruction = instruction
+                    .clone()
+                    .map_targets(|label| resolve(&labels, label))?;
+                encode(&instruction, offsets[i], widened[i], &mut code);
+            }
+        }
+        return Ok(Assembled { code, labels });
+    }
+}
+
+impl Assembled {
+    pub fn offset(&self, label: Label) -> Option<u32> {
+        self.labels.get(&label).copied()
+    }
+
+    /// Maps an offset in the original code to its offset in the new code.
+    /// This relies on the labelling of [`items_from_code`].
+    pub fn remap(&self, offset: U2) -> Result<U2, ErrorKind> {
+        resolve(&self.labels, Label(offset as u32)).map(|offset| offset as U2)
+    }
+
+    pub fn remap_exception_table(&self, table: &mut [ExceptionHandler]) -> Result<(), ErrorKind> {
+        for handler in table {
+            handler.start_pc = self.remap(handler.start_pc)?;
+            handler.end_pc = self.remap(handler.end_pc)?;
+            handler.handler_pc = self.remap(handler.handler_pc)?;
+        }
+        Ok(())
+    }
+
+    pub fn remap_line_numbers(&self, table: &mut [LineNumber]) -> Result<(), ErrorKind> {
+        for line_number in table {
+            line_number.start_pc = self.remap(line_number.start_pc)?;
+        }
+        Ok(())
+    }
+
+    /// Remaps the range of each local variable. Fails if the end of a range
+    /// is not an original offset or comes before its start in the new code.
+    pub fn remap_local_variables(&self, table: &mut [LocalVariable]) -> Result<(), ErrorKind> {
+        for local_variable in table {
+            let start = self.remap(local_variable.start_pc)?;
+            let end = u32::from(local_variable.start_pc) + u32::from(local_variable.length);
+            let end = resolve(&self.labels, Label(end))?;
+            let length =
+                end.checked_sub(u32::from(start))
+                    .ok_or(ErrorKind::InvalidLocalVariableRange {
+                        start: u32::from(start),
+                        end,
+                    })?;
+            local_variable.start_pc = start;
+            local_variable.length = length as U2;
+        }
+        Ok(())
+    }
+
+    /// Remaps the `LineNumberTable`, `LocalVariableTable` and
+    /// `LocalVariableTypeTable` among the attributes of a `Code` attribute.
+    /// Any `StackMapTable` has to be recomputed separately.
+    pub fn remap_code_attributes(&self, attributes: &mut [Attribute]) -> Result<(), ErrorKind> {
+        for attribute in attributes {
+            match attribute {
+                Attribute::LineNumberTable { line_number_table } => {
+                    self.remap_line_numbers(line_number_table)?
+                }
+                Attribute::LocalVariableTable {
+                    local_variable_table,
+                } => self.remap_local_variables(local_variable_table)?,
+                Attribute::LocalVariableTypeTable {
+                    local_variable_type_table,
+                } => self.remap_local_variables(local_variable_type_table)?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The offset of every item and label, and the total code length.
+struct Layout {
+    offsets: Vec<u32>,
+    labels: HashMap<Label, u32>,
+    len: u32,
+}
+
+/// Computes the layout of `items`, given which branches have been widened.
+fn layout(items: &[Item], widened: &[bool]) -> Result<Layout, ErrorKind> {
+    let mut offsets = Vec::with_capacity(items.len());
+    let mut labels = HashMap::new();
+    let mut pc = 0;
+    for (i, item) in items.iter().enumerate() {
+        offsets.push(pc);
+        match item {
+            Item::Label(label) => {
+                if labels.insert(*label, pc).is_some() {
+                    return Err(ErrorKind::DuplicateLabel(label.0));
+                }
+            }
+            Item::Instruction(instruction) => pc += encoded_len(instruction, pc, widened[i]),
+        }
+    }
+    Ok(Layout {
+        offsets,
+        labels,
+        len: pc,
+    })
+}
+
+/// Checks that a `tableswitch` has one target per key from `low` to `high`
+/// and that the keys of a `lookupswitch` are strictly increasing.
+fn check_switch<T>(instruction: &Instruction<T>) -> Result<(), ErrorKind> {
+    match instruction {
+        Instruction::Tableswitch {
+            low, high, targets, ..
+        } if high < low || targets.len() as i64 != *high as i64 - *low as i64 + 1 => {
+            Err(ErrorKind::InvalidTableSwitch {
+                low: *low,
+                high: *high,
+            })
+        }
+        Instruction::Lookupswitch { pairs, .. }
+            if pairs.windows(2).any(|pair| pair[0].0 >= pair[1].0) =>
+        {
+            Err(ErrorKind::InvalidLookupSwitch {
+                npairs: pairs.len() as i32,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+fn resolve(labels: &HashMap<Label, u32>, label: Label) -> Result<u32, ErrorKind> {
+    labels
+        .get(&label)
+        .copied()
+        .ok_or(ErrorKind::UndefinedLabel(label.0))
+}
+
+/// The target of a branch with a 16-bit offset.
+fn short_branch_target<T>(instruction: &Instruction<T>) -> Option<&T> {
+    use Instruction::*;
+
+    match instruction {
+        Ifeq { target }
+        | Ifne { target }
+        | Iflt { target }
+        | Ifge { target }
+        | Ifgt { target }
+        | Ifle { target }
+        | IfIcmpeq { target }
+        | IfIcmpne { target }
+        | IfIcmplt { target }
+        | IfIcmpge { target }
+        | IfIcmpgt { target }
+        | IfIcmple { target }
+        | IfAcmpeq { target }
+        | IfAcmpne { target }
+        | Goto { target }
+        | Jsr { target }
+        | Ifnull { target }
+        | Ifnonnull { target } => Some(target),
+        _ => None,
+    }
+}
+
+/// The conditional branch opcode testing the opposite condition.
+fn inverted_branch(opcode: U1) -> U1 {
+    match opcode {
+        // ifeq..if_acmpne come in complementary pairs starting at ifeq
+        0x99..=0xa6 => ((opcode - 0x99) ^ 1) + 0x99,
+        // ifnull <-> ifnonnull
+        _ => opcode ^ 1,
+    }
+}
+
+fn encoded_len<T>(instruction: &Instruction<T>, pc: u32, widened: bool) -> u32 {
+    use Instruction::*;
+
+    match instruction {
+        Bipush { .. } | Newarray { .. } => 2,
+        Sipush { .. } | LdcW { .. } | Ldc2W { .. } => 3,
+        Ldc { index } => {
+            if *index <= 0xff {
+                2
+            } else {
+                3
+            }
+        }
+        Getstatic { .. }
+        | Putstatic { .. }
+        | Getfield { .. }
+        | Putfield { .. }
+        | Invokevirtual { .. }
+        | Invokespecial { .. }
+        | Invokestatic { .. }
+        | New { .. }
+        | Anewarray { .. }
+        | Checkcast { .. }
+        | Instanceof { .. } => 3,
+        Iload { index }
+        | Lload { index }
+        | Fload { index }
+        | Dload { index }
+        | Aload { index }
+        | Istore { index }
+        | Lstore { index }
+        | Fstore { index }
+        | Dstore { index }
+        | Astore { index }
+        | Ret { index } => {
+            if *index <= 0xff {
+                2
+            } else {
+                4
+            }
+        }
+        Iinc { index, value } => {
+            if *index <= 0xff && i8::try_from(*value).is_ok() {
+                3
+            } else {
+                6
+            }
+        }
+        Multianewarray { .. } => 4,
+        Invokeinterface { .. } | Invokedynamic { .. } | GotoW { .. } | JsrW { .. } => 5,
+        Tableswitch { targets, .. } => {
+            1 + switch_padding(pc as usize) as u32 + 12 + 4 * targets.len() as u32
+        }
+        Lookupswitch { pairs, .. } => {
+            1 + switch_padding(pc as usize) as u32 + 8 + 8 * pairs.len() as u32
+        }
+        Goto { .. } | Jsr { .. } if widened => 5,
+        _ if short_branch_target(instruction).is_some() => {
+            if widened {
+                8
+            } else {
+                3
+            }
+        }
+        _ => 1,
+    }
+}
+
+fn encode(instruction: &Instruction, pc: u32, widened: bool, out: &mut Vec<u8>) {
+    use Instruction::*;
+
+    let relative = |target: u32| (target as i64 - pc as i64) as i32;
+    let opcode = instruction.opcode();
+    match instruction {
+        Bipush { value } => out.extend_from_slice(&[opcode, *value as u8]),
+        Sipush { value } => {
+            out.push(opcode);
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+        Ldc { index } if *index <= 0xff => out.extend_from_slice(&[opcode, *index as u8]),
+        Ldc { index } | LdcW { index } => {
+            // ldc_w
+            out.push(0x13);
+            out.extend_from_slice(&index.to_be_bytes());
+        }
+        Ldc2W { index }
+        | Getstatic { index }
+        | Putstatic { index }
+        | Getfield { index }
+        | Putfield { index }
+        | Invokevirtual { index }
+        | Invokespecial { index }
+        | Invokestatic { index }
+        | New { index }
+        | Anewarray { index }
+        | Checkcast { index }
+        | Instanceof { index } => {
+            out.push(opcode);
+            out.extend_from_slice(&index.to_be_bytes());
+        }
+        Iload { index }
+        | Lload { index }
+        | Fload { index }
+        | Dload { index }
+        | Aload { index }
+        | Istore { index }
+        | Lstore { index }
+        | Fstore { index }
+        | Dstore { index }
+        | Astore { index }
+        | Ret { index } => {
+            if *index <= 0xff {
+                out.extend_from_slice(&[opcode, *index as u8]);
+            } else {
+                // wide
+                out.extend_from_slice(&[0xc4, opcode]);
+                out.extend_from_slice(&index.to_be_bytes());
+            }
+        }
+        Iinc { index, value } => {
+            if *index <= 0xff && i8::try_from(*value).is_ok() {
+                out.extend_from_slice(&[opcode, *index as u8, *value as u8]);
+            } else {
+                // wide
+                out.extend_from_slice(&[0xc4, opcode]);
+                out.extend_from_slice(&index.to_be_bytes());
+                out.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+        Newarray { atype } => out.extend_from_slice(&[opcode, atype.atype()]),
+        Multianewarray { index, dimensions } => {
+            out.push(opcode);
+            out.extend_from_slice(&index.to_be_bytes());
+            out.push(*dimensions);
+        }
+        Invokeinterface { index, count } => {
+            out.push(opcode);
+            out.extend_from_slice(&index.to_be_bytes());
+            out.extend_from_slice(&[*count, 0]);
+        }
+        Invokedynamic { index } => {
+            out.push(opcode);
+            out.extend_from_slice(&index.to_be_bytes());
+            out.extend_from_slice(&[0, 0]);
+        }
+        GotoW { target } | JsrW { target } => {
+            out.push(opcode);
+            out.extend_from_slice(&relative(*target).to_be_bytes());
+        }
+        Tableswitch {
+            default,
+            low,
+            high,
+            targets,
+        } => {
+            out.push(opcode);
+            out.resize(out.len() + switch_padding(pc as usize), 0);
+            out.extend_from_slice(&relative(*default).to_be_bytes());
+            out.extend_from_slice(&low.to_be_bytes());
+            out.extend_from_slice(&high.to_be_bytes());
+            for target in targets {
+                out.extend_from_slice(&relative(*target).to_be_bytes());
+            }
+        }
+        Lookupswitch { default, pairs } => {
+            out.push(opcode);
+            out.resize(out.len() + switch_padding(pc as usize), 0);
+            out.extend_from_slice(&relative(*default).to_be_bytes());
+            out.extend_from_slice(&(pairs.len() as i32).to_be_bytes());
+            for (key, target) in pairs {
+                out.extend_from_slice(&key.to_be_bytes());
+                out.extend_from_slice(&relative(*target).to_be_bytes());
+            }
+        }
+        _ => match short_branch_target(instruction) {
+            Some(&target) if widened => {
+                match instruction {
+                    // goto_w, jsr_w
+                    Goto { .. } => out.push(0xc8),
+                    Jsr { .. } => out.push(0xc9),
+                    _ => {
+                        // skip over the goto_w when the condition fails
+                        out.push(inverted_branch(opcode));
+                        out.extend_from_slice(&8i16.to_be_bytes());
+                        out.push(0xc8);
+                        let relative = (target as i64 - (pc as i64 + 3)) as i32;
+                        out.extend_from_slice(&relative.to_be_bytes());
+                        return;
+                    }
+                }
+                out.extend_from_slice(&relative(target).to_be_bytes());
+            }
+            Some(&target) => {
+                out.push(opcode);
+                out.extend_from_slice(&(relative(target) as i16).to_be_bytes());
+            }
+            None => out.push(opcode),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local_variable(start_pc: U2, length: U2) -> LocalVariable {
+        LocalVariable {
+            start_pc,
+            length,
+            name_index: 1,
+            descriptor_index: 2,
+            index: 0,
+        }
+    }
+
+    #[test]
+    fn local_variable_ranges_past_the_code_fail() {
+        // nop, return
+        let assembled = assemble(&items_from_code(&[0x00, 0xb1]).unwrap()).unwrap();
+        let mut table = [local_variable(0xfff0, 0x20)];
+        assert_eq!(
+            assembled.remap_local_variables(&mut table),
+            Err(ErrorKind::UndefinedLabel(0xfff0))
+        );
+        let mut table = [local_variable(1, 0xffff)];
+        assert_eq!(
+            assembled.remap_local_variables(&mut table),
+            Err(ErrorKind::UndefinedLabel(0x1_0000))
+        );
+    }
+
+    #[test]
+    fn local_variable_ranges_ending_before_their_start_fail() {
+        let items = [
+            Item::Label(Label(1)),
+            Item::Instruction(Instruction::Nop),
+            Item::Label(Label(0)),
+            Item::Instruction(Instruction::Return),
+        ];
+        let assembled = assemble(&items).unwrap();
+        let mut table = [local_variable(0, 1)];
+        assert_eq!(
+            assembled.remap_local_variables(&mut table),
+            Err(ErrorKind::InvalidLocalVariableRange { start: 1, end: 0 })
+        );
+    }
+
+    #[test]
+    fn local_variable_ranges_follow_their_instructions() {
+        // iload 0x100, return
+        let mut items = items_from_code(&[0x15, 0x00, 0xb1]).unwrap();
+        items[1] = Item::Instruction(Instruction::Iload { index: 0x100 });
+        let assembled = assemble(&items).unwrap();
+        let mut table = [local_variable(0, 3), local_variable(2, 1)];
+        assembled.remap_local_variables(&mut table).unwrap();
+        assert_eq!((table[0].start_pc, table[0].length), (0, 5));
+        assert_eq!((table[1].start_pc, table[1].length), (4, 1));
+    }
+
+    fn instruction(instruction: Instruction<Label>) -> Item {
+        Item::Instruction(instruction)
+    }
+
+    /// `branch`, `nops` times `nop`, then `return` at `Label(1)`.
+    fn branch_over_nops(branch: Instruction<Label>, nops: usize) -> Vec<Item> {
+        let mut items = vec![instruction(branch)];
+        items.extend((0..nops).map(|_| instruction(Instruction::Nop)));
+        items.push(Item::Label(Label(1)));
+        items.push(instruction(Instruction::Return));
+        items
+    }
+
+    #[test]
+    fn branches_in_range_stay_short() {
+        let assembled = assemble(&branch_over_nops(
+            Instruction::Goto { target: Label(1) },
+            32764,
+        ))
+        .unwrap();
+        assert_eq!(assembled.code[..3], [0xa7, 0x7f, 0xff]);
+    }
+
+    #[test]
+    fn far_gotos_become_goto_w() {
+        let assembled = assemble(&branch_over_nops(
+            Instruction::Goto { target: Label(1) },
+            33000,
+        ))
+        .unwrap();
+        assert_eq!(assembled.offset(Label(1)), Some(33005));
+        assert_eq!(assembled.code[..5], [0xc8, 0x00, 0x00, 0x80, 0xed]);
+        assert_eq!(assembled.code.len(), 33006);
+    }
+
+    #[test]
+    fn far_conditional_branches_jump_over_a_goto_w() {
+        let assembled = assemble(&branch_over_nops(
+            Instruction::Ifeq { target: Label(1) },
+            33000,
+        ))
+        .unwrap();
+        assert_eq!(assembled.offset(Label(1)), Some(33008));
+        // ifne +8; goto_w +33005
+        assert_eq!(
+            assembled.code[..8],
+            [0x9a, 0x00, 0x08, 0xc8, 0x00, 0x00, 0x80, 0xed]
+        );
+
+        let assembled = assemble(&branch_over_nops(
+            Instruction::Ifnull { target: Label(1) },
+            33000,
+        ))
+        .unwrap();
+        // ifnonnull
+        assert_eq!(assembled.code[0], 0xc7);
+    }
+
+    #[test]
+    fn large_operands_get_wide_forms() {
+        use Instruction::*;
+
+        let cases = [
+            (Iload { index: 5 }, &[0x15, 0x05][..]),
+            (Iload { index: 0x100 }, &[0xc4, 0x15, 0x01, 0x00]),
+            (Astore { index: 0x1234 }, &[0xc4, 0x3a, 0x12, 0x34]),
+            (Ret { index: 0x100 }, &[0xc4, 0xa9, 0x01, 0x00]),
+            (
+                Iinc {
+                    index: 1,
+                    value: -1,
+                },
+                &[0x84, 0x01, 0xff],
+            ),
+            (
+                Iinc {
+                    index: 1,
+                    value: 200,
+                },
+                &[0xc4, 0x84, 0x00, 0x01, 0x00, 0xc8],
+            ),
+            (
+                Iinc {
+                    index: 0x100,
+                    value: 1,
+                },
+                &[0xc4, 0x84, 0x01, 0x00, 0x00, 0x01],
+            ),
+            (Ldc { index: 0xff }, &[0x12, 0xff]),
+            (Ldc { index: 0x100 }, &[0x13, 0x01, 0x00]),
+        ];
+        for (instruction, expected) in cases {
+            let assembled = assemble(&[Item::Instruction(instruction.clone())]).unwrap();
+            assert_eq!(assembled.code, expected, "{:?}", instruction);
+        }
+    }
+
+    #[test]
+    fn switch_padding_follows_relayout() {
+        // the goto widens to goto_w, moving the switch from offset 3 to 5
+        let mut items = vec![
+            instruction(Instruction::Goto { target: Label(1) }),
+            Item::Label(Label(2)),
+            instruction(Instruction::Tableswitch {
+                default: Label(1),
+                low: 0,
+                high: 0,
+                targets: vec![Label(1)],
+            }),
+        ];
+        items.extend(branch_over_nops(Instruction::Nop, 33000));
+        let assembled = assemble(&items).unwrap();
+        assert_eq!(assembled.offset(Label(2)), Some(5));
+        let end = assembled.offset(Label(1)).unwrap();
+        let decoded = instructions(&assembled.code)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(decoded[0], (0, Instruction::GotoW { target: end }));
+        assert_eq!(
+            decoded[1],
+            (
+                5,
+                Instruction::Tableswitch {
+                    default: end,
+                    low: 0,
+                    high: 0,
+                    targets: vec![end],
+                }
+            )
+        );
+        // two bytes of padding after the opcode at 5
+        assert_eq!(assembled.code[6..8], [0, 0]);
+    }
+
+    #[test]
+    fn code_over_65535_bytes_is_rejected() {
+        let items = vec![instruction(Instruction::Nop); 65535];
+        assert_eq!(assemble(&items).unwrap().code.len(), 65535);
+        let items = vec![instruction(Instruction::Nop); 65536];
+        assert_eq!(
+            assemble(&items).unwrap_err(),
+            ErrorKind::CodeTooLarge(65536)
+        );
+    }
+
+    #[test]
+    fn malformed_switches_are_rejected() {
+        let tableswitch = |low, high, targets: usize| {
+            [
+                Item::Label(Label(0)),
+                instruction(Instruction::Tableswitch {
+                    default: Label(0),
+                    low,
+                    high,
+                    targets: vec![Label(0); targets],
+                }),
+            ]
+        };
+        assert!(assemble(&tableswitch(1, 3, 3)).is_ok());
+        assert_eq!(
+            assemble(&tableswitch(1, 3, 2)).unwrap_err(),
+            ErrorKind::InvalidTableSwitch { low: 1, high: 3 }
+        );
+        assert_eq!(
+            assemble(&tableswitch(3, 1, 0)).unwrap_err(),
+            ErrorKind::InvalidTableSwitch { low: 3, high: 1 }
+        );
+
+        let lookupswitch = |keys: &[i32]| {
+            [
+                Item::Label(Label(0)),
+                instruction(Instruction::Lookupswitch {
+                    default: Label(0),
+                    pairs: keys.iter().map(|&key| (key, Label(0))).collect(),
+                }),
+            ]
+        };
+        assert!(assemble(&lookupswitch(&[-1, 0, 7])).is_ok());
+        assert_eq!(
+            assemble(&lookupswitch(&[0, 7, 3])).unwrap_err(),
+            ErrorKind::InvalidLookupSwitch { npairs: 3 }
+        );
+        assert_eq!(
+            assemble(&lookupswitch(&[1, 1])).unwrap_err(),
+            ErrorKind::InvalidLookupSwitch { npairs: 2 }
+        );
+    }
+}
@@ -0,0 +1,1767 @@
+use nom::{
+    bytes::complete::take,
+    multi::count,
+    number::complete::{be_i16, be_i32, be_i8, be_u16, be_u8},
+};
+
+use crate::{
+    error::{fail, ClassFileError, ErrorKind, PResult, ParseError},
+    U1, U2,
+};
+
+/// A single JVM instruction.
+///
+/// Branch targets are of type `T`; decoded instructions use absolute offsets
+/// into the code array. Instructions prefixed by `wide` decode to the same
+/// variant as their short form, with the wider operands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction<T = u32> {
+    Nop,
+    AconstNull,
+    IconstM1,
+    Iconst0,
+    Iconst1,
+    Iconst2,
+    Iconst3,
+    Iconst4,
+    Iconst5,
+    Lconst0,
+    Lconst1,
+    Fconst0,
+    Fconst1,
+    Fconst2,
+    Dconst0,
+    Dconst1,
+    Bipush {
+        value: i8,
+    },
+    Sipush {
+        value: i16,
+    },
+    Ldc {
+        index: U2,
+    },
+    LdcW {
+        index: U2,
+    },
+    Ldc2W {
+        index: U2,
+    },
+    Iload {
+        index: U2,
+    },
+    Lload {
+        index: U2,
+    },
+    Fload {
+        index: U2,
+    },
+    Dload {
+        index: U2,
+    },
+    Aload {
+        index: U2,
+    },
+    Iload0,
+    Iload1,
+    Iload2,
+    Iload3,
+    Lload0,
+    Lload1,
+    Lload2,
+    Lload3,
+    Fload0,
+    Fload1,
+    Fload2,
+    Fload3,
+    Dload0,
+    Dload1,
+    Dload2,
+    Dload3,
+    Aload0,
+    Aload1,
+    Aload2,
+    Aload3,
+    Iaload,
+    Laload,
+    Faload,
+    Daload,
+    Aaload,
+    Baload,
+    Caload,
+    Saload,
+    Istore {
+        index: U2,
+    },
+    Lstore {
+        index: U2,
+    },
+    Fstore {
+        index: U2,
+    },
+    Dstore {
+        index: U2,
+    },
+    Astore {
+        index: U2,
+    },
+    Istore0,
+    Istore1,
+    Istore2,
+    Istore3,
+    Lstore0,
+    Lstore1,
+    Lstore2,
+    Lstore3,
+    Fstore0,
+    Fstore1,
+    Fstore2,
+    Fstore3,
+    Dstore0,
+    Dstore1,
+    Dstore2,
+    Dstore3,
+    Astore0,
+    Astore1,
+    Astore2,
+    Astore3,
+    Iastore,
+    Lastore,
+    Fastore,
+    Dastore,
+    Aastore,
+    Bastore,
+    Castore,
+    Sastore,
+    Pop,
+    Pop2,
+    Dup,
+    DupX1,
+    DupX2,
+    Dup2,
+    Dup2X1,
+    Dup2X2,
+    Swap,
+    Iadd,
+    Ladd,
+    Fadd,
+    Dadd,
+    Isub,
+    Lsub,
+    Fsub,
+    Dsub,
+    Imul,
+    Lmul,
+    Fmul,
+    Dmul,
+    Idiv,
+    Ldiv,
+    Fdiv,
+    Ddiv,
+    Irem,
+    Lrem,
+    Frem,
+    Drem,
+    Ineg,
+    Lneg,
+    Fneg,
+    Dneg,
+    Ishl,
+    Lshl,
+    Ishr,
+    Lshr,
+    Iushr,
+    Lushr,
+    Iand,
+    Land,
+    Ior,
+    Lor,
+    Ixor,
+    Lxor,
+    Iinc {
+        index: U2,
+        value: i16,
+    },
+    I2l,
+    I2f,
+    I2d,
+    L2i,
+    L2f,
+    L2d,
+    F2i,
+    F2l,
+    F2d,
+    D2i,
+    D2l,
+    D2f,
+    I2b,
+    I2c,
+    I2s,
+    Lcmp,
+    Fcmpl,
+    Fcmpg,
+    Dcmpl,
+    Dcmpg,
+    Ifeq {
+        target: T,
+    },
+    Ifne {
+        target: T,
+    },
+    Iflt {
+        target: T,
+    },
+    Ifge {
+        target: T,
+    },
+    Ifgt {
+        target: T,
+    },
+    Ifle {
+        target: T,
+    },
+    IfIcmpeq {
+        target: T,
+    },
+    IfIcmpne {
+        target: T,
+    },
+    IfIcmplt {
+        target: T,
+    },
+    IfIcmpge {
+        target: T,
+    },
+    IfIcmpgt {
+        target: T,
+    },
+    IfIcmple {
+        target: T,
+    },
+    IfAcmpeq {
+        target: T,
+    },
+    IfAcmpne {
+        target: T,
+    },
+    Goto {
+        target: T,
+    },
+    Jsr {
+        target: T,
+    },
+    Ret {
+        index: U2,
+    },
+    Tableswitch {
+        default: T,
+        low: i32,
+        high: i32,
+        targets: Vec<T>,
+    },
+    Lookupswitch {
+        default: T,
+        pairs: Vec<(i32, T)>,
+    },
+    Ireturn,
+    Lreturn,
+    Freturn,
+    Dreturn,
+    Areturn,
+    Return,
+    Getstatic {
+        index: U2,
+    },
+    Putstatic {
+        index: U2,
+    },
+    Getfield {
+        index: U2,
+    },
+    Putfield {
+        index: U2,
+    },
+    Invokevirtual {
+        index: U2,
+    },
+    Invokespecial {
+        index: U2,
+    },
+    Invokestatic {
+        index: U2,
+    },
+    Invokeinterface {
+        index: U2,
+        count: U1,
+    },
+    Invokedynamic {
+        index: U2,
+    },
+    New {
+        index: U2,
+    },
+    Newarray {
+        atype: ArrayType,
+    },
+    Anewarray {
+        index: U2,
+    },
+    Arraylength,
+    Athrow,
+    Checkcast {
+        index: U2,
+    },
+    Instanceof {
+        index: U2,
+    },
+    Monitorenter,
+    Monitorexit,
+    Multianewarray {
+        index: U2,
+        dimensions: U1,
+    },
+    Ifnull {
+        target: T,
+    },
+    Ifnonnull {
+        target: T,
+    },
+    GotoW {
+        target: T,
+    },
+    JsrW {
+        target: T,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayType {
+    Boolean,
+    Char,
+    Float,
+    Double,
+    Byte,
+    Short,
+    Int,
+    Long,
+}
+
+impl ArrayType {
+    /// The `atype` operand of `newarray`.
+    pub fn atype(self) -> U1 {
+        match self {
+            Self::Boolean => 4,
+            Self::Char => 5,
+            Self::Float => 6,
+            Self::Double => 7,
+            Self::Byte => 8,
+            Self::Short => 9,
+            Self::Int => 10,
+            Self::Long => 11,
+        }
+    }
+}
+
+impl TryFrom<u8> for ArrayType {
+    type Error = ErrorKind;
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        Ok(match b {
+            4 => Self::Boolean,
+            5 => Self::Char,
+            6 => Self::Float,
+            7 => Self::Double,
+            8 => Self::Byte,
+            9 => Self::Short,
+            10 => Self::Int,
+            11 => Self::Long,
+            _ => return Err(ErrorKind::InvalidArrayType(b)),
+        })
+    }
+}
+
+impl<T> Instruction<T> {
+    pub fn opcode(&self) -> U1 {
+        match self {
+            Self::Nop => 0x00,
+            Self::AconstNull => 0x01,
+            Self::IconstM1 => 0x02,
+            Self::Iconst0 => 0x03,
+            Self::Iconst1 => 0x04,
+            Self::Iconst2 => 0x05,
+            Self::Iconst3 => 0x06,
+            Self::Iconst4 => 0x07,
+            Self::Iconst5 => 0x08,
+            Self::Lconst0 => 0x09,
+            Self::Lconst1 => 0x0a,
+            Self::Fconst0 => 0x0b,
+            Self::Fconst1 => 0x0c,
+            Self::Fconst2 => 0x0d,
+            Self::Dconst0 => 0x0e,
+            Self::Dconst1 => 0x0f,
+            Self::Bipush { .. } => 0x10,
+            Self::Sipush { .. } => 0x11,
+            Self::Ldc { .. } => 0x12,
+            Self::LdcW { .. } => 0x13,
+            Self::Ldc2W { .. } => 0x14,
+            Self::Iload { .. } => 0x15,
+            Self::Lload { .. } => 0x16,
+            Self::Fload { .. } => 0x17,
+            Self::Dload { .. } => 0x18,
+            Self::Aload { .. } => 0x19,
+            Self::Iload0 => 0x1a,
+            Self::Iload1 => 0x1b,
+            Self::Iload2 => 0x1c,
+            Self::Iload3 => 0x1d,
+            Self::Lload0 => 0x1e,
+            Self::Lload1 => 0x1f,
+            Self::Lload2 => 0x20,
+            Self::Lload3 => 0x21,
+            Self::Fload0 => 0x22,
+            Self::Fload1 => 0x23,
+            Self::Fload2 => 0x24,
+            Self::Fload3 => 0x25,
+            Self::Dload0 => 0x26,
+            Self::Dload1 => 0x27,
+            Self::Dload2 => 0x28,
+            Self::Dload3 => 0x29,
+            Self::Aload0 => 0x2a,
+            Self::Aload1 => 0x2b,
+            Self::Aload2 => 0x2c,
+            Self::Aload3 => 0x2d,
+            Self::Iaload => 0x2e,
+            Self::Laload => 0x2f,
+            Self::Faload => 0x30,
+            Self::Daload => 0x31,
+            Self::Aaload => 0x32,
+            Self::Baload => 0x33,
+            Self::Caload => 0x34,
+            Self::Saload => 0x35,
+            Self::Istore { .. } => 0x36,
+            Self::Lstore { .. } => 0x37,
+            Self::Fstore { .. } => 0x38,
+            Self::Dstore { .. } => 0x39,
+            Self::Astore { .. } => 0x3a,
+            Self::Istore0 => 0x3b,
+            Self::Istore1 => 0x3c,
+            Self::Istore2 => 0x3d,
+            Self::Istore3 => 0x3e,
+            Self::Lstore0 => 0x3f,
+            Self::Lstore1 => 0x40,
+            Self::Lstore2 => 0x41,
+            Self::Lstore3 => 0x42,
+            Self::Fstore0 => 0x43,
+            Self::Fstore1 => 0x44,
+            Self::Fstore2 => 0x45,
+            Self::Fstore3 => 0x46,
+            Self::Dstore0 => 0x47,
+            Self::Dstore1 => 0x48,
+            Self::Dstore2 => 0x49,
+            Self::Dstore3 => 0x4a,
+            Self::Astore0 => 0x4b,
+            Self::Astore1 => 0x4c,
+            Self::Astore2 => 0x4d,
+            Self::Astore3 => 0x4e,
+            Self::Iastore => 0x4f,
+            Self::Lastore => 0x50,
+            Self::Fastore => 0x51,
+            Self::Dastore => 0x52,
+            Self::Aastore => 0x53,
+            Self::Bastore => 0x54,
+            Self::Castore => 0x55,
+            Self::Sastore => 0x56,
+            Self::Pop => 0x57,
+            Self::Pop2 => 0x58,
+            Self::Dup => 0x59,
+            Self::DupX1 => 0x5a,
+            Self::DupX2 => 0x5b,
+            Self::Dup2 => 0x5c,
+            Self::Dup2X1 => 0x5d,
+            Self::Dup2X2 => 0x5e,
+            Self::Swap => 0x5f,
+            Self::Iadd => 0x60,
+            Self::Ladd => 0x61,
+            Self::Fadd => 0x62,
+            Self::Dadd => 0x63,
+            Self::Isub => 0x64,
+            Self::Lsub => 0x65,
+            Self::Fsub => 0x66,
+            Self::Dsub => 0x67,
+            Self::Imul => 0x68,
+            Self::Lmul => 0x69,
+            Self::Fmul => 0x6a,
+            Self::Dmul => 0x6b,
+            Self::Idiv => 0x6c,
+            Self::Ldiv => 0x6d,
+            Self::Fdiv => 0x6e,
+            Self::Ddiv => 0x6f,
+            Self::Irem => 0x70,
+            Self::Lrem => 0x71,
+            Self::Frem => 0x72,
+            Self::Drem => 0x73,
+            Self::Ineg => 0x74,
+            Self::Lneg => 0x75,
+            Self::Fneg => 0x76,
+            Self::Dneg => 0x77,
+            Self::Ishl => 0x78,
+            Self::Lshl => 0x79,
+            Self::Ishr => 0x7a,
+            Self::Lshr => 0x7b,
+            Self::Iushr => 0x7c,
+            Self::Lushr => 0x7d,
+            Self::Iand => 0x7e,
+            Self::Land => 0x7f,
+            Self::Ior => 0x80,
+            Self::Lor => 0x81,
+            Self::Ixor => 0x82,
+            Self::Lxor => 0x83,
+            Self::Iinc { .. } => 0x84,
+            Self::I2l => 0x85,
+            Self::I2f => 0x86,
+            Self::I2d => 0x87,
+            Self::L2i => 0x88,
+            Self::L2f => 0x89,
+            Self::L2d => 0x8a,
+            Self::F2i => 0x8b,
+            Self::F2l => 0x8c,
+            Self::F2d => 0x8d,
+            Self::D2i => 0x8e,
+            Self::D2l => 0x8f,
+            Self::D2f => 0x90,
+            Self::I2b => 0x91,
+            Self::I2c => 0x92,
+            Self::I2s => 0x93,
+            Self::Lcmp => 0x94,
+            Self::Fcmpl => 0x95,
+            Self::Fcmpg => 0x96,
+            Self::Dcmpl => 0x97,
+            Self::Dcmpg => 0x98,
+            Self::Ifeq { .. } => 0x99,
+            Self::Ifne { .. } => 0x9a,
+            Self::Iflt { .. } => 0x9b,
+            Self::Ifge { .. } => 0x9c,
+            Self::Ifgt { .. } => 0x9d,
+            Self::Ifle { .. } => 0x9e,
+            Self::IfIcmpeq { .. } => 0x9f,
+            Self::IfIcmpne { .. } => 0xa0,
+            Self::IfIcmplt { .. } => 0xa1,
+            Self::IfIcmpge { .. } => 0xa2,
+            Self::IfIcmpgt { .. } => 0xa3,
+            Self::IfIcmple { .. } => 0xa4,
+            Self::IfAcmpeq { .. } => 0xa5,
+            Self::IfAcmpne { .. } => 0xa6,
+            Self::Goto { .. } => 0xa7,
+            Self::Jsr { .. } => 0xa8,
+            Self::Ret { .. } => 0xa9,
+            Self::Tableswitch { .. } => 0xaa,
+            Self::Lookupswitch { .. } => 0xab,
+            Self::Ireturn => 0xac,
+            Self::Lreturn => 0xad,
+            Self::Freturn => 0xae,
+            Self::Dreturn => 0xaf,
+            Self::Areturn => 0xb0,
+            Self::Return => 0xb1,
+            Self::Getstatic { .. } => 0xb2,
+            Self::Putstatic { .. } => 0xb3,
+            Self::Getfield { .. } => 0xb4,
+            Self::Putfield { .. } => 0xb5,
+            Self::Invokevirtual { .. } => 0xb6,
+            Self::Invokespecial { .. } => 0xb7,
+            Self::Invokestatic { .. } => 0xb8,
+            Self::Invokeinterface { .. } => 0xb9,
+            Self::Invokedynamic { .. } => 0xba,
+            Self::New { .. } => 0xbb,
+            Self::Newarray { .. } => 0xbc,
+            Self::Anewarray { .. } => 0xbd,
+            Self::Arraylength => 0xbe,
+            Self::Athrow => 0xbf,
+            Self::Checkcast { .. } => 0xc0,
+            Self::Instanceof { .. } => 0xc1,
+            Self::Monitorenter => 0xc2,
+            Self::Monitorexit => 0xc3,
+            Self::Multianewarray { .. } => 0xc5,
+            Self::Ifnull { .. } => 0xc6,
+            Self::Ifnonnull { .. } => 0xc7,
+            Self::GotoW { .. } => 0xc8,
+            Self::JsrW { .. } => 0xc9,
+        }
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Self::Nop => "nop",
+            Self::AconstNull => "aconst_null",
+            Self::IconstM1 => "iconst_m1",
+            Self::Iconst0 => "iconst_0",
+            Self::Iconst1 => "iconst_1",
+            Self::Iconst2 => "iconst_2",
+            Self::Iconst3 => "iconst_3",
+            Self::Iconst4 => "iconst_4",
+            Self::Iconst5 => "iconst_5",
+            Self::Lconst0 => "lconst_0",
+            Self::Lconst1 => "lconst_1",
+            Self::Fconst0 => "fconst_0",
+            Self::Fconst1 => "fconst_1",
+            Self::Fconst2 => "fconst_2",
+            Self::Dconst0 => "dconst_0",
+            Self::Dconst1 => "dconst_1",
+            Self::Bipush { .. } => "bipush",
+            Self::Sipush { .. } => "sipush",
+            Self::Ldc { .. } => "ldc",
+            Self::LdcW { .. } => "ldc_w",
+            Self::Ldc2W { .. } => "ldc2_w",
+            Self::Iload { .. } => "iload",
+            Self::Lload { .. } => "lload",
+            Self::Fload { .. } => "fload",
+            Self::Dload { .. } => "dload",
+            Self::Aload { .. } => "aload",
+            Self::Iload0 => "iload_0",
+            Self::Iload1 => "iload_1",
+            Self::Iload2 => "iload_2",
+            Self::Iload3 => "iload_3",
+            Self::Lload0 => "lload_0",
+            Self::Lload1 => "lload_1",
+            Self::Lload2 => "lload_2",
+            Self::Lload3 => "lload_3",
+            Self::Fload0 => "fload_0",
+            Self::Fload1 => "fload_1",
+            Self::Fload2 => "fload_2",
+            Self::Fload3 => "fload_3",
+            Self::Dload0 => "dload_0",
+            Self::Dload1 => "dload_1",
+            Self::Dload2 => "dload_2",
+            Self::Dload3 => "dload_3",
+            Self::Aload0 => "aload_0",
+            Self::Aload1 => "aload_1",
+            Self::Aload2 => "aload_2",
+            Self::Aload3 => "aload_3",
+            Self::Iaload => "iaload",
+            Self::Laload => "laload",
+            Self::Faload => "faload",
+            Self::Daload => "daload",
+            Self::Aaload => "aaload",
+            Self::Baload => "baload",
+            Self::Caload => "caload",
+            Self::Saload => "saload",
+            Self::Istore { .. } => "istore",
+            Self::Lstore { .. } => "lstore",
+            Self::Fstore { .. } => "fstore",
+            Self::Dstore { .. } => "dstore",
+            Self::Astore { .. } => "astore",
+            Self::Istore0 => "istore_0",
+            Self::Istore1 => "istore_1",
+            Self::Istore2 => "istore_2",
+            Self::Istore3 => "istore_3",
+            Self::Lstore0 => "lstore_0",
+            Self::Lstore1 => "lstore_1",
+            Self::Lstore2 => "lstore_2",
+            Self::Lstore3 => "lstore_3",
+            Self::Fstore0 => "fstore_0",
+            Self::Fstore1 => "fstore_1",
+            Self::Fstore2 => "fstore_2",
+            Self::Fstore3 => "fstore_3",
+            Self::Dstore0 => "dstore_0",
+            Self::Dstore1 => "dstore_1",
+            Self::Dstore2 => "dstore_2",
+            Self::Dstore3 => "dstore_3",
+            Self::Astore0 => "astore_0",
+            Self::Astore1 => "astore_1",
+            Self::Astore2 => "astore_2",
+            Self::Astore3 => "astore_3",
+            Self::Iastore => "iastore",
+            Self::Lastore => "lastore",
+            Self::Fastore => "fastore",
+            Self::Dastore => "dastore",
+            Self::Aastore => "aastore",
+            Self::Bastore => "bastore",
+            Self::Castore => "castore",
+            Self::Sastore => "sastore",
+            Self::Pop => "pop",
+            Self::Pop2 => "pop2",
+            Self::Dup => "dup",
+            Self::DupX1 => "dup_x1",
+            Self::DupX2 => "dup_x2",
+            Self::Dup2 => "dup2",
+            Self::Dup2X1 => "dup2_x1",
+            Self::Dup2X2 => "dup2_x2",
+            Self::Swap => "swap",
+            Self::Iadd => "iadd",
+            Self::Ladd => "ladd",
+            Self::Fadd => "fadd",
+            Self::Dadd => "dadd",
+            Self::Isub => "isub",
+            Self::Lsub => "lsub",
+            Self::Fsub => "fsub",
+            Self::Dsub => "dsub",
+            Self::Imul => "imul",
+            Self::Lmul => "lmul",
+            Self::Fmul => "fmul",
+            Self::Dmul => "dmul",
+            Self::Idiv => "idiv",
+            Self::Ldiv => "ldiv",
+            Self::Fdiv => "fdiv",
+            Self::Ddiv => "ddiv",
+            Self::Irem => "irem",
+            Self::Lrem => "lrem",
+            Self::Frem => "frem",
+            Self::Drem => "drem",
+            Self::Ineg => "ineg",
+            Self::Lneg => "lneg",
+            Self::Fneg => "fneg",
+            Self::Dneg => "dneg",
+            Self::Ishl => "ishl",
+            Self::Lshl => "lshl",
+            Self::Ishr => "ishr",
+            Self::Lshr => "lshr",
+            Self::Iushr => "iushr",
+            Self::Lushr => "lushr",
+            Self::Iand => "iand",
+            Self::Land => "land",
+            Self::Ior => "ior",
+            Self::Lor => "lor",
+            Self::Ixor => "ixor",
+            Self::Lxor => "lxor",
+            Self::Iinc { .. } => "iinc",
+            Self::I2l => "i2l",
+            Self::I2f => "i2f",
+            Self::I2d => "i2d",
+            Self::L2i => "l2i",
+            Self::L2f => "l2f",
+            Self::L2d => "l2d",
+            Self::F2i => "f2i",
+            Self::F2l => "f2l",
+            Self::F2d => "f2d",
+            Self::D2i => "d2i",
+            Self::D2l => "d2l",
+            Self::D2f => "d2f",
+            Self::I2b => "i2b",
+            Self::I2c => "i2c",
+            Self::I2s => "i2s",
+            Self::Lcmp => "lcmp",
+            Self::Fcmpl => "fcmpl",
+            Self::Fcmpg => "fcmpg",
+            Self::Dcmpl => "dcmpl",
+            Self::Dcmpg => "dcmpg",
+            Self::Ifeq { .. } => "ifeq",
+            Self::Ifne { .. } => "ifne",
+            Self::Iflt { .. } => "iflt",
+            Self::Ifge { .. } => "ifge",
+            Self::Ifgt { .. } => "ifgt",
+            Self::Ifle { .. } => "ifle",
+            Self::IfIcmpeq { .. } => "if_icmpeq",
+            Self::IfIcmpne { .. } => "if_icmpne",
+            Self::IfIcmplt { .. } => "if_icmplt",
+            Self::IfIcmpge { .. } => "if_icmpge",
+            Self::IfIcmpgt { .. } => "if_icmpgt",
+            Self::IfIcmple { .. } => "if_icmple",
+            Self::IfAcmpeq { .. } => "if_acmpeq",
+            Self::IfAcmpne { .. } => "if_acmpne",
+            Self::Goto { .. } => "goto",
+            Self::Jsr { .. } => "jsr",
+            Self::Ret { .. } => "ret",
+            Self::Tableswitch { .. } => "tableswitch",
+            Self::Lookupswitch { .. } => "lookupswitch",
+            Self::Ireturn => "ireturn",
+            Self::Lreturn => "lreturn",
+            Self::Freturn => "freturn",
+            Self::Dreturn => "dreturn",
+            Self::Areturn => "areturn",
+            Self::Return => "return",
+            Self::Getstatic { .. } => "getstatic",
+            Self::Putstatic { .. } => "putstatic",
+            Self::Getfield { .. } => "getfield",
+            Self::Putfield { .. } => "putfield",
+            Self::Invokevirtual { .. } => "invokevirtual",
+            Self::Invokespecial { .. } => "invokespecial",
+            Self::Invokestatic { .. } => "invokestatic",
+            Self::Invokeinterface { .. } => "invokeinterface",
+            Self::Invokedynamic { .. } => "invokedynamic",
+            Self::New { .. } => "new",
+            Self::Newarray { .. } => "newarray",
+            Self::Anewarray { .. } => "anewarray",
+            Self::Arraylength => "arraylength",
+            Self::Athrow => "athrow",
+            Self::Checkcast { .. } => "checkcast",
+            Self::Instanceof { .. } => "instanceof",
+            Self::Monitorenter => "monitorenter",
+            Self::Monitorexit => "monitorexit",
+            Self::Multianewarray { .. } => "multianewarray",
+            Self::Ifnull { .. } => "ifnull",
+            Self::Ifnonnull { .. } => "ifnonnull",
+            Self::GotoW { .. } => "goto_w",
+            Self::JsrW { .. } => "jsr_w",
+        }
+    }
+
+    /// Converts the branch targets of the instruction with `f`.
+    pub fn map_targets<U, E>(
+        self,
+        mut f: impl FnMut(T) -> Result<U, E>,
+    ) -> Result<Instruction<U>, E> {
+        use Instruction::*;
+
+        Ok(match self {
+            Nop => Nop,
+            AconstNull => AconstNull,
+            IconstM1 => IconstM1,
+            Iconst0 => Iconst0,
+            Iconst1 => Iconst1,
+            Iconst2 => Iconst2,
+            Iconst3 => Iconst3,
+            Iconst4 => Iconst4,
+            Iconst5 => Iconst5,
+            Lconst0 => Lconst0,
+            Lconst1 => Lconst1,
+            Fconst0 => Fconst0,
+            Fconst1 => Fconst1,
+            Fconst2 => Fconst2,
+            Dconst0 => Dconst0,
+            Dconst1 => Dconst1,
+            Bipush { value } => Bipush { value },
+            Sipush { value } => Sipush { value },
+            Ldc { index } => Ldc { index },
+            LdcW { index } => LdcW { index },
+            Ldc2W { index } => Ldc2W { index },
+            Iload { index } => Iload { index },
+            Lload { index } => Lload { index },
+            Fload { index } => Fload { index },
+            Dload { index } => Dload { index },
+            Aload { index } => Aload { index },
+            Iload0 => Iload0,
+            Iload1 => Iload1,
+            Iload2 => Iload2,
+            Iload3 => Iload3,
+            Lload0 => Lload0,
+            Lload1 => Lload1,
+            Lload2 => Lload2,
+            Lload3 => Lload3,
+            Fload0 => Fload0,
+            Fload1 => Fload1,
+            Fload2 => Fload2,
+            Fload3 => Fload3,
+            Dload0 => Dload0,
+            Dload1 => Dload1,
+            Dload2 => Dload2,
+            Dload3 => Dload3,
+            Aload0 => Aload0,
+            Aload1 => Aload1,
+            Aload2 => Aload2,
+            Aload3 => Aload3,
+            Iaload => Iaload,
+            Laload => Laload,
+            Faload => Faload,
+            Daload => Daload,
+            Aaload => Aaload,
+            Baload => Baload,
+            Caload => Caload,
+            Saload => Saload,
+            Istore { index } => Istore { index },
+            Lstore { index } => Lstore { index },
+            Fstore { index } => Fstore { index },
+            Dstore { index } => Dstore { index },
+            Astore { index } => Astore { index },
+            Istore0 => Istore0,
+            Istore1 => Istore1,
+            Istore2 => Istore2,
+            Istore3 => Istore3,
+            Lstore0 => Lstore0,
+            Lstore1 => Lstore1,
+            Lstore2 => Lstore2,
+            Lstore3 => Lstore3,
+            Fstore0 => Fstore0,
+            Fstore1 => Fstore1,
+            Fstore2 => Fstore2,
+            Fstore3 => Fstore3,
+            Dstore0 => Dstore0,
+            Dstore1 => Dstore1,
+            Dstore2 => Dstore2,
+            Dstore3 => Dstore3,
+            Astore0 => Astore0,
+            Astore1 => Astore1,
+            Astore2 => Astore2,
+            Astore3 => Astore3,
+            Iastore => Iastore,
+            Lastore => Lastore,
+            Fastore => Fastore,
+            Dastore => Dastore,
+            Aastore => Aastore,
+            Bastore => Bastore,
+            Castore => Castore,
+            Sastore => Sastore,
+            Pop => Pop,
+            Pop2 => Pop2,
+            Dup => Dup,
+            DupX1 => DupX1,
+            DupX2 => DupX2,
+            Dup2 => Dup2,
+            Dup2X1 => Dup2X1,
+            Dup2X2 => Dup2X2,
+            Swap => Swap,
+            Iadd => Iadd,
+            Ladd => Ladd,
+            Fadd => Fadd,
+            Dadd => Dadd,
+            Isub => Isub,
+            Lsub => Lsub,
+            Fsub => Fsub,
+            Dsub => Dsub,
+            Imul => Imul,
+            Lmul => Lmul,
+            Fmul => Fmul,
+            Dmul => Dmul,
+            Idiv => Idiv,
+            Ldiv => Ldiv,
+            Fdiv => Fdiv,
+            Ddiv => Ddiv,
+            Irem => Irem,
+            Lrem => Lrem,
+            Frem => Frem,
+            Drem => Drem,
+            Ineg => Ineg,
+            Lneg => Lneg,
+            Fneg => Fneg,
+            Dneg => Dneg,
+            Ishl => Ishl,
+            Lshl => Lshl,
+            Ishr => Ishr,
+            Lshr => Lshr,
+            Iushr => Iushr,
+            Lushr => Lushr,
+            Iand => Iand,
+            Land => Land,
+            Ior => Ior,
+            Lor => Lor,
+            Ixor => Ixor,
+            Lxor => Lxor,
+            Iinc { index, value } => Iinc { index, value },
+            I2l => I2l,
+            I2f => I2f,
+            I2d => I2d,
+            L2i => L2i,
+            L2f => L2f,
+            L2d => L2d,
+            F2i => F2i,
+            F2l => F2l,
+            F2d => F2d,
+            D2i => D2i,
+            D2l => D2l,
+            D2f => D2f,
+            I2b => I2b,
+            I2c => I2c,
+            I2s => I2s,
+            Lcmp => Lcmp,
+            Fcmpl => Fcmpl,
+            Fcmpg => Fcmpg,
+            Dcmpl => Dcmpl,
+            Dcmpg => Dcmpg,
+            Ifeq { target } => Ifeq { target: f(target)? },
+            Ifne { target } => Ifne { target: f(target)? },
+            Iflt { target } => Iflt { target: f(target)? },
+            Ifge { target } => Ifge { target: f(target)? },
+            Ifgt { target } => Ifgt { target: f(target)? },
+            Ifle { target } => Ifle { target: f(target)? },
+            IfIcmpeq { target } => IfIcmpeq { target: f(target)? },
+            IfIcmpne { target } => IfIcmpne { target: f(target)? },
+            IfIcmplt { target } => IfIcmplt { target: f(target)? },
+            IfIcmpge { target } => IfIcmpge { target: f(target)? },
+            IfIcmpgt { target } => IfIcmpgt { target: f(target)? },
+            IfIcmple { target } => IfIcmple { target: f(target)? },
+            IfAcmpeq { target } => IfAcmpeq { target: f(target)? },
+            IfAcmpne { target } => IfAcmpne { target: f(target)? },
+            Goto { target } => Goto { target: f(target)? },
+            Jsr { target } => Jsr { target: f(target)? },
+            Ret { index } => Ret { index },
+            Tableswitch {
+                default,
+                low,
+                high,
+                targets,
+            } => Tableswitch {
+                default: f(default)?,
+                low,
+                high,
+                targets: targets.into_iter().map(&mut f).collect::<Result<_, _>>()?,
+            },
+            Lookupswitch { default, pairs } => Lookupswitch {
+                default: f(default)?,
+                pairs: pairs
+                    .into_iter()
+                    .map(|(key, target)| Ok((key, f(target)?)))
+                    .collect::<Result<_, _>>()?,
+            },
+            Ireturn => Ireturn,
+            Lreturn => Lreturn,
+            Freturn => Freturn,
+            Dreturn => Dreturn,
+            Areturn => Areturn,
+            Return => Return,
+            Getstatic { index } => Getstatic { index },
+            Putstatic { index } => Putstatic { index },
+            Getfield { index } => Getfield { index },
+            Putfield { index } => Putfield { index },
+            Invokevirtual { index } => Invokevirtual { index },
+            Invokespecial { index } => Invokespecial { index },
+            Invokestatic { index } => Invokestatic { index },
+            Invokeinterface { index, count } => Invokeinterface { index, count },
+            Invokedynamic { index } => Invokedynamic { index },
+            New { index } => New { index },
+            Newarray { atype } => Newarray { atype },
+            Anewarray { index } => Anewarray { index },
+            Arraylength => Arraylength,
+            Athrow => Athrow,
+            Checkcast { index } => Checkcast { index },
+            Instanceof { index } => Instanceof { index },
+            Monitorenter => Monitorenter,
+            Monitorexit => Monitorexit,
+            Multianewarray { index, dimensions } => Multianewarray { index, dimensions },
+            Ifnull { target } => Ifnull { target: f(target)? },
+            Ifnonnull { target } => Ifnonnull { target: f(target)? },
+            GotoW { target } => GotoW { target: f(target)? },
+            JsrW { target } => JsrW { target: f(target)? },
+        })
+    }
+}
+
+/// Iterates over the instructions in a code array, yielding each with its
+/// offset. Iteration stops after the first error.
+#[derive(Debug, Clone)]
+pub struct Instructions<'a> {
+    code: &'a [u8],
+    pc: usize,
+    failed: bool,
+}
+
+/// Decodes the `code` of an `Attribute::Code`.
+pub fn instructions(code: &[u8]) -> Instructions<'_> {
+    Instructions {
+        code,
+        pc: 0,
+        failed: false,
+    }
+}
+
+impl<'a> Iterator for Instructions<'a> {
+    type Item = Result<(u32, Instruction), ClassFileError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.pc >= self.code.len() {
+            return None;
+        }
+        let pc = self.pc;
+        match instruction(self.code, pc) {
+            Ok((rest, instruction)) => {
+                self.pc = self.code.len() - rest.len();
+                Some(Ok((pc as u32, instruction)))
+            }
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+                self.failed = true;
+                Some(Err(e.into_class_file_error(self.code)))
+            }
+            Err(nom::Err::Incomplete(_)) => {
+                unreachable!("complete parsers never return Incomplete")
+            }
+        }
+    }
+}
+
+fn instruction(code: &[u8], pc: usize) -> PResult<'_, Instruction> {
+    use Instruction::*;
+
+    let input = &code[pc..];
+    let (rest, opcode) = be_u8(input)?;
+    Ok(match opcode {
+        0x00 => (rest, Nop),
+        0x01 => (rest, AconstNull),
+        0x02 => (rest, IconstM1),
+        0x03 => (rest, Iconst0),
+        0x04 => (rest, Iconst1),
+        0x05 => (rest, Iconst2),
+        0x06 => (rest, Iconst3),
+        0x07 => (rest, Iconst4),
+        0x08 => (rest, Iconst5),
+        0x09 => (rest, Lconst0),
+        0x0a => (rest, Lconst1),
+        0x0b => (rest, Fconst0),
+        0x0c => (rest, Fconst1),
+        0x0d => (rest, Fconst2),
+        0x0e => (rest, Dconst0),
+        0x0f => (rest, Dconst1),
+        0x10 => {
+            let (rest, value) = be_i8(rest)?;
+            (rest, Bipush { value })
+        }
+        0x11 => {
+            let (rest, value) = be_i16(rest)?;
+            (rest, Sipush { value })
+        }
+        0x12 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Ldc { index: index as U2 })
+        }
+        0x13 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, LdcW { index })
+        }
+        0x14 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Ldc2W { index })
+        }
+        0x15 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Iload { index: index as U2 })
+        }
+        0x16 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Lload { index: index as U2 })
+        }
+        0x17 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Fload { index: index as U2 })
+        }
+        0x18 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Dload { index: index as U2 })
+        }
+        0x19 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Aload { index: index as U2 })
+        }
+        0x1a => (rest, Iload0),
+        0x1b => (rest, Iload1),
+        0x1c => (rest, Iload2),
+        0x1d => (rest, Iload3),
+        0x1e => (rest, Lload0),
+        0x1f => (rest, Lload1),
+        0x20 => (rest, Lload2),
+        0x21 => (rest, Lload3),
+        0x22 => (rest, Fload0),
+        0x23 => (rest, Fload1),
+        0x24 => (rest, Fload2),
+        0x25 => (rest, Fload3),
+        0x26 => (rest, Dload0),
+        0x27 => (rest, Dload1),
+        0x28 => (rest, Dload2),
+        0x29 => (rest, Dload3),
+        0x2a => (rest, Aload0),
+        0x2b => (rest, Aload1),
+        0x2c => (rest, Aload2),
+        0x2d => (rest, Aload3),
+        0x2e => (rest, Iaload),
+        0x2f => (rest, Laload),
+        0x30 => (rest, Faload),
+        0x31 => (rest, Daload),
+        0x32 => (rest, Aaload),
+        0x33 => (rest, Baload),
+        0x34 => (rest, Caload),
+        0x35 => (rest, Saload),
+        0x36 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Istore { index: index as U2 })
+        }
+        0x37 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Lstore { index: index as U2 })
+        }
+        0x38 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Fstore { index: index as U2 })
+        }
+        0x39 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Dstore { index: index as U2 })
+        }
+        0x3a => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Astore { index: index as U2 })
+        }
+        0x3b => (rest, Istore0),
+        0x3c => (rest, Istore1),
+        0x3d => (rest, Istore2),
+        0x3e => (rest, Istore3),
+        0x3f => (rest, Lstore0),
+        0x40 => (rest, Lstore1),
+        0x41 => (rest, Lstore2),
+        0x42 => (rest, Lstore3),
+        0x43 => (rest, Fstore0),
+        0x44 => (rest, Fstore1),
+        0x45 => (rest, Fstore2),
+        0x46 => (rest, Fstore3),
+        0x47 => (rest, Dstore0),
+        0x48 => (rest, Dstore1),
+        0x49 => (rest, Dstore2),
+        0x4a => (rest, Dstore3),
+        0x4b => (rest, Astore0),
+        0x4c => (rest, Astore1),
+        0x4d => (rest, Astore2),
+        0x4e => (rest, Astore3),
+        0x4f => (rest, Iastore),
+        0x50 => (rest, Lastore),
+        0x51 => (rest, Fastore),
+        0x52 => (rest, Dastore),
+        0x53 => (rest, Aastore),
+        0x54 => (rest, Bastore),
+        0x55 => (rest, Castore),
+        0x56 => (rest, Sastore),
+        0x57 => (rest, Pop),
+        0x58 => (rest, Pop2),
+        0x59 => (rest, Dup),
+        0x5a => (rest, DupX1),
+        0x5b => (rest, DupX2),
+        0x5c => (rest, Dup2),
+        0x5d => (rest, Dup2X1),
+        0x5e => (rest, Dup2X2),
+        0x5f => (rest, Swap),
+        0x60 => (rest, Iadd),
+        0x61 => (rest, Ladd),
+        0x62 => (rest, Fadd),
+        0x63 => (rest, Dadd),
+        0x64 => (rest, Isub),
+        0x65 => (rest, Lsub),
+        0x66 => (rest, Fsub),
+        0x67 => (rest, Dsub),
+        0x68 => (rest, Imul),
+        0x69 => (rest, Lmul),
+        0x6a => (rest, Fmul),
+        0x6b => (rest, Dmul),
+        0x6c => (rest, Idiv),
+        0x6d => (rest, Ldiv),
+        0x6e => (rest, Fdiv),
+        0x6f => (rest, Ddiv),
+        0x70 => (rest, Irem),
+        0x71 => (rest, Lrem),
+        0x72 => (rest, Frem),
+        0x73 => (rest, Drem),
+        0x74 => (rest, Ineg),
+        0x75 => (rest, Lneg),
+        0x76 => (rest, Fneg),
+        0x77 => (rest, Dneg),
+        0x78 => (rest, Ishl),
+        0x79 => (rest, Lshl),
+        0x7a => (rest, Ishr),
+        0x7b => (rest, Lshr),
+        0x7c => (rest, Iushr),
+        0x7d => (rest, Lushr),
+        0x7e => (rest, Iand),
+        0x7f => (rest, Land),
+        0x80 => (rest, Ior),
+        0x81 => (rest, Lor),
+        0x82 => (rest, Ixor),
+        0x83 => (rest, Lxor),
+        0x84 => {
+            let (rest, index) = be_u8(rest)?;
+            let (rest, value) = be_i8(rest)?;
+            (
+                rest,
+                Iinc {
+                    index: index as U2,
+                    value: value as i16,
+                },
+            )
+        }
+        0x85 => (rest, I2l),
+        0x86 => (rest, I2f),
+        0x87 => (rest, I2d),
+        0x88 => (rest, L2i),
+        0x89 => (rest, L2f),
+        0x8a => (rest, L2d),
+        0x8b => (rest, F2i),
+        0x8c => (rest, F2l),
+        0x8d => (rest, F2d),
+        0x8e => (rest, D2i),
+        0x8f => (rest, D2l),
+        0x90 => (rest, D2f),
+        0x91 => (rest, I2b),
+        0x92 => (rest, I2c),
+        0x93 => (rest, I2s),
+        0x94 => (rest, Lcmp),
+        0x95 => (rest, Fcmpl),
+        0x96 => (rest, Fcmpg),
+        0x97 => (rest, Dcmpl),
+        0x98 => (rest, Dcmpg),
+        0x99 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Ifeq { target })
+        }
+        0x9a => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Ifne { target })
+        }
+        0x9b => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Iflt { target })
+        }
+        0x9c => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Ifge { target })
+        }
+        0x9d => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Ifgt { target })
+        }
+        0x9e => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Ifle { target })
+        }
+        0x9f => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, IfIcmpeq { target })
+        }
+        0xa0 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, IfIcmpne { target })
+        }
+        0xa1 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, IfIcmplt { target })
+        }
+        0xa2 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, IfIcmpge { target })
+        }
+        0xa3 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, IfIcmpgt { target })
+        }
+        0xa4 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, IfIcmple { target })
+        }
+        0xa5 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, IfAcmpeq { target })
+        }
+        0xa6 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, IfAcmpne { target })
+        }
+        0xa7 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Goto { target })
+        }
+        0xa8 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Jsr { target })
+        }
+        0xa9 => {
+            let (rest, index) = be_u8(rest)?;
+            (rest, Ret { index: index as U2 })
+        }
+        0xaa => {
+            let (rest, _) = take(switch_padding(pc))(rest)?;
+            let (rest, default) = be_i32(rest)?;
+            let (rest, low) = be_i32(rest)?;
+            let (rest, high) = be_i32(rest)?;
+            if high < low {
+                return fail(input, ErrorKind::InvalidTableSwitch { low, high });
+            }
+            let n = (high as i64 - low as i64 + 1) as usize;
+            let (rest, offsets) = count(be_i32, n)(rest)?;
+            let default = branch_target(code, pc, default)?;
+            let targets = offsets
+                .into_iter()
+                .map(|offset| branch_target(code, pc, offset))
+                .collect::<Result<_, _>>()?;
+            (
+                rest,
+                Tableswitch {
+                    default,
+                    low,
+                    high,
+                    targets,
+                },
+            )
+        }
+        0xab => {
+            let (rest, _) = take(switch_padding(pc))(rest)?;
+            let (rest, default) = be_i32(rest)?;
+            let (rest, npairs) = be_i32(rest)?;
+            if npairs < 0 {
+                return fail(input, ErrorKind::InvalidLookupSwitch { npairs });
+            }
+            let (rest, raw_pairs) = count(
+                |i| {
+                    let (i, key) = be_i32(i)?;
+                    let (i, offset) = be_i32(i)?;
+                    Ok((i, (key, offset)))
+                },
+                npairs as usize,
+            )(rest)?;
+            let default = branch_target(code, pc, default)?;
+            let pairs = raw_pairs
+                .into_iter()
+                .map(|(key, offset)| Ok((key, branch_target(code, pc, offset)?)))
+                .collect::<Result<_, _>>()?;
+            (rest, Lookupswitch { default, pairs })
+        }
+        0xac => (rest, Ireturn),
+        0xad => (rest, Lreturn),
+        0xae => (rest, Freturn),
+        0xaf => (rest, Dreturn),
+        0xb0 => (rest, Areturn),
+        0xb1 => (rest, Return),
+        0xb2 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Getstatic { index })
+        }
+        0xb3 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Putstatic { index })
+        }
+        0xb4 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Getfield { index })
+        }
+        0xb5 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Putfield { index })
+        }
+        0xb6 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Invokevirtual { index })
+        }
+        0xb7 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Invokespecial { index })
+        }
+        0xb8 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Invokestatic { index })
+        }
+        0xb9 => {
+            let (rest, index) = be_u16(rest)?;
+            let (rest, count) = be_u8(rest)?;
+            // always zero
+            let (rest, _) = be_u8(rest)?;
+            (rest, Invokeinterface { index, count })
+        }
+        0xba => {
+            let (rest, index) = be_u16(rest)?;
+            // always zero
+            let (rest, _) = be_u16(rest)?;
+            (rest, Invokedynamic { index })
+        }
+        0xbb => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, New { index })
+        }
+        0xbc => {
+            let (rest, atype) = be_u8(rest)?;
+            match ArrayType::try_from(atype) {
+                Ok(atype) => (rest, Newarray { atype }),
+                Err(kind) => return fail(input, kind),
+            }
+        }
+        0xbd => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Anewarray { index })
+        }
+        0xbe => (rest, Arraylength),
+        0xbf => (rest, Athrow),
+        0xc0 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Checkcast { index })
+        }
+        0xc1 => {
+            let (rest, index) = be_u16(rest)?;
+            (rest, Instanceof { index })
+        }
+        0xc2 => (rest, Monitorenter),
+        0xc3 => (rest, Monitorexit),
+        0xc5 => {
+            let (rest, index) = be_u16(rest)?;
+            let (rest, dimensions) = be_u8(rest)?;
+            (rest, Multianewarray { index, dimensions })
+        }
+        0xc6 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Ifnull { target })
+        }
+        0xc7 => {
+            let (rest, offset) = be_i16(rest)?;
+            let target = branch_target(code, pc, offset as i32)?;
+            (rest, Ifnonnull { target })
+        }
+        0xc8 => {
+            let (rest, offset) = be_i32(rest)?;
+            let target = branch_target(code, pc, offset)?;
+            (rest, GotoW { target })
+        }
+        0xc9 => {
+            let (rest, offset) = be_i32(rest)?;
+            let target = branch_target(code, pc, offset)?;
+            (rest, JsrW { target })
+        }
+        // wide
+        0xc4 => {
+            let (rest, opcode) = be_u8(rest)?;
+            match opcode {
+                0x15 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Iload { index })
+                }
+                0x16 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Lload { index })
+                }
+                0x17 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Fload { index })
+                }
+                0x18 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Dload { index })
+                }
+                0x19 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Aload { index })
+                }
+                0x36 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Istore { index })
+                }
+                0x37 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Lstore { index })
+                }
+                0x38 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Fstore { index })
+                }
+                0x39 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Dstore { index })
+                }
+                0x3a => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Astore { index })
+                }
+                0x84 => {
+                    let (rest, index) = be_u16(rest)?;
+                    let (rest, value) = be_i16(rest)?;
+                    (rest, Iinc { index, value })
+                }
+                0xa9 => {
+                    let (rest, index) = be_u16(rest)?;
+                    (rest, Ret { index })
+                }
+                _ => return fail(input, ErrorKind::InvalidWideOpcode(opcode)),
+            }
+        }
+        _ => return fail(input, ErrorKind::UnknownOpcode(opcode)),
+    })
+}
+
+/// The number of padding bytes after a `tableswitch` or `lookupswitch` at
+/// `pc`, which align its operands to a multiple of four bytes from the start
+/// of the code.
+pub(crate) fn switch_padding(pc: usize) -> usize {
+    (4 - (pc + 1) % 4) % 4
+}
+
+/// Resolves a branch offset relative to the instruction at `pc` to an
+/// absolute offset, checking that it lands inside the code.
+fn branch_target(code: &[u8], pc: usize, offset: i32) -> Result<u32, nom::Err<ParseError<'_>>> {
+    let target = pc as i64 + offset as i64;
+    if target < 0 || target >= code.len() as i64 {
+        let kind = ErrorKind::InvalidBranchTarget(target);
+        return Err(nom::Err::Failure(ParseError::new(&code[pc..], kind)));
+    }
+    Ok(target as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(code: &[u8]) -> Vec<Result<(u32, Instruction), ClassFileError>> {
+        instructions(code).collect()
+    }
+
+    /// Decodes a code array that must hold exactly one instruction.
+    fn decode_one(code: &[u8]) -> Instruction {
+        match decode(code).as_slice() {
+            [Ok((0, instruction))] => instruction.clone(),
+            other => panic!("{:02x?} decoded to {:?}", code, other),
+        }
+    }
+
+    /// Decodes a code array whose first instruction fails.
+    fn decode_error(code: &[u8]) -> ClassFileError {
+        match decode(code).as_slice() {
+            [Err(err)] => err.clone(),
+            other => panic!("{:02x?} decoded to {:?}", code, other),
+        }
+    }
+
+    #[test]
+    fn wide_widens_each_allowed_opcode() {
+        use Instruction::*;
+
+        let index = 0x1234;
+        let cases = [
+            (0x15, Iload { index }),
+            (0x16, Lload { index }),
+            (0x17, Fload { index }),
+            (0x18, Dload { index }),
+            (0x19, Aload { index }),
+            (0x36, Istore { index }),
+            (0x37, Lstore { index }),
+            (0x38, Fstore { index }),
+            (0x39, Dstore { index }),
+            (0x3a, Astore { index }),
+            (0xa9, Ret { index }),
+        ];
+        for (opcode, expected) in cases {
+            assert_eq!(decode_one(&[0xc4, opcode, 0x12, 0x34]), expected);
+        }
+        assert_eq!(
+            decode_one(&[0xc4, 0x84, 0x12, 0x34, 0xff, 0xfe]),
+            Iinc { index, value: -2 }
+        );
+    }
+
+    #[test]
+    fn wide_rejects_other_opcodes() {
+        for opcode in [0x00, 0x10, 0x1a, 0xa7, 0xc4] {
+            let err = decode_error(&[0xc4, opcode, 0x00, 0x00]);
+            assert_eq!(err.kind, ErrorKind::InvalidWideOpcode(opcode));
+            assert_eq!(err.offset, 0);
+        }
+    }
+
+    #[test]
+    fn switches_are_padded_at_every_alignment() {
+        for pc in 0..4 {
+            let padding = vec![0; switch_padding(pc)];
+            assert_eq!((pc + 1 + padding.len()) % 4, 0);
+            // default and the single target branch back to the switch itself
+            let mut tableswitch = vec![0x00; pc];
+            tableswitch.push(0xaa);
+            tableswitch.extend(&padding);
+            for operand in [0i32, 7, 7, 0] {
+                tableswitch.extend(operand.to_be_bytes());
+            }
+            let decoded = decode(&tableswitch);
+            assert_eq!(decoded.len(), pc + 1);
+            assert_eq!(
+                decoded[pc].as_ref().unwrap(),
+                &(
+                    pc as u32,
+                    Instruction::Tableswitch {
+                        default: pc as u32,
+                        low: 7,
+                        high: 7,
+                        targets: vec![pc as u32],
+                    }
+                )
+            );
+
+            let mut lookupswitch = vec![0x00; pc];
+            lookupswitch.push(0xab);
+            lookupswitch.extend(&padding);
+            for operand in [0i32, 1, -5, 0] {
+                lookupswitch.extend(operand.to_be_bytes());
+            }
+            let decoded = decode(&lookupswitch);
+            assert_eq!(decoded.len(), pc + 1);
+            assert_eq!(
+                decoded[pc].as_ref().unwrap(),
+                &(
+                    pc as u32,
+                    Instruction::Lookupswitch {
+                        default: pc as u32,
+                        pairs: vec![(-5, pc as u32)],
+                    }
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn tableswitch_rejects_high_below_low() {
+        let mut code = vec![0xaa, 0, 0, 0];
+        for operand in [0i32, 1, 0] {
+            code.extend(operand.to_be_bytes());
+        }
+        let err = decode_error(&code);
+        assert_eq!(err.kind, ErrorKind::InvalidTableSwitch { low: 1, high: 0 });
+        assert_eq!(err.offset, 0);
+    }
+
+    #[test]
+    fn lookupswitch_rejects_negative_npairs() {
+        let mut code = vec![0xab, 0, 0, 0];
+        for operand in [0i32, -1] {
+            code.extend(operand.to_be_bytes());
+        }
+        let err = decode_error(&code);
+        assert_eq!(err.kind, ErrorKind::InvalidLookupSwitch { npairs: -1 });
+        assert_eq!(err.offset, 0);
+    }
+
+    #[test]
+    fn truncated_operands_fail() {
+        for code in [
+            &[0x10][..],
+            &[0x11, 0x00],
+            &[0x13, 0x00],
+            &[0xa7, 0x00],
+            &[0xc8, 0x00, 0x00, 0x00],
+            &[0xc4, 0x15, 0x00],
+            &[0xc4],
+            &[0xaa, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ] {
+            assert_eq!(
+                decode_error(code).kind,
+                ErrorKind::Truncated,
+                "{:02x?}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn branch_targets_must_land_in_the_code() {
+        let err = decode_error(&[0xa7, 0x00, 0x03]);
+        assert_eq!(err.kind, ErrorKind::InvalidBranchTarget(3));
+        assert_eq!(err.offset, 0);
+
+        let decoded = decode(&[0x00, 0xa7, 0xff, 0xfe]);
+        let err = decoded[1].as_ref().unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidBranchTarget(-1));
+        assert_eq!(err.offset, 1);
+
+        assert_eq!(
+            decode_one(&[0xc8, 0x00, 0x00, 0x00, 0x00]),
+            Instruction::GotoW { target: 0 }
+        );
+    }
+
+    #[test]
+    fn iteration_stops_after_the_first_error() {
+        let mut instructions = instructions(&[0x00, 0xcb, 0x00, 0x00]);
+        assert_eq!(instructions.next().unwrap().unwrap(), (0, Instruction::Nop));
+        let err = instructions.next().unwrap().unwrap_err();
+        assert_eq!(err.kind, ErrorKind::UnknownOpcode(0xcb));
+        assert_eq!(err.offset, 1);
+        assert!(instructions.next().is_none());
+        assert!(instructions.next().is_none());
+    }
+}
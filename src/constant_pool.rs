@@ -1,7 +1,13 @@
-use crate::{U1, U2};
+use std::borrow::Cow;
+
+use crate::{
+    descriptor::{FieldType, MethodDescriptor},
+    error::ErrorKind,
+    mutf8, U1, U2,
+};
 
 #[derive(Debug, Clone)]
-pub enum ConstantPoolType {
+pub enum ConstantPoolType<'a> {
     Class {
         name_index: U2,
     },
@@ -37,7 +43,7 @@ pub enum ConstantPoolType {
         descriptor_index: U2,
     },
     Utf8 {
-        bytes: Vec<U1>,
+        bytes: Cow<'a, [U1]>,
     },
     MethodHandle {
         reference_kind: U1,
@@ -46,13 +52,23 @@ pub enum ConstantPoolType {
     MethodType {
         descriptor_index: U2,
     },
+    Dynamic {
+        bootstrap_method_attr_index: U2,
+        name_and_type_index: U2,
+    },
     InvokeDynamic {
         bootstrap_method_attr_index: U2,
         name_and_type_index: U2,
     },
+    Module {
+        name_index: U2,
+    },
+    Package {
+        name_index: U2,
+    },
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConstantPoolTag {
     Class,
     Fieldref,
@@ -67,12 +83,17 @@ pub enum ConstantPoolTag {
     Utf8,
     MethodHandle,
     MethodType,
+    Dynamic,
     InvokeDynamic,
+    Module,
+    Package,
 }
 
-impl From<u8> for ConstantPoolTag {
-    fn from(b: u8) -> Self {
-        match b {
+impl TryFrom<u8> for ConstantPoolTag {
+    type Error = ErrorKind;
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        Ok(match b {
             9 => Self::Fieldref,
             7 => Self::Class,
             10 => Self::Methodref,
@@ -86,15 +107,589 @@ impl From<u8> for ConstantPoolTag {
             1 => Self::Utf8,
             15 => Self::MethodHandle,
             16 => Self::MethodType,
+            17 => Self::Dynamic,
             18 => Self::InvokeDynamic,
-            _ => unreachable!(),
+            19 => Self::Module,
+            20 => Self::Package,
+            _ => return Err(ErrorKind::UnknownConstantPoolTag(b)),
+        })
+    }
+}
+
+impl From<ConstantPoolTag> for U1 {
+    fn from(tag: ConstantPoolTag) -> Self {
+        match tag {
+            ConstantPoolTag::Utf8 => 1,
+            ConstantPoolTag::Integer => 3,
+            ConstantPoolTag::Float => 4,
+            ConstantPoolTag::Long => 5,
+            ConstantPoolTag::Double => 6,
+            ConstantPoolTag::Class => 7,
+            ConstantPoolTag::String => 8,
+            ConstantPoolTag::Fieldref => 9,
+            ConstantPoolTag::Methodref => 10,
+            ConstantPoolTag::InterfaceMethodref => 11,
+            ConstantPoolTag::NameAndType => 12,
+            ConstantPoolTag::MethodHandle => 15,
+            ConstantPoolTag::MethodType => 16,
+            ConstantPoolTag::Dynamic => 17,
+            ConstantPoolTag::InvokeDynamic => 18,
+            ConstantPoolTag::Module => 19,
+            ConstantPoolTag::Package => 20,
+        }
+    }
+}
+
+impl ConstantPoolTag {
+    /// The first class file major version in which the tag may appear.
+    pub fn since_major_version(self) -> U2 {
+        match self {
+            Self::MethodHandle | Self::MethodType | Self::InvokeDynamic => 51,
+            Self::Module | Self::Package => 53,
+            Self::Dynamic => 55,
+            _ => 45,
+        }
+    }
+
+    /// The name of the tag in the JVMS without its `CONSTANT_` prefix, e.g.
+    /// `Methodref`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Class => "Class",
+            Self::Fieldref => "Fieldref",
+            Self::Methodref => "Methodref",
+            Self::InterfaceMethodref => "InterfaceMethodref",
+            Self::String => "String",
+            Self::Integer => "Integer",
+            Self::Float => "Float",
+            Self::Long => "Long",
+            Self::Double => "Double",
+            Self::NameAndType => "NameAndType",
+            Self::Utf8 => "Utf8",
+            Self::MethodHandle => "MethodHandle",
+            Self::MethodType => "MethodType",
+            Self::Dynamic => "Dynamic",
+            Self::InvokeDynamic => "InvokeDynamic",
+            Self::Module => "Module",
+            Self::Package => "Package",
+        }
+    }
+}
+
+impl ConstantPoolType<'_> {
+    /// Copies the bytes of a `Utf8` entry borrowed from the parsed input.
+    pub fn into_owned(self) -> ConstantPoolType<'static> {
+        match self {
+            Self::Class { name_index } => ConstantPoolType::Class { name_index },
+            Self::Fieldref {
+                class_index,
+                name_and_type_index,
+            } => ConstantPoolType::Fieldref {
+                class_index,
+                name_and_type_index,
+            },
+            Self::Methodref {
+                class_index,
+                name_and_type_index,
+            } => ConstantPoolType::Methodref {
+                class_index,
+                name_and_type_index,
+            },
+            Self::InterfaceMethodref {
+                class_index,
+                name_and_type_index,
+            } => ConstantPoolType::InterfaceMethodref {
+                class_index,
+                name_and_type_index,
+            },
+            Self::String { string_index } => ConstantPoolType::String { string_index },
+            Self::Integer { bytes } => ConstantPoolType::Integer { bytes },
+            Self::Float { bytes } => ConstantPoolType::Float { bytes },
+            Self::Long { val } => ConstantPoolType::Long { val },
+            Self::Double { val } => ConstantPoolType::Double { val },
+            Self::NameAndType {
+                name_index,
+                descriptor_index,
+            } => ConstantPoolType::NameAndType {
+                name_index,
+                descriptor_index,
+            },
+            Self::Utf8 { bytes } => ConstantPoolType::Utf8 {
+                bytes: Cow::Owned(bytes.into_owned()),
+            },
+            Self::MethodHandle {
+                reference_kind,
+                reference_index,
+            } => ConstantPoolType::MethodHandle {
+                reference_kind,
+                reference_index,
+            },
+            Self::MethodType { descriptor_index } => {
+                ConstantPoolType::MethodType { descriptor_index }
+            }
+            Self::Dynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            } => ConstantPoolType::Dynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            },
+            Self::InvokeDynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            } => ConstantPoolType::InvokeDynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            },
+            Self::Module { name_index } => ConstantPoolType::Module { name_index },
+            Self::Package { name_index } => ConstantPoolType::Package { name_index },
+        }
+    }
+
+    /// A `Utf8` entry holding `s` in modified UTF-8.
+    pub fn utf8(s: &str) -> Self {
+        Self::Utf8 {
+            bytes: Cow::Owned(mutf8::encode(s).into_owned()),
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i32> {
+        match self {
+            Self::Integer { bytes } => Some(i32::from_be_bytes(*bytes)),
+            _ => None,
+        }
+    }
+
+    /// The value of a `Float` entry, with NaN payloads kept bit for bit.
+    pub fn as_float(&self) -> Option<f32> {
+        match self {
+            Self::Float { bytes } => Some(f32::from_bits(u32::from_be_bytes(*bytes))),
+            _ => None,
+        }
+    }
+
+    pub fn as_long(&self) -> Option<i64> {
+        match self {
+            Self::Long { val } => Some(i64::from_be_bytes(*val)),
+            _ => None,
+        }
+    }
+
+    /// The value of a `Double` entry, with NaN payloads kept bit for bit.
+    pub fn as_double(&self) -> Option<f64> {
+        match self {
+            Self::Double { val } => Some(f64::from_bits(u64::from_be_bytes(*val))),
+            _ => None,
+        }
+    }
+
+    /// The text of a `Utf8` entry, decoded from modified UTF-8 and borrowed
+    /// where possible. Fails like [`mutf8::decode`]; [`ConstantPool::get_str`]
+    /// resolves an index the same way.
+    pub fn as_cow_str(&self) -> Option<Result<Cow<'_, str>, ErrorKind>> {
+        match self {
+            Self::Utf8 { bytes } => Some(mutf8::decode(bytes)),
+            _ => None,
+        }
+    }
+
+    /// Whether the entry takes up two constant pool slots.
+    pub fn is_wide(&self) -> bool {
+        matches!(self, Self::Long { .. } | Self::Double { .. })
+    }
+
+    pub fn tag(&self) -> ConstantPoolTag {
+        match self {
+            Self::Class { .. } => ConstantPoolTag::Class,
+            Self::Fieldref { .. } => ConstantPoolTag::Fieldref,
+            Self::Methodref { .. } => ConstantPoolTag::Methodref,
+            Self::InterfaceMethodref { .. } => ConstantPoolTag::InterfaceMethodref,
+            Self::String { .. } => ConstantPoolTag::String,
+            Self::Integer { .. } => ConstantPoolTag::Integer,
+            Self::Float { .. } => ConstantPoolTag::Float,
+            Self::Long { .. } => ConstantPoolTag::Long,
+            Self::Double { .. } => ConstantPoolTag::Double,
+            Self::NameAndType { .. } => ConstantPoolTag::NameAndType,
+            Self::Utf8 { .. } => ConstantPoolTag::Utf8,
+            Self::MethodHandle { .. } => ConstantPoolTag::MethodHandle,
+            Self::MethodType { .. } => ConstantPoolTag::MethodType,
+            Self::Dynamic { .. } => ConstantPoolTag::Dynamic,
+            Self::InvokeDynamic { .. } => ConstantPoolTag::InvokeDynamic,
+            Self::Module { .. } => ConstantPoolTag::Module,
+            Self::Package { .. } => ConstantPoolTag::Package,
+        }
+    }
+}
+
+/// The constant pool of a class file.
+///
+/// Entries are indexed from 1 as in the JVM spec. Slot 0 and the slot that
+/// follows every `Long` or `Double` entry are unusable and hold `None`.
+#[derive(Debug, Clone)]
+pub struct ConstantPool<'a> {
+    entries: Vec<Option<ConstantPoolType<'a>>>,
+}
+
+impl<'a> ConstantPool<'a> {
+    pub fn new() -> Self {
+        Self {
+            entries: vec![None],
+        }
+    }
+
+    /// Appends an entry, returning its index. `Long` and `Double` entries
+    /// also reserve the following slot. Fails with
+    /// [`ErrorKind::TooManyItems`] once the `constant_pool_count` would no
+    /// longer fit in a `u2`.
+    pub fn push(&mut self, entry: ConstantPoolType<'a>) -> Result<U2, ErrorKind> {
+        let wide = entry.is_wide();
+        let slots = self.entries.len() + 1 + usize::from(wide);
+        if U2::try_from(slots).is_err() {
+            return Err(ErrorKind::TooManyItems(slots));
+        }
+        let index = self.entries.len() as U2;
+        self.entries.push(Some(entry));
+        if wide {
+            self.entries.push(None);
+        }
+        Ok(index)
+    }
+
+    /// The `constant_pool_count` of the class file: one more than the
+    /// highest valid index.
+    pub fn count(&self) -> U2 {
+        U2::try_from(self.slots()).expect("push keeps the count within a u2")
+    }
+
+    /// The number of slots, counting slot 0 and the second slot of every
+    /// `Long` and `Double` entry.
+    pub fn slots(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn get(&self, index: U2) -> Result<&ConstantPoolType<'a>, ErrorKind> {
+        match self.entries.get(index as usize) {
+            Some(Some(entry)) => Ok(entry),
+            _ => Err(ErrorKind::BadConstantPoolIndex(index)),
+        }
+    }
+
+    pub fn get_utf8(&self, index: U2) -> Result<&[u8], ErrorKind> {
+        match self.get(index)? {
+            ConstantPoolType::Utf8 { bytes } => Ok(bytes),
+            other => Err(ErrorKind::WrongConstantPoolEntry {
+                index,
+                expected: ConstantPoolTag::Utf8,
+                found: other.tag(),
+            }),
+        }
+    }
+
+    pub fn get_int(&self, index: U2) -> Result<i32, ErrorKind> {
+        self.get_as(index, ConstantPoolTag::Integer, ConstantPoolType::as_int)
+    }
+
+    pub fn get_float(&self, index: U2) -> Result<f32, ErrorKind> {
+        self.get_as(index, ConstantPoolTag::Float, ConstantPoolType::as_float)
+    }
+
+    pub fn get_long(&self, index: U2) -> Result<i64, ErrorKind> {
+        self.get_as(index, ConstantPoolTag::Long, ConstantPoolType::as_long)
+    }
+
+    pub fn get_double(&self, index: U2) -> Result<f64, ErrorKind> {
+        self.get_as(index, ConstantPoolTag::Double, ConstantPoolType::as_double)
+    }
+
+    fn get_as<T>(
+        &self,
+        index: U2,
+        expected: ConstantPoolTag,
+        value: impl FnOnce(&ConstantPoolType<'a>) -> Option<T>,
+    ) -> Result<T, ErrorKind> {
+        let entry = self.get(index)?;
+        value(entry).ok_or(ErrorKind::WrongConstantPoolEntry {
+            index,
+            expected,
+            found: entry.tag(),
+        })
+    }
+
+    /// The index of the first `Utf8` entry holding `bytes`.
+    pub fn find_utf8(&self, bytes: &[u8]) -> Option<U2> {
+        self.iter().find_map(|(index, entry)| match entry {
+            ConstantPoolType::Utf8 { bytes: candidate } if candidate.as_ref() == bytes => {
+                Some(index)
+            }
+            _ => None,
+        })
+    }
+
+    /// Resolves a `Utf8` entry to a string, decoding its modified UTF-8.
+    pub fn get_str(&self, index: U2) -> Result<Cow<'_, str>, ErrorKind> {
+        mutf8::decode(self.get_utf8(index)?).map_err(|kind| kind.in_entry(index))
+    }
+
+    /// Resolves a `Utf8` entry to the field descriptor it holds.
+    pub fn get_field_type(&self, index: U2) -> Result<FieldType, ErrorKind> {
+        FieldType::parse(&self.get_str(index)?).map_err(|kind| kind.in_entry(index))
+    }
+
+    /// Resolves a `Utf8` entry to the method descriptor it holds.
+    pub fn get_method_descriptor(&self, index: U2) -> Result<MethodDescriptor, ErrorKind> {
+        MethodDescriptor::parse(&self.get_str(index)?).map_err(|kind| kind.in_entry(index))
+    }
+
+    /// Resolves a `Class` entry to its internal name, e.g. `java/lang/Object`.
+    pub fn get_class_name(&self, index: U2) -> Result<Cow<'_, str>, ErrorKind> {
+        self.get_name(index, ConstantPoolTag::Class)
+    }
+
+    pub fn get_module_name(&self, index: U2) -> Result<Cow<'_, str>, ErrorKind> {
+        self.get_name(index, ConstantPoolTag::Module)
+    }
+
+    pub fn get_package_name(&self, index: U2) -> Result<Cow<'_, str>, ErrorKind> {
+        self.get_name(index, ConstantPoolTag::Package)
+    }
+
+    fn get_name(&self, index: U2, expected: ConstantPoolTag) -> Result<Cow<'_, str>, ErrorKind> {
+        let entry = self.get(index)?;
+        match entry {
+            ConstantPoolType::Class { name_index }
+            | ConstantPoolType::Module { name_index }
+            | ConstantPoolType::Package { name_index }
+                if entry.tag() == expected =>
+            {
+                self.get_str(*name_index)
+            }
+            _ => Err(ErrorKind::WrongConstantPoolEntry {
+                index,
+                expected,
+                found: entry.tag(),
+            }),
         }
     }
+
+    /// Copies the bytes of every `Utf8` entry borrowed from the parsed input.
+    pub fn into_owned(self) -> ConstantPool<'static> {
+        ConstantPool {
+            entries: self
+                .entries
+                .into_iter()
+                .map(|entry| entry.map(ConstantPoolType::into_owned))
+                .collect(),
+        }
+    }
+
+    /// Iterates over the usable entries along with their indices.
+    pub fn iter(&self) -> impl Iterator<Item = (U2, &ConstantPoolType<'a>)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| Some((U2::try_from(index).ok()?, entry.as_ref()?)))
+    }
+}
+
+impl Default for ConstantPool<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-pub fn get_utf8(constant_pool: &[ConstantPoolType], index: usize) -> &[u8] {
-    match constant_pool.get(index) {
-        Some(ConstantPoolType::Utf8 { bytes }) => bytes,
-        _ => unreachable!(),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_stops_at_the_largest_count() {
+        let mut pool = ConstantPool::new();
+        for i in 1..U2::MAX {
+            assert_eq!(
+                pool.push(ConstantPoolType::Integer { bytes: [0; 4] }),
+                Ok(i)
+            );
+        }
+        assert_eq!(pool.count(), U2::MAX);
+        assert_eq!(
+            pool.push(ConstantPoolType::Integer { bytes: [0; 4] }),
+            Err(ErrorKind::TooManyItems(0x1_0000))
+        );
+        assert_eq!(pool.count(), U2::MAX);
+        assert_eq!(
+            pool.iter().last().map(|(index, _)| index),
+            Some(U2::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn push_keeps_room_for_the_second_slot_of_wide_entries() {
+        let mut pool = ConstantPool::new();
+        for _ in 2..U2::MAX {
+            pool.push(ConstantPoolType::Integer { bytes: [0; 4] })
+                .unwrap();
+        }
+        assert_eq!(
+            pool.push(ConstantPoolType::Long { val: [0; 8] }),
+            Err(ErrorKind::TooManyItems(0x1_0000))
+        );
+        assert_eq!(
+            pool.push(ConstantPoolType::Integer { bytes: [0; 4] }),
+            Ok(U2::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn utf8_entries_decode_modified_utf8() {
+        let entry = ConstantPoolType::utf8("a\0\u{1f600}");
+        assert_eq!(entry.as_cow_str(), Some(Ok(Cow::Borrowed("a\0\u{1f600}"))));
+        let entry = ConstantPoolType::Utf8 {
+            bytes: Cow::Borrowed(b"a\0"),
+        };
+        assert_eq!(
+            entry.as_cow_str(),
+            Some(Err(ErrorKind::InvalidModifiedUtf8 {
+                index: None,
+                offset: 1
+            }))
+        );
+        assert_eq!(
+            ConstantPoolType::Integer { bytes: [0; 4] }.as_cow_str(),
+            None
+        );
+    }
+
+    #[test]
+    fn errors_name_the_entry_they_were_read_from() {
+        let mut pool = ConstantPool::new();
+        pool.push(ConstantPoolType::Utf8 {
+            bytes: Cow::Borrowed(b"a\0"),
+        })
+        .unwrap();
+        pool.push(ConstantPoolType::utf8("(I)VX")).unwrap();
+        assert_eq!(
+            pool.get_str(1),
+            Err(ErrorKind::InvalidModifiedUtf8 {
+                index: Some(1),
+                offset: 1
+            })
+        );
+        assert_eq!(
+            pool.get_method_descriptor(2),
+            Err(ErrorKind::InvalidDescriptor {
+                index: Some(2),
+                offset: 4
+            })
+        );
+        assert_eq!(
+            pool.get_field_type(1),
+            Err(ErrorKind::InvalidModifiedUtf8 {
+                index: Some(1),
+                offset: 1
+            })
+        );
+    }
+
+    /// A constant pool with a `Float` at #1, a `Double` at #2, an `Integer` at #4
+    /// and a `Long` at #5, holding the given bits.
+    fn numbers(float: u32, double: u64) -> ConstantPool<'static> {
+        let mut pool = ConstantPool::new();
+        for entry in [
+            ConstantPoolType::Float {
+                bytes: float.to_be_bytes(),
+            },
+            ConstantPoolType::Double {
+                val: double.to_be_bytes(),
+            },
+            ConstantPoolType::Integer {
+                bytes: 7i32.to_be_bytes(),
+            },
+            ConstantPoolType::Long {
+                val: (-7i64).to_be_bytes(),
+            },
+            ConstantPoolType::utf8("Numbers"),
+            ConstantPoolType::Class { name_index: 7 },
+            ConstantPoolType::utf8("I"),
+            ConstantPoolType::utf8("x"),
+        ] {
+            pool.push(entry).unwrap();
+        }
+        pool
+    }
+
+    #[test]
+    fn nan_payloads_survive_parsing_and_writing() {
+        // A quiet NaN with a payload, and a signalling one.
+        for (float, double) in [
+            (0x7fc0_0001, 0x7ff8_0000_0000_0001),
+            (0xff80_0001, 0xfff0_0000_0000_0001),
+        ] {
+            let class_file = crate::ClassFile {
+                version: crate::Version {
+                    minor: 0,
+                    major: 52,
+                },
+                constant_pool: numbers(float, double),
+                access_flags: crate::access::ClassAccess::PUBLIC,
+                this_class: 8,
+                super_class: 0,
+                interfaces: Vec::new(),
+                fields: Vec::new(),
+                methods: Vec::new(),
+                attributes: Vec::new(),
+                attribute_name_indices: Vec::new(),
+            };
+            let bytes = class_file.to_bytes().unwrap();
+            let parsed = crate::parse(&bytes).unwrap();
+            for pool in [&class_file.constant_pool, &parsed.constant_pool] {
+                let f = pool.get_float(1).unwrap();
+                assert!(f.is_nan());
+                assert_eq!(f.to_bits(), float);
+                assert_eq!(pool.get(1).unwrap().as_float().unwrap().to_bits(), float);
+                let d = pool.get_double(2).unwrap();
+                assert!(d.is_nan());
+                assert_eq!(d.to_bits(), double);
+                assert_eq!(pool.get(2).unwrap().as_double().unwrap().to_bits(), double);
+            }
+        }
+    }
+
+    #[test]
+    fn numeric_accessors_reject_other_entries() {
+        let pool = numbers(0, 0);
+        assert_eq!(pool.get_int(4), Ok(7));
+        assert_eq!(pool.get_long(5), Ok(-7));
+        let wrong = |index, expected, found| ErrorKind::WrongConstantPoolEntry {
+            index,
+            expected,
+            found,
+        };
+        use ConstantPoolTag::*;
+        assert_eq!(pool.get_int(1), Err(wrong(1, Integer, Float)));
+        assert_eq!(pool.get_float(4), Err(wrong(4, Float, Integer)));
+        assert_eq!(pool.get_long(2), Err(wrong(2, Long, Double)));
+        assert_eq!(pool.get_double(5), Err(wrong(5, Double, Long)));
+        assert_eq!(pool.get_int(7), Err(wrong(7, Integer, Utf8)));
+        // The second slots of wide entries and indices past the end are not
+        // entries at all.
+        assert_eq!(pool.get_double(3), Err(ErrorKind::BadConstantPoolIndex(3)));
+        assert_eq!(pool.get_int(0), Err(ErrorKind::BadConstantPoolIndex(0)));
+        assert_eq!(pool.get_int(99), Err(ErrorKind::BadConstantPoolIndex(99)));
+
+        // Constant values are checked against the field's descriptor.
+        let field = crate::FieldInfo {
+            access_flags: crate::access::FieldAccess::STATIC,
+            name_index: 10,
+            descriptor_index: 9,
+            attributes: vec![crate::Attribute::ConstantValue {
+                constant_value_index: 5,
+            }],
+            attribute_name_indices: Vec::new(),
+        };
+        assert_eq!(field.constant_value(&pool), Err(wrong(5, Integer, Long)));
+        let element = crate::ElementValue::Float { index: 4 };
+        assert_eq!(element.constant(&pool), Err(wrong(4, Float, Integer)));
+        let element = crate::ElementValue::Long { index: 5 };
+        assert_eq!(element.constant(&pool), Ok(Some(crate::Constant::Long(-7))));
     }
 }
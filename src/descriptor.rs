@@ -0,0 +1,354 @@
+use std::fmt;
+
+use crate::error::ErrorKind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BaseType {
+    Byte,
+    Char,
+    Double,
+    Float,
+    Int,
+    Long,
+    Short,
+    Boolean,
+}
+
+impl BaseType {
+    pub fn from_descriptor(c: u8) -> Option<Self> {
+        Some(match c {
+            b'B' => Self::Byte,
+            b'C' => Self::Char,
+            b'D' => Self::Double,
+            b'F' => Self::Float,
+            b'I' => Self::Int,
+            b'J' => Self::Long,
+            b'S' => Self::Short,
+            b'Z' => Self::Boolean,
+            _ => return None,
+        })
+    }
+
+    pub fn descriptor(self) -> char {
+        match self {
+            Self::Byte => 'B',
+            Self::Char => 'C',
+            Self::Double => 'D',
+            Self::Float => 'F',
+            Self::Int => 'I',
+            Self::Long => 'J',
+            Self::Short => 'S',
+            Self::Boolean => 'Z',
+        }
+    }
+
+    /// The keyword for the type, e.g. `int`.
+    pub fn java_name(self) -> &'static str {
+        match self {
+            Self::Byte => "byte",
+            Self::Char => "char",
+            Self::Double => "double",
+            Self::Float => "float",
+            Self::Int => "int",
+            Self::Long => "long",
+            Self::Short => "short",
+            Self::Boolean => "boolean",
+        }
+    }
+
+    /// The number of local variable slots a value of the type takes.
+    pub fn slots(self) -> usize {
+        match self {
+            Self::Long | Self::Double => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// The type of a field, parameter or return value, parsed from a descriptor
+/// such as `[Ljava/lang/String;`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FieldType {
+    Base(BaseType),
+    /// A class or interface, by internal name, e.g. `java/lang/String`.
+    Object(String),
+    /// An array of `dimensions` dimensions whose element is never itself an
+    /// array.
+    Array {
+        dimensions: u8,
+        element: Box<FieldType>,
+    },
+}
+
+impl FieldType {
+    /// Parses a field descriptor, failing with
+    /// [`ErrorKind::InvalidDescriptor`] at the first byte that cannot
+    /// belong to one.
+    pub fn parse(descriptor: &str) -> Result<Self, ErrorKind> {
+        let mut parser = Parser { descriptor, pos: 0 };
+        let ty = parser.field_type()?;
+        parser.end()?;
+        Ok(ty)
+    }
+
+    /// The number of local variable slots a value of the type takes: two for
+    /// `long` and `double`, one otherwise.
+    pub fn slots(&self) -> usize {
+        match self {
+            Self::Base(base) => base.slots(),
+            _ => 1,
+        }
+    }
+
+    /// Renders the type in Java syntax, e.g. `java.lang.String[]`.
+    pub fn to_java(&self) -> String {
+        match self {
+            Self::Base(base) => base.java_name().to_string(),
+            Self::Object(name) => name.replace('/', "."),
+            Self::Array {
+                dimensions,
+                element,
+            } => element.to_java() + &"[]".repeat(usize::from(*dimensions)),
+        }
+    }
+}
+
+/// Renders the type back into its descriptor.
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Base(base) => write!(f, "{}", base.descriptor()),
+            Self::Object(name) => write!(f, "L{};", name),
+            Self::Array {
+                dimensions,
+                element,
+            } => write!(f, "{}{}", "[".repeat(usize::from(*dimensions)), element),
+        }
+    }
+}
+
+/// A method descriptor such as `(I[Ljava/lang/String;)V`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MethodDescriptor {
+    pub params: Vec<FieldType>,
+    /// The return type, or `None` for `void`.
+    pub ret: Option<FieldType>,
+}
+
+impl MethodDescriptor {
+    /// Parses a method descriptor, failing with
+    /// [`ErrorKind::InvalidDescriptor`] at the first byte that cannot
+    /// belong to one.
+    pub fn parse(descriptor: &str) -> Result<Self, ErrorKind> {
+        let mut parser = Parser { descriptor, pos: 0 };
+        parser.expect(b'(')?;
+        let mut params = Vec::new();
+        while parser.peek() != Some(b')') {
+            params.push(parser.field_type()?);
+        }
+        parser.pos += 1;
+        let ret = match parser.peek() {
+            Some(b'V') => {
+                parser.pos += 1;
+                None
+            }
+            _ => Some(parser.field_type()?),
+        };
+        parser.end()?;
+        Ok(Self { params, ret })
+    }
+
+    /// The number of local variable slots the parameters take, not counting
+    /// `this`.
+    pub fn param_slots(&self) -> usize {
+        self.params.iter().map(FieldType::slots).sum()
+    }
+}
+
+/// Renders the descriptor back into its string form.
+impl fmt::Display for MethodDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("(")?;
+        for param in &self.params {
+            write!(f, "{}", param)?;
+        }
+        f.write_str(")")?;
+        match &self.ret {
+            Some(ret) => write!(f, "{}", ret),
+            None => f.write_str("V"),
+        }
+    }
+}
+
+struct Parser<'s> {
+    descriptor: &'s str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.descriptor.as_bytes().get(self.pos).copied()
+    }
+
+    fn error(&self) -> ErrorKind {
+        ErrorKind::InvalidDescriptor {
+            index: None,
+            offset: self.pos,
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), ErrorKind> {
+        if self.peek() != Some(c) {
+            return Err(self.error());
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn end(&self) -> Result<(), ErrorKind> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.error()),
+        }
+    }
+
+    fn field_type(&mut self) -> Result<FieldType, ErrorKind> {
+        let start = self.pos;
+        while self.peek() == Some(b'[') {
+            self.pos += 1;
+        }
+        let dimensions = self.pos - start;
+        if dimensions > usize::from(u8::MAX) {
+            return Err(ErrorKind::InvalidDescriptor {
+                index: None,
+                offset: start + usize::from(u8::MAX),
+            });
+        }
+        let element = match self.peek() {
+            Some(b'L') => {
+                self.pos += 1;
+                FieldType::Object(self.class_name()?)
+            }
+            Some(c) => {
+                let base = BaseType::from_descriptor(c).ok_or_else(|| self.error())?;
+                self.pos += 1;
+                FieldType::Base(base)
+            }
+            None => return Err(self.error()),
+        };
+        Ok(match dimensions {
+            0 => element,
+            dimensions => FieldType::Array {
+                dimensions: dimensions as u8,
+                element: Box::new(element),
+            },
+        })
+    }
+
+    /// Consumes an internal class name and the `;` ending it.
+    fn class_name(&mut self) -> Result<String, ErrorKind> {
+        let start = self.pos;
+        let mut segment_start = self.pos;
+        loop {
+            match self.peek() {
+                Some(b';') if self.pos > segment_start => break,
+                Some(b'/') if self.pos > segment_start => segment_start = self.pos + 1,
+                Some(b';' | b'/' | b'.' | b'[') | None => return Err(self.error()),
+                Some(_) => {}
+            }
+            self.pos += 1;
+        }
+        let name = self.descriptor[start..self.pos].to_string();
+        self.pos += 1;
+        Ok(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn malformed(offset: usize) -> ErrorKind {
+        ErrorKind::InvalidDescriptor {
+            index: None,
+            offset,
+        }
+    }
+
+    #[test]
+    fn descriptors_display_as_written() {
+        for descriptor in [
+            "I",
+            "J",
+            "Ljava/lang/String;",
+            "[[D",
+            "[Ljava/util/Map$Entry;",
+        ] {
+            assert_eq!(
+                FieldType::parse(descriptor).unwrap().to_string(),
+                descriptor
+            );
+        }
+        for descriptor in [
+            "()V",
+            "(IJ)D",
+            "([Ljava/lang/String;)V",
+            "(Ljava/lang/Object;[[IZ)Ljava/util/List;",
+        ] {
+            assert_eq!(
+                MethodDescriptor::parse(descriptor).unwrap().to_string(),
+                descriptor
+            );
+        }
+        assert_eq!(
+            FieldType::parse("[[Ljava/lang/String;").unwrap().to_java(),
+            "java.lang.String[][]"
+        );
+    }
+
+    #[test]
+    fn longs_and_doubles_take_two_slots() {
+        let slots = |descriptor| MethodDescriptor::parse(descriptor).unwrap().param_slots();
+        assert_eq!(slots("()V"), 0);
+        assert_eq!(slots("(IZ)V"), 2);
+        assert_eq!(slots("(JD)V"), 4);
+        assert_eq!(slots("(IJLjava/lang/Object;D)J"), 6);
+        // An array of longs is a reference.
+        assert_eq!(slots("([J[D)V"), 2);
+    }
+
+    #[test]
+    fn arrays_have_at_most_255_dimensions() {
+        let descriptor = "[".repeat(255) + "I";
+        assert_eq!(
+            FieldType::parse(&descriptor),
+            Ok(FieldType::Array {
+                dimensions: 255,
+                element: Box::new(FieldType::Base(BaseType::Int)),
+            })
+        );
+        assert_eq!(
+            FieldType::parse(&("[".repeat(256) + "I")),
+            Err(malformed(255))
+        );
+        let descriptor = format!("(J{}I)V", "[".repeat(256));
+        assert_eq!(MethodDescriptor::parse(&descriptor), Err(malformed(257)));
+    }
+
+    #[test]
+    fn errors_point_at_the_first_bad_byte() {
+        assert_eq!(MethodDescriptor::parse("(I"), Err(malformed(2)));
+        assert_eq!(MethodDescriptor::parse("()"), Err(malformed(2)));
+        assert_eq!(MethodDescriptor::parse("I)V"), Err(malformed(0)));
+        assert_eq!(MethodDescriptor::parse("(V)V"), Err(malformed(1)));
+        assert_eq!(MethodDescriptor::parse("()VI"), Err(malformed(3)));
+        assert_eq!(FieldType::parse("L;"), Err(malformed(1)));
+        assert_eq!(FieldType::parse("La//b;"), Err(malformed(3)));
+        assert_eq!(FieldType::parse("La/b"), Err(malformed(4)));
+        assert_eq!(FieldType::parse("La.b;"), Err(malformed(2)));
+        assert_eq!(FieldType::parse("II"), Err(malformed(1)));
+        assert_eq!(FieldType::parse("Ljava/lang/String;;"), Err(malformed(18)));
+        assert_eq!(FieldType::parse(""), Err(malformed(0)));
+        assert_eq!(FieldType::parse("["), Err(malformed(1)));
+    }
+}
@@ -0,0 +1,2082 @@
+use std::{borrow::Cow, fmt};
+
+use crate::{
+    access::{ClassAccess, ExportsFlags, FieldAccess, MethodAccess, ModuleFlags, RequiresFlags},
+    bytecode::{instructions, ArrayType, Instruction},
+    constant_pool::{ConstantPool, ConstantPoolTag, ConstantPoolType},
+    descriptor::FieldType,
+    error::ErrorKind,
+    mutf8,
+    signature::{ClassTypeSignature, JavaTypeSignature, ReferenceTypeSignature, TypeParameter},
+    Annotation, Attribute, ClassFile, ElementValue, ElementValuePair, FieldInfo, LocalVariable,
+    MethodInfo, StackMapFrame, TypeAnnotation, TypeAnnotationTarget, TypePathKind,
+    VerificationTypeInfo, U2,
+};
+
+const INDENT_WIDTH: usize = 2;
+const TAB_COLUMN: usize = 40;
+
+/// Renders a class file in the layout of `javap -c -v -p` from JDK 17.
+///
+/// The output starts at the `Compiled from` line: the `Classfile`,
+/// `Last modified` and checksum lines javap prints first describe the file
+/// on disk rather than the class, and are left out.
+///
+/// `float` and `double` constants are printed with the shortest digits that
+/// read back as the same value, as Java 19 and later do. JDK 17 sometimes
+/// prints a digit more, e.g. `1.07374182E9` for 2^30 as a `float` where this
+/// prints `1.0737418E9`, so its output can differ on such constants.
+pub fn disassemble(class_file: &ClassFile) -> Result<String, ErrorKind> {
+    let mut disassembler = Disassembler::new(class_file);
+    disassembler.class()?;
+    Ok(disassembler.out.output)
+}
+
+/// Renders just the `Constant pool:` section of [`disassemble`].
+pub fn constant_pool(class_file: &ClassFile) -> Result<String, ErrorKind> {
+    let mut disassembler = Disassembler::new(class_file);
+    disassembler.constant_pool()?;
+    Ok(disassembler.out.output)
+}
+
+/// Line-oriented output that indents lazily and drops trailing spaces, the
+/// way javap's own writer does.
+#[derive(Default)]
+struct Printer {
+    output: String,
+    line: String,
+    pending_spaces: usize,
+    level: usize,
+}
+
+impl Printer {
+    fn print(&mut self, text: impl fmt::Display) {
+        for c in text.to_string().chars() {
+            match c {
+                ' ' => self.pending_spaces += 1,
+                '\n' => self.newline(),
+                c => {
+                    if self.line.is_empty() {
+                        self.pending_spaces += self.level * INDENT_WIDTH;
+                    }
+                    self.line
+                        .extend(std::iter::repeat_n(' ', self.pending_spaces));
+                    self.pending_spaces = 0;
+                    self.line.push(c);
+                }
+            }
+        }
+    }
+
+    fn println(&mut self, text: impl fmt::Display) {
+        self.print(text);
+        self.newline();
+    }
+
+    fn newline(&mut self) {
+        self.pending_spaces = 0;
+        self.output.push_str(&self.line);
+        self.output.push('\n');
+        self.line.clear();
+    }
+
+    /// Pads to the comment column, or by a single space once past it.
+    fn tab(&mut self) {
+        let column = self.level * INDENT_WIDTH + TAB_COLUMN;
+        let len = self.line.chars().count();
+        self.pending_spaces += if column <= len { 1 } else { column - len };
+    }
+
+    fn indent(&mut self, delta: isize) {
+        self.level = self.level.wrapping_add_signed(delta);
+    }
+}
+
+struct Disassembler<'c> {
+    class_file: &'c ClassFile<'c>,
+    constant_pool: &'c ConstantPool<'c>,
+    out: Printer,
+}
+
+impl<'c> Disassembler<'c> {
+    fn new(class_file: &'c ClassFile<'c>) -> Self {
+        Self {
+            class_file,
+            constant_pool: &class_file.constant_pool,
+            out: Printer::default(),
+        }
+    }
+
+    fn class(&mut self) -> Result<(), ErrorKind> {
+        let class_file = self.class_file;
+        let flags = class_file.access_flags;
+        let is_interface = flags.is_interface();
+
+        for attribute in &class_file.attributes {
+            if let Attribute::SourceFile { source_file_index } = attribute {
+                let source_file = self.utf8(*source_file_index)?;
+                self.out.indent(1);
+                self.out
+                    .println(format_args!("Compiled from \"{}\"", source_file));
+                self.out.indent(-1);
+            }
+        }
+
+        self.modifiers(flags.to_java(), None);
+        let module = class_file
+            .attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Module {
+                    module_name_index,
+                    module_flags,
+                    module_version_index,
+                    ..
+                } => Some((*module_name_index, *module_flags, *module_version_index)),
+                _ => None,
+            });
+        match module {
+            Some((name_index, module_flags, version_index)) if flags.is_module() => {
+                if module_flags.is_open() {
+                    self.out.print("open ");
+                }
+                let name = java_name(&self.constant_pool.get_module_name(name_index)?);
+                self.out.print(format_args!("module {}", name));
+                if version_index != 0 {
+                    self.out
+                        .print(format_args!("@{}", self.utf8(version_index)?));
+                }
+            }
+            _ => {
+                self.out
+                    .print(if is_interface { "interface " } else { "class " });
+                self.out.print(self.this_class_name()?);
+            }
+        }
+
+        match class_file.signature()? {
+            None => {
+                if !is_interface && class_file.super_class != 0 {
+                    let super_name = self.java_class_name(class_file.super_class)?;
+                    if super_name != "java.lang.Object" {
+                        self.out.print(format_args!(" extends {}", super_name));
+                    }
+                }
+                for (i, &interface) in class_file.interfaces.iter().enumerate() {
+                    self.out.print(match (i, is_interface) {
+                        (0, false) => " implements ",
+                        (0, true) => " extends ",
+                        _ => ",",
+                    });
+                    self.out.print(self.java_class_name(interface)?);
+                }
+            }
+            // javap cannot tell a signature with just a superclass from a
+            // field signature, and shows it as one even for interfaces.
+            Some(signature)
+                if signature.type_params.is_empty() && signature.interfaces.is_empty() =>
+            {
+                self.out
+                    .print(format_args!(" extends {}", signature.superclass.to_java()));
+            }
+            Some(signature) => {
+                let mut declaration = String::new();
+                if !signature.type_params.is_empty() {
+                    declaration.push_str(&format!(
+                        "<{}>",
+                        join(&signature.type_params, TypeParameter::to_java)
+                    ));
+                }
+                if is_interface {
+                    if !signature.interfaces.is_empty() {
+                        declaration.push_str(" extends ");
+                        declaration
+                            .push_str(&join(&signature.interfaces, ClassTypeSignature::to_java));
+                    }
+                } else {
+                    declaration.push_str(" extends ");
+                    declaration.push_str(&signature.superclass.to_java());
+                    if !signature.interfaces.is_empty() {
+                        declaration.push_str(" implements ");
+                        declaration
+                            .push_str(&join(&signature.interfaces, ClassTypeSignature::to_java));
+                    }
+                }
+                self.out.print(declaration);
+            }
+        }
+        self.out.newline();
+
+        self.out.indent(1);
+        self.out
+            .println(format_args!("minor version: {}", class_file.version.minor));
+        self.out
+            .println(format_args!("major version: {}", class_file.version.major));
+        self.flags(flags, ClassAccess::NAMES);
+        self.out
+            .print(format_args!("this_class: #{}", class_file.this_class));
+        if class_file.this_class != 0 {
+            self.out.tab();
+            self.out.print(format_args!(
+                "// {}",
+                self.string_value(class_file.this_class)?
+            ));
+        }
+        self.out.newline();
+        self.out
+            .print(format_args!("super_class: #{}", class_file.super_class));
+        if class_file.super_class != 0 {
+            self.out.tab();
+            self.out.print(format_args!(
+                "// {}",
+                self.string_value(class_file.super_class)?
+            ));
+        }
+        self.out.newline();
+        self.out.println(format_args!(
+            "interfaces: {}, fields: {}, methods: {}, attributes: {}",
+            class_file.interfaces.len(),
+            class_file.fields.len(),
+            class_file.methods.len(),
+            class_file.attributes.len()
+        ));
+        self.out.indent(-1);
+        self.constant_pool()?;
+
+        self.out.println("{");
+        self.out.indent(1);
+        for field in &class_file.fields {
+            self.field(field)?;
+        }
+        for (i, method) in class_file.methods.iter().enumerate() {
+            if i > 0 {
+                self.out.newline();
+            }
+            self.method(method)?;
+        }
+        self.out.indent(-1);
+        self.out.println("}");
+
+        self.attributes(&class_file.attributes, None)
+    }
+
+    fn constant_pool(&mut self) -> Result<(), ErrorKind> {
+        self.out.println("Constant pool:");
+        self.out.indent(1);
+        let width = self.constant_pool.count().to_string().len() + 1;
+        for (index, entry) in self.constant_pool.iter() {
+            self.out.print(format_args!(
+                "{:>width$}",
+                format!("#{}", index),
+                width = width
+            ));
+            self.out
+                .print(format_args!(" = {:<18} ", entry.tag().name()));
+            let operands = match entry {
+                ConstantPoolType::Class { name_index }
+                | ConstantPoolType::Module { name_index }
+                | ConstantPoolType::Package { name_index } => format!("#{}", name_index),
+                ConstantPoolType::String { string_index } => format!("#{}", string_index),
+                ConstantPoolType::MethodType { descriptor_index } => {
+                    format!("#{}", descriptor_index)
+                }
+                ConstantPoolType::Fieldref {
+                    class_index,
+                    name_and_type_index,
+                }
+                | ConstantPoolType::Methodref {
+                    class_index,
+                    name_and_type_index,
+                }
+                | ConstantPoolType::InterfaceMethodref {
+                    class_index,
+                    name_and_type_index,
+                } => format!("#{}.#{}", class_index, name_and_type_index),
+                ConstantPoolType::NameAndType {
+                    name_index,
+                    descriptor_index,
+                } => format!("#{}:#{}", name_index, descriptor_index),
+                ConstantPoolType::MethodHandle {
+                    reference_kind,
+                    reference_index,
+                } => format!("{}:#{}", reference_kind, reference_index),
+                ConstantPoolType::Dynamic {
+                    bootstrap_method_attr_index,
+                    name_and_type_index,
+                }
+                | ConstantPoolType::InvokeDynamic {
+                    bootstrap_method_attr_index,
+                    name_and_type_index,
+                } => format!("#{}:#{}", bootstrap_method_attr_index, name_and_type_index),
+                ConstantPoolType::Integer { .. }
+                | ConstantPoolType::Float { .. }
+                | ConstantPoolType::Long { .. }
+                | ConstantPoolType::Double { .. }
+                | ConstantPoolType::Utf8 { .. } => {
+                    self.out.println(self.string_value(index)?);
+                    continue;
+                }
+            };
+            self.out.print(operands);
+            self.out.tab();
+            // javap separates method types from their comment marker by two
+            // spaces.
+            let marker = match entry {
+                ConstantPoolType::MethodType { .. } => "//  ",
+                _ => "// ",
+            };
+            self.out
+                .println(format_args!("{}{}", marker, self.string_value(index)?));
+        }
+        self.out.indent(-1);
+        Ok(())
+    }
+
+    fn field(&mut self, field: &FieldInfo) -> Result<(), ErrorKind> {
+        self.modifiers(field.access_flags.to_java(), None);
+        let ty = match field.signature(self.constant_pool)? {
+            Some(ty) => ty.to_java(),
+            None => self.java_field_type(field.descriptor_index)?,
+        };
+        self.out
+            .println(format_args!("{} {};", ty, self.utf8(field.name_index)?));
+        self.out.indent(1);
+        self.out.println(format_args!(
+            "descriptor: {}",
+            self.utf8(field.descriptor_index)?
+        ));
+        self.flags(field.access_flags, FieldAccess::NAMES);
+        self.attributes(&field.attributes, None)?;
+        self.out.indent(-1);
+        self.out.newline();
+        Ok(())
+    }
+
+    fn method(&mut self, method: &MethodInfo) -> Result<(), ErrorKind> {
+        let class_file = self.class_file;
+        let flags = method.access_flags;
+        let name = self.utf8(method.name_index)?;
+        let signature = method.signature(self.constant_pool)?;
+
+        // Like javap, mark the non-abstract instance methods of interfaces
+        // from class file version 52 on as default methods.
+        let default = class_file.access_flags.is_interface()
+            && !flags.is_abstract()
+            && name != "<clinit>"
+            && class_file.version.major >= 52
+            && !flags.intersects(MethodAccess::STATIC | MethodAccess::PRIVATE);
+        self.modifiers(flags.to_java(), default.then_some("default"));
+
+        let (params, ret) = match &signature {
+            Some(signature) => {
+                if !signature.type_params.is_empty() {
+                    self.out.print(format_args!(
+                        "<{}> ",
+                        join(&signature.type_params, TypeParameter::to_java)
+                    ));
+                }
+                let ret = match &signature.ret {
+                    Some(ret) => ret.to_java(),
+                    None => "void".to_string(),
+                };
+                (
+                    format!("({})", join(&signature.params, JavaTypeSignature::to_java)),
+                    ret,
+                )
+            }
+            _ => {
+                let descriptor = method.descriptor(self.constant_pool)?;
+                let ret = match &descriptor.ret {
+                    Some(ret) => ret.to_java(),
+                    None => "void".to_string(),
+                };
+                (
+                    format!("({})", join(&descriptor.params, FieldType::to_java)),
+                    ret,
+                )
+            }
+        };
+        let params = if flags.is_varargs() {
+            match params.rfind("[]") {
+                Some(i) if i > 0 => format!("{}...{}", &params[..i], &params[i + 2..]),
+                _ => params,
+            }
+        } else {
+            params
+        };
+        match &*name {
+            "<init>" => self
+                .out
+                .print(format_args!("{}{}", self.this_class_name()?, params)),
+            "<clinit>" => self.out.print("{}"),
+            _ => self.out.print(format_args!("{} {}{}", ret, name, params)),
+        }
+
+        for attribute in &method.attributes {
+            if let Attribute::Exceptions {
+                exception_index_table,
+                ..
+            } = attribute
+            {
+                self.out.print(" throws ");
+                match &signature {
+                    Some(signature) if !signature.throws.is_empty() => {
+                        self.out.print(join(&signature.throws, throws_name))
+                    }
+                    _ => {
+                        let exceptions = exception_index_table
+                            .iter()
+                            .map(|&index| self.java_class_name(index))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        self.out.print(exceptions.join(", "));
+                    }
+                }
+                break;
+            }
+        }
+        self.out.println(";");
+
+        self.out.indent(1);
+        self.out.println(format_args!(
+            "descriptor: {}",
+            self.utf8(method.descriptor_index)?
+        ));
+        self.flags(flags, MethodAccess::NAMES);
+        self.attributes(&method.attributes, Some(method))?;
+        self.out.indent(-1);
+        Ok(())
+    }
+
+    fn attributes(
+        &mut self,
+        attributes: &[Attribute],
+        method: Option<&MethodInfo>,
+    ) -> Result<(), ErrorKind> {
+        for attribute in attributes {
+            self.attribute(attribute, method)?;
+        }
+        Ok(())
+    }
+
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        method: Option<&MethodInfo>,
+    ) -> Result<(), ErrorKind> {
+        match attribute {
+            Attribute::ConstantValue {
+                constant_value_index,
+            } => {
+                self.out.println(format_args!(
+                    "ConstantValue: {}",
+                    self.constant(*constant_value_index)?
+                ));
+            }
+            Attribute::Code {
+                max_stack,
+                max_locals,
+                code,
+                exception_table,
+                attributes,
+                ..
+            } => {
+                self.out.println("Code:");
+                self.out.indent(1);
+                let args_size = match method {
+                    Some(method) => {
+                        let descriptor = method.descriptor(self.constant_pool)?;
+                        descriptor.params.len() + usize::from(!method.access_flags.is_static())
+                    }
+                    None => 0,
+                };
+                self.out.println(format_args!(
+                    "stack={}, locals={}, args_size={}",
+                    max_stack, max_locals, args_size
+                ));
+                self.code(code)?;
+                if !exception_table.is_empty() {
+                    self.out.println("Exception table:");
+                    self.out.indent(1);
+                    self.out.println(" from    to  target type");
+                    for handler in exception_table {
+                        self.out.print(format_args!(
+                            " {:5} {:5} {:5}   ",
+                            handler.start_pc, handler.end_pc, handler.handler_pc
+                        ));
+                        match handler.catch_type {
+                            0 => self.out.println("any"),
+                            catch_type => self
+                                .out
+                                .println(format_args!("Class {}", self.string_value(catch_type)?)),
+                        }
+                    }
+                    self.out.indent(-1);
+                }
+                self.attributes(attributes, method)?;
+                self.out.indent(-1);
+            }
+            Attribute::StackMapTable { entries } => {
+                self.out.println(format_args!(
+                    "StackMapTable: number_of_entries = {}",
+                    entries.len()
+                ));
+                self.out.indent(1);
+                for frame in entries {
+                    self.stack_map_frame(frame)?;
+                }
+                self.out.indent(-1);
+            }
+            Attribute::Exceptions {
+                exception_index_table,
+                ..
+            } => {
+                self.out.println("Exceptions:");
+                self.out.indent(1);
+                let exceptions = exception_index_table
+                    .iter()
+                    .map(|&index| self.java_class_name(index))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.out
+                    .println(format_args!("throws {}", exceptions.join(", ")));
+                self.out.indent(-1);
+            }
+            Attribute::InnerClasses { classes } => {
+                if !classes.is_empty() {
+                    self.out.println("InnerClasses:");
+                    self.out.indent(1);
+                }
+                for class in classes {
+                    self.modifiers(class.inner_class_access_flags.to_java(), None);
+                    if class.inner_name_index != 0 {
+                        self.out
+                            .print(format_args!("#{}= ", class.inner_name_index));
+                    }
+                    self.out.print(format_args!("#{}", class.inner_class_info));
+                    if class.outer_class_info != 0 {
+                        self.out
+                            .print(format_args!(" of #{}", class.outer_class_info));
+                    }
+                    self.out.print(";");
+                    self.out.tab();
+                    self.out.print("// ");
+                    if class.inner_name_index != 0 {
+                        self.out
+                            .print(format_args!("{}=", self.utf8(class.inner_name_index)?));
+                    }
+                    self.out.print(self.constant(class.inner_class_info)?);
+                    if class.outer_class_info != 0 {
+                        self.out.print(format_args!(
+                            " of {}",
+                            self.constant(class.outer_class_info)?
+                        ));
+                    }
+                    self.out.newline();
+                }
+                if !classes.is_empty() {
+                    self.out.indent(-1);
+                }
+            }
+            Attribute::EnclosingMethod {
+                class_index,
+                method_index,
+            } => {
+                self.out.print(format_args!(
+                    "EnclosingMethod: #{}.#{}",
+                    class_index, method_index
+                ));
+                self.out.tab();
+                self.out
+                    .print(format_args!("// {}", self.java_class_name(*class_index)?));
+                if *method_index != 0 {
+                    let name_index = match self.constant_pool.get(*method_index)? {
+                        ConstantPoolType::NameAndType { name_index, .. } => *name_index,
+                        other => {
+                            return Err(ErrorKind::WrongConstantPoolEntry {
+                                index: *method_index,
+                                expected: crate::constant_pool::ConstantPoolTag::NameAndType,
+                                found: other.tag(),
+                            })
+                        }
+                    };
+                    self.out.print(format_args!(".{}", self.utf8(name_index)?));
+                }
+                self.out.newline();
+            }
+            Attribute::Synthetic => self.out.println("Synthetic: true"),
+            Attribute::Signature { signature_index } => {
+                self.out
+                    .print(format_args!("Signature: #{}", signature_index));
+                self.out.tab();
+                self.out
+                    .println(format_args!("// {}", self.utf8(*signature_index)?));
+            }
+            Attribute::SourceFile { source_file_index } => {
+                self.out.println(format_args!(
+                    "SourceFile: \"{}\"",
+                    self.utf8(*source_file_index)?
+                ));
+            }
+            Attribute::SourceDebugExtension { debug_extension } => {
+                self.out.println("SourceDebugExtension:");
+                self.out.indent(1);
+                let value = String::from_utf8_lossy(debug_extension);
+                let lines = value.split(['\r', '\n']).collect::<Vec<_>>();
+                // Mirror Java's `split("[\r\n]+")`, which drops the empty
+                // strings between and after line breaks but keeps a leading one.
+                let last = lines.iter().rposition(|line| !line.is_empty()).unwrap_or(0);
+                for (i, line) in lines[..=last].iter().enumerate() {
+                    if i == 0 || !line.is_empty() {
+                        self.out.println(line);
+                    }
+                }
+                self.out.indent(-1);
+            }
+            Attribute::LineNumberTable { line_number_table } => {
+                self.out.println("LineNumberTable:");
+                self.out.indent(1);
+                for line_number in line_number_table {
+                    self.out.println(format_args!(
+                        "line {}: {}",
+                        line_number.line_number, line_number.start_pc
+                    ));
+                }
+                self.out.indent(-1);
+            }
+            Attribute::LocalVariableTable {
+                local_variable_table,
+            } => self.local_variables("LocalVariableTable", local_variable_table)?,
+            Attribute::LocalVariableTypeTable {
+                local_variable_type_table,
+            } => self.local_variables("LocalVariableTypeTable", local_variable_type_table)?,
+            Attribute::Deprecated => self.out.println("Deprecated: true"),
+            Attribute::RuntimeVisibleAnnotations { annotations } => {
+                self.annotations("RuntimeVisibleAnnotations", annotations)?
+            }
+            Attribute::RuntimeInvisibleAnnotations { annotations } => {
+                self.annotations("RuntimeInvisibleAnnotations", annotations)?
+            }
+            Attribute::RuntimeVisibleParameterAnnotations {
+                parameter_annotations,
+                ..
+            } => self.parameter_annotations(
+                "RuntimeVisibleParameterAnnotations",
+                parameter_annotations,
+            )?,
+            Attribute::RuntimeInvisibleParameterAnnotations {
+                parameter_annotations,
+                ..
+            } => self.parameter_annotations(
+                "RuntimeInvisibleParameterAnnotations",
+                parameter_annotations,
+            )?,
+            Attribute::AnnotationDefault { default_value } => {
+                self.out.println("AnnotationDefault:");
+                self.out.indent(1);
+                self.out.print("default_value: ");
+                self.out.print(self.element_value(default_value)?);
+                self.out.newline();
+                self.out.indent(1);
+                self.element_value_resolved(default_value)?;
+                self.out.indent(-1);
+                self.out.indent(-1);
+                self.out.newline();
+            }
+            Attribute::BootstrapMethods { bootstrap_methods } => {
+                self.out.println("BootstrapMethods:");
+                for (i, bootstrap_method) in bootstrap_methods.iter().enumerate() {
+                    self.out.indent(1);
+                    self.out.println(format_args!(
+                        "{}: #{} {}",
+                        i,
+                        bootstrap_method.method_ref,
+                        self.string_value(bootstrap_method.method_ref)?
+                    ));
+                    self.out.indent(1);
+                    self.out.println("Method arguments:");
+                    self.out.indent(1);
+                    for &arg in &bootstrap_method.args {
+                        self.out
+                            .println(format_args!("#{} {}", arg, self.string_value(arg)?));
+                    }
+                    self.out.indent(-3);
+                }
+            }
+            Attribute::Module {
+                module_name_index,
+                module_flags,
+                module_version_index,
+                requires,
+                exports,
+                opens,
+                uses_index,
+                provides,
+            } => {
+                self.out.println("Module:");
+                self.out.indent(1);
+                self.out
+                    .print(format_args!("#{},{:x}", module_name_index, module_flags.0));
+                self.out.tab();
+                self.out.print(format_args!(
+                    "// {}",
+                    self.string_value(*module_name_index)?
+                ));
+                self.module_flags(*module_flags, ModuleFlags::NAMES);
+                self.out.newline();
+                self.optional_index(*module_version_index)?;
+
+                self.table_header(requires.len(), "requires");
+                for require in requires {
+                    self.out.print(format_args!(
+                        "#{},{:x}",
+                        require.requires_index, require.requires_flags.0
+                    ));
+                    self.out.tab();
+                    self.out.print(format_args!(
+                        "// {}",
+                        self.string_value(require.requires_index)?
+                    ));
+                    self.module_flags(require.requires_flags, RequiresFlags::NAMES);
+                    self.out.newline();
+                    self.optional_index(require.requires_version_index)?;
+                }
+                self.out.indent(-1);
+
+                self.table_header(exports.len(), "exports");
+                for export in exports {
+                    self.exports_or_opens(
+                        export.exports_index,
+                        export.exports_flags,
+                        &export.exports_to_index,
+                    )?;
+                }
+                self.out.indent(-1);
+
+                self.table_header(opens.len(), "opens");
+                for open in opens {
+                    self.exports_or_opens(
+                        open.opens_index,
+                        open.opens_flags,
+                        &open.opens_to_index,
+                    )?;
+                }
+                self.out.indent(-1);
+
+                self.table_header(uses_index.len(), "uses");
+                for &index in uses_index {
+                    self.out.print(format_args!("#{}", index));
+                    self.out.tab();
+                    self.out
+                        .println(format_args!("// {}", self.string_value(index)?));
+                }
+                self.out.indent(-1);
+
+                self.table_header(provides.len(), "provides");
+                for provide in provides {
+                    self.out.print(format_args!("#{}", provide.provides_index));
+                    self.out.tab();
+                    self.out.println(format_args!(
+                        "// {} with ... {}",
+                        self.string_value(provide.provides_index)?,
+                        provide.provides_with_index.len()
+                    ));
+                    self.out.indent(1);
+                    for &index in &provide.provides_with_index {
+                        self.out.print(format_args!("#{}", index));
+                        self.out.tab();
+                        self.out
+                            .println(format_args!("// ... with {}", self.string_value(index)?));
+                    }
+                    self.out.indent(-1);
+                }
+                self.out.indent(-1);
+                self.out.indent(-1);
+            }
+            Attribute::ModulePackages { package_index } => {
+                self.out.println("ModulePackages:");
+                self.out.indent(1);
+                for &index in package_index {
+                    self.out.print(format_args!("#{}", index));
+                    self.out.tab();
+                    self.out.println(format_args!(
+                        "// {}",
+                        java_name(&self.constant_pool.get_package_name(index)?)
+                    ));
+                }
+                self.out.indent(-1);
+            }
+            Attribute::ModuleMainClass { main_class_index } => {
+                self.out
+                    .print(format_args!("ModuleMainClass: #{}", main_class_index));
+                self.out.tab();
+                self.out.println(format_args!(
+                    "// {}",
+                    self.java_class_name(*main_class_index)?
+                ));
+            }
+            Attribute::NestHost { host_class_index } => {
+                self.out.println(format_args!(
+                    "NestHost: {}",
+                    self.constant(*host_class_index)?
+                ));
+            }
+            Attribute::NestMembers { classes } => self.class_list("NestMembers", classes)?,
+            Attribute::Record { components } => {
+                self.out.println("Record:");
+                self.out.indent(1);
+                for component in components {
+                    let ty = match component.signature(self.constant_pool)? {
+                        Some(ty) => ty.to_java(),
+                        None => self.java_field_type(component.descriptor_index)?,
+                    };
+                    self.out
+                        .println(format_args!("{} {};", ty, self.utf8(component.name_index)?));
+                    self.out.indent(1);
+                    self.out.println(format_args!(
+                        "descriptor: {}",
+                        self.utf8(component.descriptor_index)?
+                    ));
+                    self.attributes(&component.attributes, None)?;
+                    self.out.newline();
+                    self.out.indent(-1);
+                }
+                self.out.indent(-1);
+            }
+            Attribute::PermittedSubclasses { classes } => {
+                self.class_list("PermittedSubclasses", classes)?
+            }
+            Attribute::MethodParameters { parameters } => {
+                self.out.println("MethodParameters:");
+                self.out.indent(1);
+                self.out.println(format_args!("{:<31}{}", "Name", "Flags"));
+                for parameter in parameters {
+                    let name = match parameter.name_index {
+                        0 => "<no name>".to_string(),
+                        index => self.string_value(index)?,
+                    };
+                    let mut flags = String::new();
+                    if parameter.access_flags.is_final() {
+                        flags.push_str("final ");
+                    }
+                    if parameter.access_flags.is_mandated() {
+                        flags.push_str("mandated ");
+                    }
+                    if parameter.access_flags.is_synthetic() {
+                        flags.push_str("synthetic");
+                    }
+                    self.out.println(format_args!("{:<31}{}", name, flags));
+                }
+                self.out.indent(-1);
+            }
+            Attribute::RuntimeVisibleTypeAnnotations { annotations } => {
+                self.type_annotations("RuntimeVisibleTypeAnnotations", annotations)?
+            }
+            Attribute::RuntimeInvisibleTypeAnnotations { annotations } => {
+                self.type_annotations("RuntimeInvisibleTypeAnnotations", annotations)?
+            }
+            Attribute::Unknown { name_index, info } => self.unknown(*name_index, info)?,
+        }
+        Ok(())
+    }
+
+    fn code(&mut self, code: &[u8]) -> Result<(), ErrorKind> {
+        for decoded in instructions(code) {
+            let (pc, instruction) = decoded.map_err(|err| err.kind)?;
+            let wide = code[pc as usize] == 0xc4;
+            let mnemonic = if wide {
+                Cow::Owned(format!("{}_w", instruction.mnemonic()))
+            } else {
+                Cow::Borrowed(instruction.mnemonic())
+            };
+            self.out.print(format_args!("{:4}: {:<13} ", pc, mnemonic));
+            self.operands(pc, &instruction)?;
+            self.out.newline();
+        }
+        Ok(())
+    }
+
+    fn operands(&mut self, pc: u32, instruction: &Instruction) -> Result<(), ErrorKind> {
+        use Instruction::*;
+
+        match instruction {
+            Bipush { value } => self.out.print(value),
+            Sipush { value } => self.out.print(value),
+            Ldc { index }
+            | LdcW { index }
+            | Ldc2W { index }
+            | Getstatic { index }
+            | Putstatic { index }
+            | Getfield { index }
+            | Putfield { index }
+            | Invokevirtual { index }
+            | Invokespecial { index }
+            | Invokestatic { index }
+            | New { index }
+            | Anewarray { index }
+            | Checkcast { index }
+            | Instanceof { index } => {
+                self.out.print(format_args!("#{}", index));
+                self.out.tab();
+                self.out
+                    .print(format_args!("// {}", self.constant(*index)?));
+            }
+            Invokeinterface { index, count } => self.constant_and_value(*index, *count)?,
+            Invokedynamic { index } => self.constant_and_value(*index, 0)?,
+            Multianewarray { index, dimensions } => self.constant_and_value(*index, *dimensions)?,
+            Iload { index }
+            | Lload { index }
+            | Fload { index }
+            | Dload { index }
+            | Aload { index }
+            | Istore { index }
+            | Lstore { index }
+            | Fstore { index }
+            | Dstore { index }
+            | Astore { index }
+            | Ret { index } => self.out.print(index),
+            Iinc { index, value } => self.out.print(format_args!("{}, {}", index, value)),
+            Ifeq { target }
+            | Ifne { target }
+            | Iflt { target }
+            | Ifge { target }
+            | Ifgt { target }
+            | Ifle { target }
+            | IfIcmpeq { target }
+            | IfIcmpne { target }
+            | IfIcmplt { target }
+            | IfIcmpge { target }
+            | IfIcmpgt { target }
+            | IfIcmple { target }
+            | IfAcmpeq { target }
+            | IfAcmpne { target }
+            | Goto { target }
+            | Jsr { target }
+            | Ifnull { target }
+            | Ifnonnull { target }
+            | GotoW { target }
+            | JsrW { target } => self.out.print(target),
+            Newarray { atype } => self.out.print(format_args!(" {}", array_type_name(*atype))),
+            Tableswitch {
+                default,
+                low,
+                high,
+                targets,
+            } => {
+                self.out.print(format_args!("{{ // {} to {}", low, high));
+                let cases = (*low..=*high).zip(targets);
+                self.switch_cases(cases, *default);
+            }
+            Lookupswitch { default, pairs } => {
+                self.out.print(format_args!("{{ // {}", pairs.len()));
+                let cases = pairs.iter().map(|(key, target)| (*key, target));
+                self.switch_cases(cases, *default);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn switch_cases<'t>(&mut self, cases: impl Iterator<Item = (i32, &'t u32)>, default: u32) {
+        // The cases line up under the operands, three levels in.
+        self.out.indent(3);
+        for (key, target) in cases {
+            self.out.print(format_args!("\n{:12}: {}", key, target));
+        }
+        self.out
+            .print(format_args!("\n     default: {}\n}}", default));
+        self.out.indent(-3);
+    }
+
+    fn constant_and_value(&mut self, index: U2, value: u8) -> Result<(), ErrorKind> {
+        self.out.print(format_args!("#{},  {}", index, value));
+        self.out.tab();
+        self.out.print(format_args!("// {}", self.constant(index)?));
+        Ok(())
+    }
+
+    fn stack_map_frame(&mut self, frame: &StackMapFrame) -> Result<(), ErrorKind> {
+        match frame {
+            StackMapFrame::Same { tag, .. } => self
+                .out
+                .println(format_args!("frame_type = {} /* same */", tag)),
+            StackMapFrame::SameLocals1StackItem { tag, stack, .. } => {
+                self.out.println(format_args!(
+                    "frame_type = {} /* same_locals_1_stack_item */",
+                    tag
+                ));
+                self.out.indent(1);
+                self.verification_types("stack", stack)?;
+                self.out.indent(-1);
+            }
+            StackMapFrame::Reserved(tag) => self.out.println(format_args!("frame_type = {}", tag)),
+            StackMapFrame::SameLocalsStackItemExtended {
+                tag,
+                offset_delta,
+                stack,
+            } => {
+                self.out.println(format_args!(
+                    "frame_type = {} /* same_locals_1_stack_item_frame_extended */",
+                    tag
+                ));
+                self.out.indent(1);
+                self.out
+                    .println(format_args!("offset_delta = {}", offset_delta));
+                self.verification_types("stack", stack)?;
+                self.out.indent(-1);
+            }
+            StackMapFrame::Chop { tag, offset_delta } => {
+                self.out
+                    .println(format_args!("frame_type = {} /* chop */", tag));
+                self.out.indent(1);
+                self.out
+                    .println(format_args!("offset_delta = {}", offset_delta));
+                self.out.indent(-1);
+            }
+            StackMapFrame::SameExtended { tag, offset_delta } => {
+                self.out.println(format_args!(
+                    "frame_type = {} /* same_frame_extended */",
+                    tag
+                ));
+                self.out.indent(1);
+                self.out
+                    .println(format_args!("offset_delta = {}", offset_delta));
+                self.out.indent(-1);
+            }
+            StackMapFrame::Append {
+                tag,
+                offset_delta,
+                locals,
+            } => {
+                self.out
+                    .println(format_args!("frame_type = {} /* append */", tag));
+                self.out.indent(1);
+                self.out
+                    .println(format_args!("offset_delta = {}", offset_delta));
+                self.verification_types("locals", locals)?;
+                self.out.indent(-1);
+            }
+            StackMapFrame::Full {
+                tag,
+                offset_delta,
+                locals,
+                stack,
+            } => {
+                self.out
+                    .println(format_args!("frame_type = {} /* full_frame */", tag));
+                self.out.indent(1);
+                self.out
+                    .println(format_args!("offset_delta = {}", offset_delta));
+                self.verification_types("locals", locals)?;
+                self.verification_types("stack", stack)?;
+                self.out.indent(-1);
+            }
+        }
+        Ok(())
+    }
+
+    fn verification_types(
+        &mut self,
+        label: &str,
+        types: &[VerificationTypeInfo],
+    ) -> Result<(), ErrorKind> {
+        self.out.print(format_args!("{} = [", label));
+        for (i, ty) in types.iter().enumerate() {
+            let name = match ty {
+                VerificationTypeInfo::TopVariable => Cow::Borrowed("top"),
+                VerificationTypeInfo::IntegerVariable => Cow::Borrowed("int"),
+                VerificationTypeInfo::FloatVariable => Cow::Borrowed("float"),
+                VerificationTypeInfo::LongVariable => Cow::Borrowed("long"),
+                VerificationTypeInfo::DoubleVariable => Cow::Borrowed("double"),
+                VerificationTypeInfo::NullVariable => Cow::Borrowed("null"),
+                VerificationTypeInfo::UninitializedThisVariable => Cow::Borrowed("this"),
+                VerificationTypeInfo::ObjectVariable { cpool_index } => {
+                    Cow::Owned(self.constant(*cpool_index)?)
+                }
+                VerificationTypeInfo::UninitializedVariable { offset } => {
+                    Cow::Owned(format!("uninitialized {}", offset))
+                }
+            };
+            let separator = if i == types.len() - 1 { " " } else { "," };
+            self.out.print(format_args!(" {}{}", name, separator));
+        }
+        self.out.println("]");
+        Ok(())
+    }
+
+    fn local_variables(
+        &mut self,
+        label: &str,
+        local_variables: &[LocalVariable],
+    ) -> Result<(), ErrorKind> {
+        self.out.println(format_args!("{}:", label));
+        self.out.indent(1);
+        self.out.println("Start  Length  Slot  Name   Signature");
+        for local_variable in local_variables {
+            self.out.println(format_args!(
+                "{:5} {:7} {:5} {:>5}   {}",
+                local_variable.start_pc,
+                local_variable.length,
+                local_variable.index,
+                self.string_value(local_variable.name_index)?,
+                self.string_value(local_variable.descriptor_index)?
+            ));
+        }
+        self.out.indent(-1);
+        Ok(())
+    }
+
+    fn class_list(&mut self, label: &str, classes: &[U2]) -> Result<(), ErrorKind> {
+        self.out.println(format_args!("{}:", label));
+        self.out.indent(1);
+        for &index in classes {
+            self.out.println(self.string_value(index)?);
+        }
+        self.out.indent(-1);
+        Ok(())
+    }
+
+    fn module_flags<T: Copy + Into<U2>>(&mut self, flags: T, names: &[(T, &str)]) {
+        let flags = flags.into();
+        for &(flag, name) in names {
+            if flags & flag.into() != 0 {
+                self.out.print(format_args!(" {}", name));
+            }
+        }
+    }
+
+    /// Prints `#index`, with the string it refers to unless it is 0.
+    fn optional_index(&mut self, index: U2) -> Result<(), ErrorKind> {
+        self.out.print(format_args!("#{}", index));
+        if index != 0 {
+            self.out.tab();
+            self.out
+                .print(format_args!("// {}", self.string_value(index)?));
+        }
+        self.out.newline();
+        Ok(())
+    }
+
+    /// Starts one of the tables of the `Module` attribute; the caller
+    /// unindents after its entries.
+    fn table_header(&mut self, len: usize, label: &str) {
+        self.out.print(len);
+        self.out.tab();
+        self.out.println(format_args!("// {}", label));
+        self.out.indent(1);
+    }
+
+    fn exports_or_opens(
+        &mut self,
+        index: U2,
+        flags: ExportsFlags,
+        to: &[U2],
+    ) -> Result<(), ErrorKind> {
+        self.out.print(format_args!("#{},{:x}", index, flags.0));
+        self.out.tab();
+        self.out
+            .print(format_args!("// {}", self.string_value(index)?));
+        self.module_flags(flags, ExportsFlags::NAMES);
+        if to.is_empty() {
+            self.out.newline();
+        } else {
+            self.out.println(format_args!(" to ... {}", to.len()));
+            self.out.indent(1);
+            for &index in to {
+                self.out.print(format_args!("#{}", index));
+                self.out.tab();
+                self.out
+                    .println(format_args!("// ... to {}", self.string_value(index)?));
+            }
+            self.out.indent(-1);
+        }
+        Ok(())
+    }
+
+    fn annotations(&mut self, label: &str, annotations: &[Annotation]) -> Result<(), ErrorKind> {
+        self.out.println(format_args!("{}:", label));
+        self.out.indent(1);
+        for (i, annotation) in annotations.iter().enumerate() {
+            self.out.print(format_args!("{}: ", i));
+            self.annotation(annotation)?;
+        }
+        self.out.indent(-1);
+        Ok(())
+    }
+
+    fn parameter_annotations(
+        &mut self,
+        label: &str,
+        parameter_annotations: &[Vec<Annotation>],
+    ) -> Result<(), ErrorKind> {
+        self.out.println(format_args!("{}:", label));
+        self.out.indent(1);
+        for (parameter, annotations) in parameter_annotations.iter().enumerate() {
+            self.out.println(format_args!("parameter {}: ", parameter));
+            self.out.indent(1);
+            for (i, annotation) in annotations.iter().enumerate() {
+                self.out.print(format_args!("{}: ", i));
+                self.annotation(annotation)?;
+            }
+            self.out.indent(-1);
+        }
+        self.out.indent(-1);
+        Ok(())
+    }
+
+    fn type_annotations(
+        &mut self,
+        label: &str,
+        annotations: &[TypeAnnotation],
+    ) -> Result<(), ErrorKind> {
+        self.out.println(format_args!("{}:", label));
+        self.out.indent(1);
+        for (i, type_annotation) in annotations.iter().enumerate() {
+            self.out.print(format_args!(
+                "{}: {}: {}",
+                i,
+                self.annotation_indices(&type_annotation.annotation)?,
+                type_annotation_position(type_annotation)
+            ));
+            self.out.newline();
+            self.out.indent(1);
+            self.annotation_resolved(&type_annotation.annotation)?;
+            self.out.indent(-1);
+            self.out.newline();
+        }
+        self.out.indent(-1);
+        Ok(())
+    }
+
+    /// Prints an annotation with its constant pool indices, then resolved on
+    /// the following lines.
+    fn annotation(&mut self, annotation: &Annotation) -> Result<(), ErrorKind> {
+        self.out.print(self.annotation_indices(annotation)?);
+        self.out.newline();
+        self.out.indent(1);
+        self.annotation_resolved(annotation)?;
+        self.out.indent(-1);
+        self.out.newline();
+        Ok(())
+    }
+
+    fn annotation_indices(&self, annotation: &Annotation) -> Result<String, ErrorKind> {
+        let pairs = annotation
+            .element_value_pairs
+            .iter()
+            .map(|pair| {
+                self.element_value(&pair.value)
+                    .map(|value| format!("#{}={}", pair.element_name_index, value))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(format!("#{}({})", annotation.type_index, pairs.join(",")))
+    }
+
+    fn annotation_resolved(&mut self, annotation: &Annotation) -> Result<(), ErrorKind> {
+        let type_name = self.utf8(annotation.type_index)?;
+        match FieldType::parse(&type_name) {
+            Ok(ty) => self.out.print(ty.to_java()),
+            Err(_) => self.out.print(format_args!("#{}", annotation.type_index)),
+        }
+        if !annotation.element_value_pairs.is_empty() {
+            self.out.println("(");
+            self.out.indent(1);
+            for pair in &annotation.element_value_pairs {
+                self.element_value_pair(pair)?;
+                self.out.newline();
+            }
+            self.out.indent(-1);
+            self.out.print(")");
+        }
+        Ok(())
+    }
+
+    fn element_value_pair(&mut self, pair: &ElementValuePair) -> Result<(), ErrorKind> {
+        self.out.print(format_args!(
+            "{}=",
+            self.string_value(pair.element_name_index)?
+        ));
+        self.element_value_resolved(&pair.value)
+    }
+
+    /// Renders an element value with its constant pool indices, e.g. `I#12`.
+    fn element_value(&self, value: &ElementValue) -> Result<String, ErrorKind> {
+        Ok(match value {
+            ElementValue::Byte { index } => format!("B#{}", index),
+            ElementValue::Char { index } => format!("C#{}", index),
+            ElementValue::Double { index } => format!("D#{}", index),
+            ElementValue::Float { index } => format!("F#{}", index),
+            ElementValue::Int { index } => format!("I#{}", index),
+            ElementValue::Long { index } => format!("J#{}", index),
+            ElementValue::Short { index } => format!("S#{}", index),
+            ElementValue::Boolean { index } => format!("Z#{}", index),
+            ElementValue::String { index } => format!("s#{}", index),
+            ElementValue::EnumConstValue {
+                type_name_index,
+                const_name_index,
+            } => format!("e#{}.#{}", type_name_index, const_name_index),
+            ElementValue::Class { index } => format!("c#{}", index),
+            ElementValue::Annotation { annotation } => {
+                format!("@{}", self.annotation_indices(annotation)?)
+            }
+            ElementValue::Array { values } => {
+                let values = values
+                    .iter()
+                    .map(|value| self.element_value(value))
+                    .collect::<Result<Vec<_>, _>>()?;
+                format!("[{}]", values.join(","))
+            }
+        })
+    }
+
+    fn element_value_resolved(&mut self, value: &ElementValue) -> Result<(), ErrorKind> {
+        match value {
+            ElementValue::Byte { index } => self
+                .out
+                .print(format_args!("(byte) {}", self.string_value(*index)?)),
+            ElementValue::Char { index } => {
+                let c = match self.constant_pool.get(*index)?.as_int() {
+                    Some(value) => char::from_u32(value as u16 as u32)
+                        .unwrap_or(char::REPLACEMENT_CHARACTER)
+                        .to_string(),
+                    None => self.string_value(*index)?,
+                };
+                self.out.print(format_args!("'{}'", c));
+            }
+            ElementValue::Double { index }
+            | ElementValue::Float { index }
+            | ElementValue::Int { index }
+            | ElementValue::Long { index } => self.out.print(self.string_value(*index)?),
+            ElementValue::Short { index } => self
+                .out
+                .print(format_args!("(short) {}", self.string_value(*index)?)),
+            ElementValue::Boolean { index } => {
+                let value = match self.constant_pool.get(*index)?.as_int() {
+                    Some(value) => (value != 0).to_string(),
+                    None => self.string_value(*index)?,
+                };
+                self.out.print(value);
+            }
+            ElementValue::String { index } => self
+                .out
+                .print(format_args!("\"{}\"", self.string_value(*index)?)),
+            ElementValue::EnumConstValue {
+                type_name_index,
+                const_name_index,
+            } => self.out.print(format_args!(
+                "{}.{}",
+                self.string_value(*type_name_index)?,
+                self.string_value(*const_name_index)?
+            )),
+            ElementValue::Class { index } => self
+                .out
+                .print(format_args!("class {}", self.string_value(*index)?)),
+            ElementValue::Annotation { annotation } => {
+                self.out.print("@");
+                self.annotation_resolved(annotation)?;
+            }
+            ElementValue::Array { values } => {
+                self.out.print("[");
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        self.out.print(",");
+                    }
+                    self.element_value_resolved(value)?;
+                }
+                self.out.print("]");
+            }
+        }
+        Ok(())
+    }
+
+    /// Prints the attributes javap knows but this crate keeps as
+    /// [`Attribute::Unknown`], and a hex dump of any others.
+    fn unknown(&mut self, name_index: U2, info: &[u8]) -> Result<(), ErrorKind> {
+        let name = self.utf8(name_index)?;
+        let u2 = |at: usize| {
+            info.get(at..at + 2)
+                .map(|b| U2::from_be_bytes([b[0], b[1]]))
+        };
+        match (&*name, info.len()) {
+            ("ModuleTarget", 2) => {
+                let index = u2(0).unwrap_or_default();
+                self.out.println("ModuleTarget:");
+                self.out.indent(1);
+                self.out.print(format_args!("target_platform: #{}", index));
+                if index != 0 {
+                    self.out.tab();
+                    self.out.print(format_args!("// {}", self.utf8(index)?));
+                }
+                self.out.newline();
+                self.out.indent(-1);
+                return Ok(());
+            }
+            ("ModuleResolution", 2) => {
+                let flags = u2(0).unwrap_or_default();
+                self.out.println("ModuleResolution:");
+                self.out.indent(1);
+                self.out.print(format_args!("{:x}", flags));
+                self.out.tab();
+                self.out.print("// ");
+                for (flag, name) in [
+                    (0x0001, "DO_NOT_RESOLVE_BY_DEFAULT"),
+                    (0x0002, "WARN_DEPRECATED"),
+                    (0x0004, "WARN_DEPRECATED_FOR_REMOVAL"),
+                    (0x0008, "WARN_INCUBATING"),
+                ] {
+                    if flags & flag != 0 {
+                        self.out.print(format_args!(" {}", name));
+                    }
+                }
+                self.out.newline();
+                self.out.indent(-1);
+                return Ok(());
+            }
+            ("ModuleHashes", _) => {
+                if let Some(hashes) = module_hashes(info) {
+                    self.out.println("ModuleHashes:");
+                    self.out.indent(1);
+                    self.out
+                        .print(format_args!("algorithm: #{}", hashes.algorithm_index));
+                    self.out.tab();
+                    self.out
+                        .println(format_args!("// {}", self.utf8(hashes.algorithm_index)?));
+                    self.out.print(hashes.entries.len());
+                    self.out.tab();
+                    self.out.println("// hashes");
+                    for (module_index, hash) in hashes.entries {
+                        self.out.print(format_args!("#{}", module_index));
+                        self.out.tab();
+                        self.out.println(format_args!(
+                            "// {}",
+                            self.constant_pool.get_module_name(module_index)?
+                        ));
+                        self.out
+                            .println(format_args!("hash_length: {}", hash.len()));
+                        let hex = hash
+                            .iter()
+                            .map(|b| format!("{:02x}", b))
+                            .collect::<String>();
+                        self.out.println(format_args!("hash: [{}]", hex));
+                    }
+                    self.out.indent(-1);
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        self.out.print(format_args!(
+            "  {}: length = 0x{:X} (unknown attribute)",
+            name,
+            info.len()
+        ));
+        self.out.newline();
+        self.out.print("   ");
+        for (i, byte) in info.iter().enumerate() {
+            self.out.print(format_args!("{:02X}", byte));
+            if i % 16 == 15 {
+                self.out.newline();
+                self.out.print("   ");
+            } else {
+                self.out.print(" ");
+            }
+        }
+        self.out.newline();
+        Ok(())
+    }
+
+    fn modifiers(&mut self, modifiers: String, extra: Option<&str>) {
+        if !modifiers.is_empty() {
+            self.out.print(format_args!("{} ", modifiers));
+        }
+        if let Some(modifier) = extra {
+            self.out.print(format_args!("{} ", modifier));
+        }
+    }
+
+    fn flags<T: Copy + Into<U2>>(&mut self, flags: T, table: &[(T, &str)]) {
+        let flags = flags.into();
+        let mut names = Vec::new();
+        let mut rest = flags;
+        for &(flag, name) in table {
+            let flag = flag.into();
+            if flags & flag != 0 {
+                names.push(name.to_string());
+                rest &= !flag;
+            }
+        }
+        while rest != 0 {
+            let bit = 1 << (15 - rest.leading_zeros());
+            names.push(format!("0x{:x}", bit));
+            rest &= !bit;
+        }
+        self.out.println(format_args!(
+            "flags: (0x{:04x}) {}",
+            flags,
+            names.join(", ")
+        ));
+    }
+
+    fn utf8(&self, index: U2) -> Result<Cow<'c, str>, ErrorKind> {
+        let bytes = self.constant_pool.get_utf8(index)?;
+        if let Ok(decoded) = mutf8::decode(bytes) {
+            return Ok(decoded);
+        }
+        // javap's UTF-8 output turns unpaired surrogates into `?`
+        let units = mutf8::decode_utf16(bytes).map_err(|kind| kind.in_entry(index))?;
+        Ok(Cow::Owned(
+            char::decode_utf16(units)
+                .map(|c| c.unwrap_or('?'))
+                .collect(),
+        ))
+    }
+
+    fn this_class_name(&self) -> Result<String, ErrorKind> {
+        self.java_class_name(self.class_file.this_class)
+    }
+
+    /// The name of a `Class` entry in Java syntax, e.g. `java.lang.Object`.
+    fn java_class_name(&self, index: U2) -> Result<String, ErrorKind> {
+        Ok(java_name(&self.constant_pool.get_class_name(index)?))
+    }
+
+    fn java_field_type(&self, descriptor_index: U2) -> Result<String, ErrorKind> {
+        Ok(self
+            .constant_pool
+            .get_field_type(descriptor_index)?
+            .to_java())
+    }
+
+    /// A constant as javap shows it in comments, prefixed by its kind, e.g.
+    /// `Method java/lang/Object."<init>":()V`. Members of this class are
+    /// shown without the class name.
+    fn constant(&self, index: U2) -> Result<String, ErrorKind> {
+        if index == 0 {
+            return Ok("#0".to_string());
+        }
+        let entry = self.constant_pool.get(index)?;
+        let value = match entry {
+            ConstantPoolType::Fieldref {
+                class_index,
+                name_and_type_index,
+            }
+            | ConstantPoolType::Methodref {
+                class_index,
+                name_and_type_index,
+            }
+            | ConstantPoolType::InterfaceMethodref {
+                class_index,
+                name_and_type_index,
+            } if *class_index == self.class_file.this_class => {
+                self.string_value(*name_and_type_index)?
+            }
+            _ => self.string_value(index)?,
+        };
+        let tag = match entry.tag() {
+            ConstantPoolTag::Class => "class",
+            ConstantPoolTag::Fieldref => "Field",
+            ConstantPoolTag::Methodref => "Method",
+            ConstantPoolTag::InterfaceMethodref => "InterfaceMethod",
+            ConstantPoolTag::Integer => "int",
+            ConstantPoolTag::Float => "float",
+            ConstantPoolTag::Long => "long",
+            ConstantPoolTag::Double => "double",
+            tag => tag.name(),
+        };
+        Ok(format!("{} {}", tag, value))
+    }
+
+    /// A constant as javap shows it in the constant pool comments.
+    fn string_value(&self, index: U2) -> Result<String, ErrorKind> {
+        Ok(match self.constant_pool.get(index)? {
+            ConstantPoolType::Class { name_index }
+            | ConstantPoolType::Module { name_index }
+            | ConstantPoolType::Package { name_index } => check_name(&self.utf8(*name_index)?),
+            ConstantPoolType::Fieldref {
+                class_index,
+                name_and_type_index,
+            }
+            | ConstantPoolType::Methodref {
+                class_index,
+                name_and_type_index,
+            }
+            | ConstantPoolType::InterfaceMethodref {
+                class_index,
+                name_and_type_index,
+            } => format!(
+                "{}.{}",
+                check_name(&self.constant_pool.get_class_name(*class_index)?),
+                self.string_value(*name_and_type_index)?
+            ),
+            ConstantPoolType::NameAndType {
+                name_index,
+                descriptor_index,
+            } => format!(
+                "{}:{}",
+                check_name(&self.utf8(*name_index)?),
+                self.utf8(*descriptor_index)?
+            ),
+            ConstantPoolType::String { string_index } => self.string_value(*string_index)?,
+            ConstantPoolType::Utf8 { .. } => escape(&self.utf8(index)?),
+            ConstantPoolType::Integer { .. } => self.constant_pool.get_int(index)?.to_string(),
+            ConstantPoolType::Float { .. } => {
+                format!("{}f", java_float(self.constant_pool.get_float(index)?))
+            }
+            ConstantPoolType::Long { .. } => format!("{}l", self.constant_pool.get_long(index)?),
+            ConstantPoolType::Double { .. } => {
+                format!("{}d", java_double(self.constant_pool.get_double(index)?))
+            }
+            ConstantPoolType::MethodHandle {
+                reference_kind,
+                reference_index,
+            } => format!(
+                "{} {}",
+                reference_kind_name(*reference_kind),
+                self.string_value(*reference_index)?
+            ),
+            ConstantPoolType::MethodType { descriptor_index } => {
+                self.string_value(*descriptor_index)?
+            }
+            ConstantPoolType::Dynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            }
+            | ConstantPoolType::InvokeDynamic {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            } => format!(
+                "#{}:{}",
+                bootstrap_method_attr_index,
+                self.string_value(*name_and_type_index)?
+            ),
+        })
+    }
+}
+
+fn reference_kind_name(kind: u8) -> Cow<'static, str> {
+    Cow::Borrowed(match kind {
+        1 => "REF_getField",
+        2 => "REF_getStatic",
+        3 => "REF_putField",
+        4 => "REF_putStatic",
+        5 => "REF_invokeVirtual",
+        6 => "REF_invokeStatic",
+        7 => "REF_invokeSpecial",
+        8 => "REF_newInvokeSpecial",
+        9 => "REF_invokeInterface",
+        _ => return Cow::Owned(kind.to_string()),
+    })
+}
+
+fn array_type_name(atype: ArrayType) -> &'static str {
+    match atype {
+        ArrayType::Boolean => "boolean",
+        ArrayType::Char => "char",
+        ArrayType::Float => "float",
+        ArrayType::Double => "double",
+        ArrayType::Byte => "byte",
+        ArrayType::Short => "short",
+        ArrayType::Int => "int",
+        ArrayType::Long => "long",
+    }
+}
+
+fn type_annotation_position(type_annotation: &TypeAnnotation) -> String {
+    use TypeAnnotationTarget::*;
+
+    let mut position = match &type_annotation.target {
+        ClassTypeParameter {
+            type_parameter_index,
+        } => format!("CLASS_TYPE_PARAMETER, param_index={}", type_parameter_index),
+        MethodTypeParameter {
+            type_parameter_index,
+        } => format!(
+            "METHOD_TYPE_PARAMETER, param_index={}",
+            type_parameter_index
+        ),
+        Supertype { supertype_index } => format!("CLASS_EXTENDS, type_index={}", supertype_index),
+        ClassTypeParameterBound {
+            type_parameter_index,
+            bound_index,
+        } => format!(
+            "CLASS_TYPE_PARAMETER_BOUND, param_index={}, bound_index={}",
+            type_parameter_index, bound_index
+        ),
+        MethodTypeParameterBound {
+            type_parameter_index,
+            bound_index,
+        } => format!(
+            "METHOD_TYPE_PARAMETER_BOUND, param_index={}, bound_index={}",
+            type_parameter_index, bound_index
+        ),
+        Field => "FIELD".to_string(),
+        Return => "METHOD_RETURN".to_string(),
+        Receiver => "METHOD_RECEIVER".to_string(),
+        FormalParameter {
+            formal_parameter_index,
+        } => format!(
+            "METHOD_FORMAL_PARAMETER, param_index={}",
+            formal_parameter_index
+        ),
+        Throws { throws_type_index } => format!("THROWS, type_index={}", throws_type_index),
+        LocalVariable { table } | ResourceVariable { table } => {
+            let name = match &type_annotation.target {
+                LocalVariable { .. } => "LOCAL_VARIABLE",
+                _ => "RESOURCE_VARIABLE",
+            };
+            let ranges = table
+                .iter()
+                .map(|range| {
+                    format!(
+                        "start_pc={}, length={}, index={}",
+                        range.start_pc, range.length, range.index
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!("{}, {{{}}}", name, ranges.join("; "))
+        }
+        ExceptionParameter {
+            exception_table_index,
+        } => format!(
+            "EXCEPTION_PARAMETER, exception_index={}",
+            exception_table_index
+        ),
+        InstanceOf { offset } => format!("INSTANCEOF, offset={}", offset),
+        New { offset } => format!("NEW, offset={}", offset),
+        ConstructorReference { offset } => format!("CONSTRUCTOR_REFERENCE, offset={}", offset),
+        MethodReference { offset } => format!("METHOD_REFERENCE, offset={}", offset),
+        Cast {
+            offset,
+            type_argument_index,
+        } => format!(
+            "CAST, offset={}, type_index={}",
+            offset, type_argument_index
+        ),
+        ConstructorInvocationTypeArgument {
+            offset,
+            type_argument_index,
+        } => format!(
+            "CONSTRUCTOR_INVOCATION_TYPE_ARGUMENT, offset={}, type_index={}",
+            offset, type_argument_index
+        ),
+        MethodInvocationTypeArgument {
+            offset,
+            type_argument_index,
+        } => format!(
+            "METHOD_INVOCATION_TYPE_ARGUMENT, offset={}, type_index={}",
+            offset, type_argument_index
+        ),
+        ConstructorReferenceTypeArgument {
+            offset,
+            type_argument_index,
+        } => format!(
+            "CONSTRUCTOR_REFERENCE_TYPE_ARGUMENT, offset={}, type_index={}",
+            offset, type_argument_index
+        ),
+        MethodReferenceTypeArgument {
+            offset,
+            type_argument_index,
+        } => format!(
+            "METHOD_REFERENCE_TYPE_ARGUMENT, offset={}, type_index={}",
+            offset, type_argument_index
+        ),
+    };
+    let path = &type_annotation.target_path.path;
+    if !path.is_empty() {
+        let entries = path
+            .iter()
+            .map(|entry| match entry.kind {
+                TypePathKind::Array => "ARRAY".to_string(),
+                TypePathKind::Nested => "INNER_TYPE".to_string(),
+                TypePathKind::WildcardBound => "WILDCARD".to_string(),
+                TypePathKind::TypeArgument => {
+                    format!("TYPE_ARGUMENT({})", entry.type_argument_index)
+                }
+            })
+            .collect::<Vec<_>>();
+        position.push_str(&format!(", location=[{}]", entries.join(", ")));
+    }
+    position
+}
+
+struct ModuleHashes<'a> {
+    algorithm_index: U2,
+    entries: Vec<(U2, &'a [u8])>,
+}
+
+/// Reads the JDK-specific `ModuleHashes` attribute.
+fn module_hashes(info: &[u8]) -> Option<ModuleHashes<'_>> {
+    let u2 = |at: usize| {
+        info.get(at..at + 2)
+            .map(|b| U2::from_be_bytes([b[0], b[1]]))
+    };
+    let algorithm_index = u2(0)?;
+    let count = u2(2)?;
+    let mut at = 4;
+    let mut entries = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let module_index = u2(at)?;
+        let len = u2(at + 2)? as usize;
+        let hash = info.get(at + 4..at + 4 + len)?;
+        entries.push((module_index, hash));
+        at += 4 + len;
+    }
+    (at == info.len()).then_some(ModuleHashes {
+        algorithm_index,
+        entries,
+    })
+}
+
+/// Converts an internal name to Java syntax, e.g. `java/lang/Object` to
+/// `java.lang.Object`.
+fn java_name(name: &str) -> String {
+    name.replace('/', ".")
+}
+
+/// javap shows the class types of a `throws` clause taken from a signature
+/// by their internal names, e.g. `java/io/IOException`.
+fn throws_name(ty: &ReferenceTypeSignature) -> String {
+    match ty {
+        ReferenceTypeSignature::Class(class)
+            if class.type_args.is_empty()
+                && class
+                    .nested
+                    .iter()
+                    .all(|nested| nested.type_args.is_empty()) =>
+        {
+            let mut name = class.name.clone();
+            for nested in &class.nested {
+                name.push('.');
+                name.push_str(&nested.name);
+            }
+            name
+        }
+        ty => ty.to_java(),
+    }
+}
+
+fn join<T>(items: &[T], render: impl Fn(&T) -> String) -> String {
+    items.iter().map(render).collect::<Vec<_>>().join(", ")
+}
+
+/// Quotes a name that is not a sequence of Java identifiers separated by
+/// slashes, e.g. `"<init>"` or `"[Ljava/lang/Object;"`.
+fn check_name(name: &str) -> String {
+    let mut previous = '/';
+    for c in name.chars() {
+        let valid = if previous == '/' {
+            is_java_identifier_start(c)
+        } else {
+            c == '/' || is_java_identifier_part(c)
+        };
+        if !valid {
+            return format!("\"{}\"", escape_name(name));
+        }
+        previous = c;
+    }
+    if name.is_empty() {
+        return "\"\"".to_string();
+    }
+    name.to_string()
+}
+
+fn is_java_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '$' || c == '_'
+}
+
+fn is_java_identifier_part(c: char) -> bool {
+    c.is_alphanumeric()
+        || c == '$'
+        || c == '_'
+        || matches!(c, '\u{0}'..='\u{8}' | '\u{e}'..='\u{1b}' | '\u{7f}'..='\u{9f}')
+}
+
+/// The escaping of quoted names, which unlike [`escape`] leaves other
+/// control characters alone.
+fn escape_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escapes a string constant the way javap prints it.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            '"' => escaped.push_str("\\\""),
+            '\'' => escaped.push_str("\\'"),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Formats a `float` like Java's `Float.toString`.
+fn java_float(value: f32) -> String {
+    match java_special(
+        value.is_nan(),
+        value.is_infinite(),
+        value.is_sign_negative(),
+        value == 0.0,
+    ) {
+        Some(special) => special.to_string(),
+        None => java_decimal(&shortest_digits(value)),
+    }
+}
+
+/// Formats a `double` like Java's `Double.toString`.
+fn java_double(value: f64) -> String {
+    match java_special(
+        value.is_nan(),
+        value.is_infinite(),
+        value.is_sign_negative(),
+        value == 0.0,
+    ) {
+        Some(special) => special.to_string(),
+        None => java_decimal(&shortest_digits(value)),
+    }
+}
+
+/// The shortest digits that read back as `value`, in Rust's `{:e}` form.
+/// Java always prints at least two digits, so a single digit becomes the
+/// two-digit decimal closest to `value`, e.g. `1.4e-45` rather than `1e-45`.
+fn shortest_digits(value: impl fmt::LowerExp + Copy) -> String {
+    let shortest = format!("{:e}", value);
+    if shortest.contains('.') {
+        shortest
+    } else {
+        format!("{:.1e}", value)
+    }
+}
+
+fn java_special(nan: bool, infinite: bool, negative: bool, zero: bool) -> Option<&'static str> {
+    match (nan, infinite, negative, zero) {
+        (true, ..) => Some("NaN"),
+        (_, true, false, _) => Some("Infinity"),
+        (_, true, true, _) => Some("-Infinity"),
+        (_, _, false, true) => Some("0.0"),
+        (_, _, true, true) => Some("-0.0"),
+        _ => None,
+    }
+}
+
+/// Lays out the shortest digits of a number, given in Rust's `{:e}` form,
+/// the way Java does: plainly from 10^-3 up to 10^7, and in computerized
+/// scientific notation otherwise. Java releases before 19 sometimes print
+/// a digit more than necessary, e.g. `1.07374182E9` for 2^30 as a `float`.
+fn java_decimal(scientific: &str) -> String {
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((scientific, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => ("-", mantissa),
+        None => ("", mantissa),
+    };
+    let digits = mantissa.replace('.', "");
+    let digits = match digits.trim_end_matches('0') {
+        "" => "0",
+        digits => digits,
+    };
+    if (-3..7).contains(&exponent) {
+        if exponent >= 0 {
+            let split = exponent as usize + 1;
+            let int = format!("{:0<width$}", digits, width = split);
+            let (int, frac) = int.split_at(split.min(int.len()));
+            let frac = if frac.is_empty() { "0" } else { frac };
+            format!("{}{}.{}", sign, int, frac)
+        } else {
+            let zeros = "0".repeat((-exponent - 1) as usize);
+            format!("{}0.{}{}", sign, zeros, digits)
+        }
+    } else {
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() { "0" } else { rest };
+        format!("{}{}.{}E{}", sign, first, rest, exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floats_print_like_float_to_string() {
+        for (value, java) in [
+            (f32::NAN, "NaN"),
+            (f32::INFINITY, "Infinity"),
+            (f32::NEG_INFINITY, "-Infinity"),
+            (0.0, "0.0"),
+            (-0.0, "-0.0"),
+            (f32::from_bits(1), "1.4E-45"),
+            (f32::MAX, "3.4028235E38"),
+            (1.0, "1.0"),
+            (0.1, "0.1"),
+            (-2.5, "-2.5"),
+            (123456.78, "123456.78"),
+            // Plain notation runs from 10^-3 up to but excluding 10^7.
+            (9999999.0, "9999999.0"),
+            (1.0e7, "1.0E7"),
+            (1.0e-3, "0.001"),
+            (9.999999e-4, "9.999999E-4"),
+            (1.0e10, "1.0E10"),
+            (2.0e22, "2.0E22"),
+        ] {
+            assert_eq!(java_float(value), java, "{:e}", value);
+        }
+    }
+
+    #[test]
+    fn doubles_print_like_double_to_string() {
+        for (value, java) in [
+            (f64::NAN, "NaN"),
+            (f64::INFINITY, "Infinity"),
+            (f64::NEG_INFINITY, "-Infinity"),
+            (-0.0, "-0.0"),
+            (f64::from_bits(1), "4.9E-324"),
+            (f64::MIN_POSITIVE, "2.2250738585072014E-308"),
+            (f64::MAX, "1.7976931348623157E308"),
+            (0.1, "0.1"),
+            (-1234.5678, "-1234.5678"),
+            (9999999.0, "9999999.0"),
+            (1.0e7, "1.0E7"),
+            (2.0e-3, "0.002"),
+            (9.99e-4, "9.99E-4"),
+            (1.0e10, "1.0E10"),
+        ] {
+            assert_eq!(java_double(value), java, "{:e}", value);
+        }
+    }
+
+    /// Before Java 19, `Float.toString` and `Double.toString` sometimes
+    /// print more digits than needed; the shortest digits are printed
+    /// instead.
+    #[test]
+    fn shortest_digits_differ_from_jdk_17() {
+        for (value, ours, jdk_17) in [
+            (f32::MIN_POSITIVE, "1.1754944E-38", "1.17549435E-38"),
+            (1073741824.0, "1.0737418E9", "1.07374182E9"),
+        ] {
+            assert_eq!(java_float(value), ours, "JDK 17 prints {}", jdk_17);
+        }
+        for (value, ours, jdk_17) in [
+            (2.0e23, "2.0E23", "1.9999999999999998E23"),
+            (1.0e23, "1.0E23", "9.999999999999999E22"),
+        ] {
+            assert_eq!(java_double(value), ours, "JDK 17 prints {}", jdk_17);
+        }
+    }
+}
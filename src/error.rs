@@ -0,0 +1,326 @@
+use std::fmt;
+
+use nom::Offset;
+
+use crate::{constant_pool::ConstantPoolTag, U1, U2, U4};
+
+/// An error encountered while parsing a class file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassFileError {
+    pub kind: ErrorKind,
+    /// Byte offset into the input at which the error was detected.
+    pub offset: usize,
+    /// Where in the class file the error occurred, outermost segment first,
+    /// e.g. `["method 3", "Code", "StackMapTable", "frame 7"]`.
+    pub path: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    BadMagic(U4),
+    Truncated,
+    TrailingBytes,
+    UnknownConstantPoolTag(U1),
+    /// The constant pool entry's tag is not valid in this class file version.
+    UnsupportedConstantPoolTag {
+        tag: ConstantPoolTag,
+        major_version: U2,
+    },
+    BadConstantPoolIndex(U2),
+    WrongConstantPoolEntry {
+        index: U2,
+        expected: ConstantPoolTag,
+        found: ConstantPoolTag,
+    },
+    /// Modified UTF-8 that is malformed, or that holds an unpaired surrogate
+    /// where a string was needed. `offset` counts the encoded bytes, from
+    /// the start of the contents of the `Utf8` entry at `index` when the
+    /// text came from the constant pool.
+    InvalidModifiedUtf8 {
+        index: Option<U2>,
+        offset: usize,
+    },
+    UnknownVerificationType(U1),
+    UnknownElementValueTag(U1),
+    UnknownTypeAnnotationTarget(U1),
+    UnknownTypePathKind(U1),
+    UnknownOpcode(U1),
+    /// `wide` followed by an opcode that has no wide form.
+    InvalidWideOpcode(U1),
+    InvalidArrayType(U1),
+    /// A `tableswitch` whose `high` is less than `low`, or which is being
+    /// assembled with other than `high - low + 1` targets.
+    InvalidTableSwitch {
+        low: i32,
+        high: i32,
+    },
+    /// A `lookupswitch` with a negative `npairs`, or which is being assembled
+    /// with keys that are not strictly increasing.
+    InvalidLookupSwitch {
+        npairs: i32,
+    },
+    /// A branch whose absolute target lies outside the code array.
+    InvalidBranchTarget(i64),
+    UndefinedLabel(u32),
+    DuplicateLabel(u32),
+    /// A local variable whose range ends before it starts once its code is
+    /// laid out again.
+    InvalidLocalVariableRange {
+        start: u32,
+        end: u32,
+    },
+    /// The assembled code exceeds the 65535 byte limit.
+    CodeTooLarge(u32),
+    /// A descriptor that is malformed from byte `offset` on, held by the
+    /// `Utf8` entry at `index` when it came from the constant pool.
+    /// `offset` counts bytes of the decoded `str`, which differ from those
+    /// of the modified UTF-8 once the descriptor has a NUL or a
+    /// supplementary character before it.
+    InvalidDescriptor {
+        index: Option<U2>,
+        offset: usize,
+    },
+    /// A generic signature that is malformed from byte `offset` on, counted
+    /// like the offset of [`ErrorKind::InvalidDescriptor`].
+    InvalidSignature {
+        index: Option<U2>,
+        offset: usize,
+    },
+    /// A combination of access flags the JVMS forbids.
+    IllegalAccessFlags {
+        flags: U2,
+        reason: &'static str,
+    },
+    /// The constant pool has no `Utf8` entry for the name of an attribute
+    /// being written.
+    MissingAttributeName(&'static str),
+    /// A table or blob being written is too long for its length field.
+    TooManyItems(usize),
+    /// The attribute body ended before `attribute_length` bytes were consumed.
+    AttributeUnderread {
+        declared: U4,
+        consumed: U4,
+    },
+    /// The attribute body needs more than `attribute_length` bytes.
+    AttributeOverread {
+        declared: U4,
+    },
+    Nom(nom::error::ErrorKind),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadMagic(magic) => write!(f, "bad magic number {:#010x}", magic),
+            Self::Truncated => write!(f, "unexpected end of input"),
+            Self::TrailingBytes => write!(f, "trailing bytes after end of class file"),
+            Self::UnknownConstantPoolTag(tag) => write!(f, "unknown constant pool tag {}", tag),
+            Self::UnsupportedConstantPoolTag { tag, major_version } => write!(
+                f,
+                "constant pool tag {:?} requires major version {} but the class file is version {}",
+                tag,
+                tag.since_major_version(),
+                major_version
+            ),
+            Self::BadConstantPoolIndex(index) => {
+                write!(f, "invalid constant pool index #{}", index)
+            }
+            Self::WrongConstantPoolEntry {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "constant pool entry #{} is {:?}, expected {:?}",
+                index, found, expected
+            ),
+            Self::InvalidModifiedUtf8 {
+                index: Some(index),
+                offset,
+            } => write!(
+                f,
+                "cannot decode constant pool entry #{} at byte {}",
+                index, offset
+            ),
+            Self::InvalidModifiedUtf8 {
+                index: None,
+                offset,
+            } => write!(f, "cannot decode modified UTF-8 at byte {}", offset),
+            Self::UnknownVerificationType(tag) => {
+                write!(f, "unknown verification type tag {}", tag)
+            }
+            Self::UnknownElementValueTag(tag) => {
+                write!(f, "unknown element value tag {:?}", *tag as char)
+            }
+            Self::UnknownTypeAnnotationTarget(target_type) => {
+                write!(
+                    f,
+                    "unknown type annotation target type {:#04x}",
+                    target_type
+                )
+            }
+            Self::UnknownTypePathKind(kind) => write!(f, "unknown type path kind {}", kind),
+            Self::UnknownOpcode(opcode) => write!(f, "unknown opcode {:#04x}", opcode),
+            Self::InvalidWideOpcode(opcode) => {
+                write!(f, "opcode {:#04x} cannot be used with wide", opcode)
+            }
+            Self::InvalidArrayType(atype) => write!(f, "invalid newarray type {}", atype),
+            Self::InvalidTableSwitch { low, high } => {
+                write!(f, "invalid tableswitch from {} to {}", low, high)
+            }
+            Self::InvalidLookupSwitch { npairs } => {
+                write!(f, "invalid lookupswitch with {} pairs", npairs)
+            }
+            Self::InvalidBranchTarget(target) => {
+                write!(f, "branch target {} is outside the code", target)
+            }
+            Self::UndefinedLabel(label) => write!(f, "label {} is not defined", label),
+            Self::DuplicateLabel(label) => write!(f, "label {} is defined more than once", label),
+            Self::InvalidLocalVariableRange { start, end } => write!(
+                f,
+                "local variable range from {} to {} ends before it starts",
+                start, end
+            ),
+            Self::CodeTooLarge(len) => write!(f, "code of {} bytes exceeds 65535 bytes", len),
+            Self::InvalidDescriptor {
+                index: Some(index),
+                offset,
+            } => write!(f, "invalid descriptor at index {}, byte {}", index, offset),
+            Self::InvalidDescriptor {
+                index: None,
+                offset,
+            } => write!(f, "invalid descriptor at byte {}", offset),
+            Self::InvalidSignature {
+                index: Some(index),
+                offset,
+            } => write!(f, "invalid signature at index {}, byte {}", index, offset),
+            Self::InvalidSignature {
+                index: None,
+                offset,
+            } => write!(f, "invalid signature at byte {}", offset),
+            Self::IllegalAccessFlags { flags, reason } => {
+                write!(f, "illegal access flags 0x{:04x}: {}", flags, reason)
+            }
+            Self::MissingAttributeName(name) => {
+                write!(f, "no Utf8 constant names the {} attribute", name)
+            }
+            Self::TooManyItems(len) => write!(f, "{} items do not fit their length field", len),
+            Self::AttributeUnderread { declared, consumed } => write!(
+                f,
+                "attribute declares {} bytes but only {} were read",
+                declared, consumed
+            ),
+            Self::AttributeOverread { declared } => {
+                write!(f, "attribute reads past its declared {} bytes", declared)
+            }
+            Self::Nom(kind) => write!(f, "parse error ({:?})", kind),
+        }
+    }
+}
+
+impl ErrorKind {
+    /// Attributes text that failed to decode or parse to the `Utf8` entry at
+    /// `index` it was read from.
+    pub(crate) fn in_entry(self, entry: U2) -> Self {
+        match self {
+            Self::InvalidModifiedUtf8 {
+                index: None,
+                offset,
+            } => Self::InvalidModifiedUtf8 {
+                index: Some(entry),
+                offset,
+            },
+            Self::InvalidDescriptor {
+                index: None,
+                offset,
+            } => Self::InvalidDescriptor {
+                index: Some(entry),
+                offset,
+            },
+            Self::InvalidSignature {
+                index: None,
+                offset,
+            } => Self::InvalidSignature {
+                index: Some(entry),
+                offset,
+            },
+            kind => kind,
+        }
+    }
+}
+
+impl fmt::Display for ClassFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)?;
+        if !self.path.is_empty() {
+            write!(f, " in {}", self.path.join(" / "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ClassFileError {}
+
+/// The nom error type used internally by the parser. It keeps the remaining
+/// input so the byte offset can be recovered once parsing has unwound.
+#[derive(Debug)]
+pub(crate) struct ParseError<'a> {
+    pub(crate) input: &'a [u8],
+    pub(crate) kind: ErrorKind,
+    pub(crate) path: Vec<String>,
+}
+
+pub(crate) type PResult<'a, O> = nom::IResult<&'a [u8], O, ParseError<'a>>;
+
+impl<'a> ParseError<'a> {
+    pub(crate) fn new(input: &'a [u8], kind: ErrorKind) -> Self {
+        Self {
+            input,
+            kind,
+            path: Vec::new(),
+        }
+    }
+
+    pub(crate) fn into_class_file_error(self, original: &[u8]) -> ClassFileError {
+        let mut path = self.path;
+        path.reverse();
+        ClassFileError {
+            kind: self.kind,
+            offset: original.offset(self.input),
+            path,
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a [u8]> for ParseError<'a> {
+    fn from_error_kind(input: &'a [u8], kind: nom::error::ErrorKind) -> Self {
+        let kind = match kind {
+            nom::error::ErrorKind::Eof => ErrorKind::Truncated,
+            kind => ErrorKind::Nom(kind),
+        };
+        Self::new(input, kind)
+    }
+
+    fn append(_: &'a [u8], _: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+/// Fails the parse at `input` with a non-recoverable error.
+pub(crate) fn fail<O>(input: &[u8], kind: ErrorKind) -> PResult<'_, O> {
+    Err(nom::Err::Failure(ParseError::new(input, kind)))
+}
+
+/// Records `segment` as part of the error path if `result` is an error.
+pub(crate) fn context<'a, O>(
+    result: PResult<'a, O>,
+    segment: impl FnOnce() -> String,
+) -> PResult<'a, O> {
+    result.map_err(|err| {
+        err.map(|mut e| {
+            e.path.push(segment());
+            e
+        })
+    })
+}
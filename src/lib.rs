@@ -1,25 +1,163 @@
 #![allow(unused)]
 
+pub mod access;
+pub mod assembler;
+pub mod bytecode;
 pub mod constant_pool;
-pub mod parser;
+pub mod descriptor;
+pub mod disassembler;
+pub mod error;
+pub mod module;
+pub mod mutf8;
+mod parser;
+pub mod signature;
+pub mod view;
+mod writer;
 
-use constant_pool::ConstantPoolType;
+use std::{borrow::Cow, io};
+
+use access::{
+    ClassAccess, ExportsFlags, FieldAccess, InnerClassAccess, MethodAccess, ModuleFlags,
+    ParameterAccess, RequiresFlags,
+};
+use constant_pool::{ConstantPool, ConstantPoolTag, ConstantPoolType};
+use descriptor::{FieldType, MethodDescriptor};
+pub use error::{ClassFileError, ErrorKind};
+use module::ModuleDescriptor;
+use signature::{ClassSignature, MethodSignature, ReferenceTypeSignature};
+use view::ClassView;
 
 pub type U1 = u8;
 pub type U2 = u16;
 pub type U4 = u32;
 
+/// Parses a complete class file in [`Mode::Strict`].
+///
+/// The class file borrows its `Utf8` constants, bytecode and raw attribute
+/// bodies from `bytes`; [`ClassFile::into_owned`] detaches it.
+pub fn parse(bytes: &[u8]) -> Result<ClassFile<'_>, ClassFileError> {
+    parser::parse(bytes, Mode::Strict).map(|(class_file, _)| class_file)
+}
+
+/// Parses a complete class file, returning any problems tolerated in
+/// [`Mode::Lenient`] as warnings.
+pub fn parse_with_mode(
+    bytes: &[u8],
+    mode: Mode,
+) -> Result<(ClassFile<'_>, Vec<ClassFileError>), ClassFileError> {
+    parser::parse(bytes, mode)
+}
+
+/// How the parser reacts to recoverable inconsistencies, such as an
+/// attribute whose body does not match its `attribute_length`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Reject the class file.
+    #[default]
+    Strict,
+    /// Record a warning and resume at the next well-defined offset.
+    Lenient,
+}
+
 #[derive(Debug)]
-pub struct ClassFile {
+pub struct ClassFile<'a> {
     pub version: Version,
-    pub constant_pool: Vec<ConstantPoolType>,
-    pub access_flags: U2,
+    pub constant_pool: ConstantPool<'a>,
+    pub access_flags: ClassAccess,
     pub this_class: U2,
     pub super_class: U2,
     pub interfaces: Vec<U2>,
-    pub fields: Vec<FieldInfo>,
-    pub methods: Vec<MethodInfo>,
-    pub attributes: Vec<Attribute>,
+    pub fields: Vec<FieldInfo<'a>>,
+    pub methods: Vec<MethodInfo<'a>>,
+    pub attributes: Vec<Attribute<'a>>,
+    /// The `attribute_name_index` each of `attributes` was parsed with. The
+    /// writer reuses an index while it still names its attribute, so that a
+    /// constant pool holding an attribute name more than once is written
+    /// back unchanged, and otherwise falls back to the first `Utf8` entry
+    /// holding the name. Attributes built by hand can leave this empty.
+    pub attribute_name_indices: Vec<U2>,
+}
+
+impl ClassFile<'_> {
+    /// Copies every slice borrowed from the parsed input, so that the class
+    /// file can outlive it.
+    pub fn into_owned(self) -> ClassFile<'static> {
+        ClassFile {
+            version: self.version,
+            constant_pool: self.constant_pool.into_owned(),
+            access_flags: self.access_flags,
+            this_class: self.this_class,
+            super_class: self.super_class,
+            interfaces: self.interfaces,
+            fields: self.fields.into_iter().map(FieldInfo::into_owned).collect(),
+            methods: self
+                .methods
+                .into_iter()
+                .map(MethodInfo::into_owned)
+                .collect(),
+            attributes: owned_attributes(self.attributes),
+            attribute_name_indices: self.attribute_name_indices,
+        }
+    }
+
+    /// Serializes the class file, recomputing every count and attribute
+    /// length. A class file parsed without warnings is written back
+    /// byte for byte.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ErrorKind> {
+        writer::write(self)
+    }
+
+    /// Serializes the class file like [`ClassFile::to_bytes`] into `writer`.
+    /// A class file that cannot be serialized fails with
+    /// [`io::ErrorKind::InvalidData`].
+    pub fn write_to(&self, mut writer: impl io::Write) -> io::Result<()> {
+        let bytes = self
+            .to_bytes()
+            .map_err(|kind| io::Error::new(io::ErrorKind::InvalidData, kind.to_string()))?;
+        writer.write_all(&bytes)
+    }
+
+    /// Borrows the class as a [`ClassView`], which reads names rather than
+    /// constant pool indices.
+    pub fn view(&self) -> ClassView<'_> {
+        ClassView::new(self)
+    }
+
+    /// Resolves the module declaration of a `module-info.class`.
+    pub fn module_descriptor(&self) -> Result<Option<ModuleDescriptor>, ErrorKind> {
+        ModuleDescriptor::from_class_file(self)
+    }
+
+    /// Parses the class's `Signature` attribute, if it has one.
+    pub fn signature(&self) -> Result<Option<ClassSignature>, ErrorKind> {
+        signature::find(&self.attributes, &self.constant_pool, ClassSignature::parse)
+    }
+
+    /// The internal name of the class's nest host, if it is a nest member.
+    pub fn nest_host(&self) -> Result<Option<Cow<'_, str>>, ErrorKind> {
+        for attribute in &self.attributes {
+            if let Attribute::NestHost { host_class_index } = attribute {
+                return self
+                    .constant_pool
+                    .get_class_name(*host_class_index)
+                    .map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// The internal names of the members of the nest this class hosts.
+    pub fn nest_members(&self) -> Result<Vec<Cow<'_, str>>, ErrorKind> {
+        for attribute in &self.attributes {
+            if let Attribute::NestMembers { classes } = attribute {
+                return classes
+                    .iter()
+                    .map(|&index| self.constant_pool.get_class_name(index))
+                    .collect();
+            }
+        }
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Debug)]
@@ -29,11 +167,81 @@ pub struct Version {
 }
 
 #[derive(Debug)]
-pub struct FieldInfo {
-    pub access_flags: U2,
+pub struct FieldInfo<'a> {
+    pub access_flags: FieldAccess,
     pub name_index: U2,
     pub descriptor_index: U2,
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<'a>>,
+    /// As in [`ClassFile::attribute_name_indices`].
+    pub attribute_name_indices: Vec<U2>,
+}
+
+impl FieldInfo<'_> {
+    /// Copies any bytes borrowed from the parsed input.
+    pub fn into_owned(self) -> FieldInfo<'static> {
+        FieldInfo {
+            access_flags: self.access_flags,
+            name_index: self.name_index,
+            descriptor_index: self.descriptor_index,
+            attributes: owned_attributes(self.attributes),
+            attribute_name_indices: self.attribute_name_indices,
+        }
+    }
+
+    pub fn descriptor(&self, constant_pool: &ConstantPool) -> Result<FieldType, ErrorKind> {
+        constant_pool.get_field_type(self.descriptor_index)
+    }
+
+    /// Parses the field's `Signature` attribute, if it has one.
+    pub fn signature(
+        &self,
+        constant_pool: &ConstantPool,
+    ) -> Result<Option<ReferenceTypeSignature>, ErrorKind> {
+        signature::find(
+            &self.attributes,
+            constant_pool,
+            ReferenceTypeSignature::parse,
+        )
+    }
+
+    /// Resolves the field's `ConstantValue` attribute, typed by the field's
+    /// descriptor, or returns `None` if the field has none.
+    pub fn constant_value<'c>(
+        &self,
+        constant_pool: &'c ConstantPool,
+    ) -> Result<Option<Constant<'c>>, ErrorKind> {
+        for attribute in &self.attributes {
+            if let Attribute::ConstantValue {
+                constant_value_index: index,
+            } = *attribute
+            {
+                let constant = match constant_pool.get_utf8(self.descriptor_index)? {
+                    b"B" => Constant::Byte(constant_pool.get_int(index)? as i8),
+                    b"C" => Constant::Char(constant_pool.get_int(index)? as u16),
+                    b"S" => Constant::Short(constant_pool.get_int(index)? as i16),
+                    b"Z" => Constant::Boolean(constant_pool.get_int(index)? != 0),
+                    b"I" => Constant::Int(constant_pool.get_int(index)?),
+                    b"J" => Constant::Long(constant_pool.get_long(index)?),
+                    b"F" => Constant::Float(constant_pool.get_float(index)?),
+                    b"D" => Constant::Double(constant_pool.get_double(index)?),
+                    _ => match constant_pool.get(index)? {
+                        ConstantPoolType::String { string_index } => {
+                            Constant::String(constant_pool.get_str(*string_index)?)
+                        }
+                        entry => {
+                            return Err(ErrorKind::WrongConstantPoolEntry {
+                                index,
+                                expected: ConstantPoolTag::String,
+                                found: entry.tag(),
+                            })
+                        }
+                    },
+                };
+                return Ok(Some(constant));
+            }
+        }
+        Ok(None)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -58,6 +266,57 @@ pub enum AttributeTag {
     RuntimeInvisibleParameterAnnotations,
     AnnotationDefault,
     BootstrapMethods,
+    Module,
+    ModulePackages,
+    ModuleMainClass,
+    NestHost,
+    NestMembers,
+    Record,
+    PermittedSubclasses,
+    MethodParameters,
+    RuntimeVisibleTypeAnnotations,
+    RuntimeInvisibleTypeAnnotations,
+    Unknown,
+}
+
+impl AttributeTag {
+    /// The name the attribute is stored under, or `None` for
+    /// [`AttributeTag::Unknown`].
+    pub fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::ConstantValue => "ConstantValue",
+            Self::Code => "Code",
+            Self::StackMapTable => "StackMapTable",
+            Self::Exceptions => "Exceptions",
+            Self::InnerClasses => "InnerClasses",
+            Self::EnclosingMethod => "EnclosingMethod",
+            Self::Synthetic => "Synthetic",
+            Self::Signature => "Signature",
+            Self::SourceFile => "SourceFile",
+            Self::SourceDebugExtension => "SourceDebugExtension",
+            Self::LineNumberTable => "LineNumberTable",
+            Self::LocalVariableTable => "LocalVariableTable",
+            Self::LocalVariableTypeTable => "LocalVariableTypeTable",
+            Self::Deprecated => "Deprecated",
+            Self::RuntimeVisibleAnnotations => "RuntimeVisibleAnnotations",
+            Self::RuntimeInvisibleAnnotations => "RuntimeInvisibleAnnotations",
+            Self::RuntimeVisibleParameterAnnotations => "RuntimeVisibleParameterAnnotations",
+            Self::RuntimeInvisibleParameterAnnotations => "RuntimeInvisibleParameterAnnotations",
+            Self::AnnotationDefault => "AnnotationDefault",
+            Self::BootstrapMethods => "BootstrapMethods",
+            Self::Module => "Module",
+            Self::ModulePackages => "ModulePackages",
+            Self::ModuleMainClass => "ModuleMainClass",
+            Self::NestHost => "NestHost",
+            Self::NestMembers => "NestMembers",
+            Self::Record => "Record",
+            Self::PermittedSubclasses => "PermittedSubclasses",
+            Self::MethodParameters => "MethodParameters",
+            Self::RuntimeVisibleTypeAnnotations => "RuntimeVisibleTypeAnnotations",
+            Self::RuntimeInvisibleTypeAnnotations => "RuntimeInvisibleTypeAnnotations",
+            Self::Unknown => return None,
+        })
+    }
 }
 
 impl From<&[u8]> for AttributeTag {
@@ -83,22 +342,34 @@ impl From<&[u8]> for AttributeTag {
             b"RuntimeInvisibleParameterAnnotations" => Self::RuntimeInvisibleParameterAnnotations,
             b"AnnotationDefault" => Self::AnnotationDefault,
             b"BootstrapMethods" => Self::BootstrapMethods,
-            _ => unreachable!(),
+            b"Module" => Self::Module,
+            b"ModulePackages" => Self::ModulePackages,
+            b"ModuleMainClass" => Self::ModuleMainClass,
+            b"NestHost" => Self::NestHost,
+            b"NestMembers" => Self::NestMembers,
+            b"Record" => Self::Record,
+            b"PermittedSubclasses" => Self::PermittedSubclasses,
+            b"MethodParameters" => Self::MethodParameters,
+            b"RuntimeVisibleTypeAnnotations" => Self::RuntimeVisibleTypeAnnotations,
+            b"RuntimeInvisibleTypeAnnotations" => Self::RuntimeInvisibleTypeAnnotations,
+            _ => Self::Unknown,
         }
     }
 }
 
 #[derive(Debug, Clone)]
-pub enum Attribute {
+pub enum Attribute<'a> {
     ConstantValue {
         constant_value_index: U2,
     },
     Code {
         max_stack: U2,
         max_locals: U2,
-        code: Vec<U1>,
+        code: Cow<'a, [U1]>,
         exception_table: Vec<ExceptionHandler>,
-        attributes: Vec<Attribute>,
+        attributes: Vec<Attribute<'a>>,
+        /// As in [`ClassFile::attribute_name_indices`].
+        attribute_name_indices: Vec<U2>,
     },
     StackMapTable {
         entries: Vec<StackMapFrame>,
@@ -122,7 +393,7 @@ pub enum Attribute {
         source_file_index: U2,
     },
     SourceDebugExtension {
-        debug_extension: Vec<U1>,
+        debug_extension: Cow<'a, [U1]>,
     },
     LineNumberTable {
         line_number_table: Vec<LineNumber>,
@@ -141,10 +412,12 @@ pub enum Attribute {
         annotations: Vec<Annotation>,
     },
     RuntimeVisibleParameterAnnotations {
-        annotations: Vec<Annotation>,
+        num_parameters: U1,
+        parameter_annotations: Vec<Vec<Annotation>>,
     },
     RuntimeInvisibleParameterAnnotations {
-        annotations: Vec<Annotation>,
+        num_parameters: U1,
+        parameter_annotations: Vec<Vec<Annotation>>,
     },
     AnnotationDefault {
         default_value: ElementValue,
@@ -152,6 +425,226 @@ pub enum Attribute {
     BootstrapMethods {
         bootstrap_methods: Vec<BootstrapMethod>,
     },
+    Module {
+        module_name_index: U2,
+        module_flags: ModuleFlags,
+        module_version_index: U2,
+        requires: Vec<ModuleRequires>,
+        exports: Vec<ModuleExports>,
+        opens: Vec<ModuleOpens>,
+        uses_index: Vec<U2>,
+        provides: Vec<ModuleProvides>,
+    },
+    ModulePackages {
+        package_index: Vec<U2>,
+    },
+    ModuleMainClass {
+        main_class_index: U2,
+    },
+    NestHost {
+        host_class_index: U2,
+    },
+    NestMembers {
+        classes: Vec<U2>,
+    },
+    Record {
+        components: Vec<RecordComponent<'a>>,
+    },
+    PermittedSubclasses {
+        classes: Vec<U2>,
+    },
+    MethodParameters {
+        parameters: Vec<MethodParameter>,
+    },
+    RuntimeVisibleTypeAnnotations {
+        annotations: Vec<TypeAnnotation>,
+    },
+    RuntimeInvisibleTypeAnnotations {
+        annotations: Vec<TypeAnnotation>,
+    },
+    /// An attribute this crate does not recognize, or one that overran its
+    /// length in [`Mode::Lenient`], kept as its raw payload.
+    Unknown {
+        name_index: U2,
+        info: Cow<'a, [U1]>,
+    },
+}
+
+impl Attribute<'_> {
+    /// Copies any bytes borrowed from the parsed input.
+    pub fn into_owned(self) -> Attribute<'static> {
+        match self {
+            Self::ConstantValue {
+                constant_value_index,
+            } => Attribute::ConstantValue {
+                constant_value_index,
+            },
+            Self::Code {
+                max_stack,
+                max_locals,
+                code,
+                exception_table,
+                attributes,
+                attribute_name_indices,
+            } => Attribute::Code {
+                max_stack,
+                max_locals,
+                code: Cow::Owned(code.into_owned()),
+                exception_table,
+                attributes: owned_attributes(attributes),
+                attribute_name_indices,
+            },
+            Self::StackMapTable { entries } => Attribute::StackMapTable { entries },
+            Self::Exceptions {
+                number_of_exceptions,
+                exception_index_table,
+            } => Attribute::Exceptions {
+                number_of_exceptions,
+                exception_index_table,
+            },
+            Self::InnerClasses { classes } => Attribute::InnerClasses { classes },
+            Self::EnclosingMethod {
+                class_index,
+                method_index,
+            } => Attribute::EnclosingMethod {
+                class_index,
+                method_index,
+            },
+            Self::Synthetic => Attribute::Synthetic,
+            Self::Signature { signature_index } => Attribute::Signature { signature_index },
+            Self::SourceFile { source_file_index } => Attribute::SourceFile { source_file_index },
+            Self::SourceDebugExtension { debug_extension } => Attribute::SourceDebugExtension {
+                debug_extension: Cow::Owned(debug_extension.into_owned()),
+            },
+            Self::LineNumberTable { line_number_table } => {
+                Attribute::LineNumberTable { line_number_table }
+            }
+            Self::LocalVariableTable {
+                local_variable_table,
+            } => Attribute::LocalVariableTable {
+                local_variable_table,
+            },
+            Self::LocalVariableTypeTable {
+                local_variable_type_table,
+            } => Attribute::LocalVariableTypeTable {
+                local_variable_type_table,
+            },
+            Self::Deprecated => Attribute::Deprecated,
+            Self::RuntimeVisibleAnnotations { annotations } => {
+                Attribute::RuntimeVisibleAnnotations { annotations }
+            }
+            Self::RuntimeInvisibleAnnotations { annotations } => {
+                Attribute::RuntimeInvisibleAnnotations { annotations }
+            }
+            Self::RuntimeVisibleParameterAnnotations {
+                num_parameters,
+                parameter_annotations,
+            } => Attribute::RuntimeVisibleParameterAnnotations {
+                num_parameters,
+                parameter_annotations,
+            },
+            Self::RuntimeInvisibleParameterAnnotations {
+                num_parameters,
+                parameter_annotations,
+            } => Attribute::RuntimeInvisibleParameterAnnotations {
+                num_parameters,
+                parameter_annotations,
+            },
+            Self::AnnotationDefault { default_value } => {
+                Attribute::AnnotationDefault { default_value }
+            }
+            Self::BootstrapMethods { bootstrap_methods } => {
+                Attribute::BootstrapMethods { bootstrap_methods }
+            }
+            Self::Module {
+                module_name_index,
+                module_flags,
+                module_version_index,
+                requires,
+                exports,
+                opens,
+                uses_index,
+                provides,
+            } => Attribute::Module {
+                module_name_index,
+                module_flags,
+                module_version_index,
+                requires,
+                exports,
+                opens,
+                uses_index,
+                provides,
+            },
+            Self::ModulePackages { package_index } => Attribute::ModulePackages { package_index },
+            Self::ModuleMainClass { main_class_index } => {
+                Attribute::ModuleMainClass { main_class_index }
+            }
+            Self::NestHost { host_class_index } => Attribute::NestHost { host_class_index },
+            Self::NestMembers { classes } => Attribute::NestMembers { classes },
+            Self::Record { components } => Attribute::Record {
+                components: components
+                    .into_iter()
+                    .map(RecordComponent::into_owned)
+                    .collect(),
+            },
+            Self::PermittedSubclasses { classes } => Attribute::PermittedSubclasses { classes },
+            Self::MethodParameters { parameters } => Attribute::MethodParameters { parameters },
+            Self::RuntimeVisibleTypeAnnotations { annotations } => {
+                Attribute::RuntimeVisibleTypeAnnotations { annotations }
+            }
+            Self::RuntimeInvisibleTypeAnnotations { annotations } => {
+                Attribute::RuntimeInvisibleTypeAnnotations { annotations }
+            }
+            Self::Unknown { name_index, info } => Attribute::Unknown {
+                name_index,
+                info: Cow::Owned(info.into_owned()),
+            },
+        }
+    }
+
+    pub fn tag(&self) -> AttributeTag {
+        match self {
+            Self::ConstantValue { .. } => AttributeTag::ConstantValue,
+            Self::Code { .. } => AttributeTag::Code,
+            Self::StackMapTable { .. } => AttributeTag::StackMapTable,
+            Self::Exceptions { .. } => AttributeTag::Exceptions,
+            Self::InnerClasses { .. } => AttributeTag::InnerClasses,
+            Self::EnclosingMethod { .. } => AttributeTag::EnclosingMethod,
+            Self::Synthetic => AttributeTag::Synthetic,
+            Self::Signature { .. } => AttributeTag::Signature,
+            Self::SourceFile { .. } => AttributeTag::SourceFile,
+            Self::SourceDebugExtension { .. } => AttributeTag::SourceDebugExtension,
+            Self::LineNumberTable { .. } => AttributeTag::LineNumberTable,
+            Self::LocalVariableTable { .. } => AttributeTag::LocalVariableTable,
+            Self::LocalVariableTypeTable { .. } => AttributeTag::LocalVariableTypeTable,
+            Self::Deprecated => AttributeTag::Deprecated,
+            Self::RuntimeVisibleAnnotations { .. } => AttributeTag::RuntimeVisibleAnnotations,
+            Self::RuntimeInvisibleAnnotations { .. } => AttributeTag::RuntimeInvisibleAnnotations,
+            Self::RuntimeVisibleParameterAnnotations { .. } => {
+                AttributeTag::RuntimeVisibleParameterAnnotations
+            }
+            Self::RuntimeInvisibleParameterAnnotations { .. } => {
+                AttributeTag::RuntimeInvisibleParameterAnnotations
+            }
+            Self::AnnotationDefault { .. } => AttributeTag::AnnotationDefault,
+            Self::BootstrapMethods { .. } => AttributeTag::BootstrapMethods,
+            Self::Module { .. } => AttributeTag::Module,
+            Self::ModulePackages { .. } => AttributeTag::ModulePackages,
+            Self::ModuleMainClass { .. } => AttributeTag::ModuleMainClass,
+            Self::NestHost { .. } => AttributeTag::NestHost,
+            Self::NestMembers { .. } => AttributeTag::NestMembers,
+            Self::Record { .. } => AttributeTag::Record,
+            Self::PermittedSubclasses { .. } => AttributeTag::PermittedSubclasses,
+            Self::MethodParameters { .. } => AttributeTag::MethodParameters,
+            Self::RuntimeVisibleTypeAnnotations { .. } => {
+                AttributeTag::RuntimeVisibleTypeAnnotations
+            }
+            Self::RuntimeInvisibleTypeAnnotations { .. } => {
+                AttributeTag::RuntimeInvisibleTypeAnnotations
+            }
+            Self::Unknown { .. } => AttributeTag::Unknown,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -218,7 +711,7 @@ pub struct InnerClass {
     pub inner_class_info: U2,
     pub outer_class_info: U2,
     pub inner_name_index: U2,
-    pub inner_class_access_flags: U2,
+    pub inner_class_access_flags: InnerClassAccess,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -238,10 +731,187 @@ pub struct LocalVariable {
 
 #[derive(Debug, Clone)]
 pub struct Annotation {
-    pub type_name: Vec<U1>,
+    /// The `Utf8` entry holding the annotation interface as a field
+    /// descriptor, e.g. `Ljava/lang/Deprecated;`. See
+    /// [`ConstantPool::get_field_type`].
+    pub type_index: U2,
     pub element_value_pairs: Vec<ElementValuePair>,
 }
 
+/// An annotation on a use of a type (JSR 308).
+#[derive(Debug, Clone)]
+pub struct TypeAnnotation {
+    pub target: TypeAnnotationTarget,
+    pub target_path: TypePath,
+    pub annotation: Annotation,
+}
+
+/// The kind of type use an annotation applies to, as given by its
+/// `target_type` and `target_info`.
+#[derive(Debug, Clone)]
+pub enum TypeAnnotationTarget {
+    ClassTypeParameter {
+        type_parameter_index: U1,
+    },
+    MethodTypeParameter {
+        type_parameter_index: U1,
+    },
+    /// `supertype_index` 65535 denotes the superclass, any other value an
+    /// index into `interfaces`.
+    Supertype {
+        supertype_index: U2,
+    },
+    ClassTypeParameterBound {
+        type_parameter_index: U1,
+        bound_index: U1,
+    },
+    MethodTypeParameterBound {
+        type_parameter_index: U1,
+        bound_index: U1,
+    },
+    /// The type of a field or record component.
+    Field,
+    /// The return type of a method, or the type of a newly constructed object.
+    Return,
+    Receiver,
+    FormalParameter {
+        formal_parameter_index: U1,
+    },
+    Throws {
+        throws_type_index: U2,
+    },
+    LocalVariable {
+        table: Vec<LocalVariableTarget>,
+    },
+    ResourceVariable {
+        table: Vec<LocalVariableTarget>,
+    },
+    ExceptionParameter {
+        exception_table_index: U2,
+    },
+    InstanceOf {
+        offset: U2,
+    },
+    New {
+        offset: U2,
+    },
+    ConstructorReference {
+        offset: U2,
+    },
+    MethodReference {
+        offset: U2,
+    },
+    Cast {
+        offset: U2,
+        type_argument_index: U1,
+    },
+    ConstructorInvocationTypeArgument {
+        offset: U2,
+        type_argument_index: U1,
+    },
+    MethodInvocationTypeArgument {
+        offset: U2,
+        type_argument_index: U1,
+    },
+    ConstructorReferenceTypeArgument {
+        offset: U2,
+        type_argument_index: U1,
+    },
+    MethodReferenceTypeArgument {
+        offset: U2,
+        type_argument_index: U1,
+    },
+}
+
+impl TypeAnnotationTarget {
+    pub fn target_type(&self) -> U1 {
+        match self {
+            Self::ClassTypeParameter { .. } => 0x00,
+            Self::MethodTypeParameter { .. } => 0x01,
+            Self::Supertype { .. } => 0x10,
+            Self::ClassTypeParameterBound { .. } => 0x11,
+            Self::MethodTypeParameterBound { .. } => 0x12,
+            Self::Field => 0x13,
+            Self::Return => 0x14,
+            Self::Receiver => 0x15,
+            Self::FormalParameter { .. } => 0x16,
+            Self::Throws { .. } => 0x17,
+            Self::LocalVariable { .. } => 0x40,
+            Self::ResourceVariable { .. } => 0x41,
+            Self::ExceptionParameter { .. } => 0x42,
+            Self::InstanceOf { .. } => 0x43,
+            Self::New { .. } => 0x44,
+            Self::ConstructorReference { .. } => 0x45,
+            Self::MethodReference { .. } => 0x46,
+            Self::Cast { .. } => 0x47,
+            Self::ConstructorInvocationTypeArgument { .. } => 0x48,
+            Self::MethodInvocationTypeArgument { .. } => 0x49,
+            Self::ConstructorReferenceTypeArgument { .. } => 0x4A,
+            Self::MethodReferenceTypeArgument { .. } => 0x4B,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LocalVariableTarget {
+    pub start_pc: U2,
+    pub length: U2,
+    pub index: U2,
+}
+
+/// The location of the annotated type within the type named by the target,
+/// e.g. the `String` in `List<String>`. An empty path denotes the target
+/// type itself.
+#[derive(Debug, Clone, Default)]
+pub struct TypePath {
+    pub path: Vec<TypePathEntry>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TypePathEntry {
+    pub kind: TypePathKind,
+    /// Which type argument of a parameterized type is meant; 0 for the
+    /// other kinds.
+    pub type_argument_index: U1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypePathKind {
+    /// Deeper in an array type.
+    Array,
+    /// Deeper in a nested type.
+    Nested,
+    /// On the bound of a wildcard type argument.
+    WildcardBound,
+    /// On a type argument of a parameterized type.
+    TypeArgument,
+}
+
+impl From<TypePathKind> for U1 {
+    fn from(kind: TypePathKind) -> Self {
+        match kind {
+            TypePathKind::Array => 0,
+            TypePathKind::Nested => 1,
+            TypePathKind::WildcardBound => 2,
+            TypePathKind::TypeArgument => 3,
+        }
+    }
+}
+
+impl TryFrom<u8> for TypePathKind {
+    type Error = ErrorKind;
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        Ok(match b {
+            0 => Self::Array,
+            1 => Self::Nested,
+            2 => Self::WildcardBound,
+            3 => Self::TypeArgument,
+            _ => return Err(ErrorKind::UnknownTypePathKind(b)),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ElementValuePair {
     pub element_name_index: U2,
@@ -265,9 +935,11 @@ pub enum ElementValueTag {
     Array,
 }
 
-impl From<u8> for ElementValueTag {
-    fn from(b: u8) -> Self {
-        match b {
+impl TryFrom<u8> for ElementValueTag {
+    type Error = ErrorKind;
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        Ok(match b {
             b'B' => Self::Byte,
             b'C' => Self::Char,
             b'D' => Self::Double,
@@ -281,8 +953,8 @@ impl From<u8> for ElementValueTag {
             b'c' => Self::Class,
             b'@' => Self::Annotation,
             b'[' => Self::Array,
-            _ => unreachable!(),
-        }
+            _ => return Err(ErrorKind::UnknownElementValueTag(b)),
+        })
     }
 }
 
@@ -330,16 +1002,178 @@ pub enum ElementValue {
     },
 }
 
+impl ElementValue {
+    /// Resolves a primitive or `String` element value, or returns `None`
+    /// for enum constants, classes, annotations and arrays.
+    pub fn constant<'c>(
+        &self,
+        constant_pool: &'c ConstantPool,
+    ) -> Result<Option<Constant<'c>>, ErrorKind> {
+        Ok(Some(match *self {
+            Self::Byte { index } => Constant::Byte(constant_pool.get_int(index)? as i8),
+            Self::Char { index } => Constant::Char(constant_pool.get_int(index)? as u16),
+            Self::Short { index } => Constant::Short(constant_pool.get_int(index)? as i16),
+            Self::Boolean { index } => Constant::Boolean(constant_pool.get_int(index)? != 0),
+            Self::Int { index } => Constant::Int(constant_pool.get_int(index)?),
+            Self::Long { index } => Constant::Long(constant_pool.get_long(index)?),
+            Self::Float { index } => Constant::Float(constant_pool.get_float(index)?),
+            Self::Double { index } => Constant::Double(constant_pool.get_double(index)?),
+            Self::String { index } => Constant::String(constant_pool.get_str(index)?),
+            Self::EnumConstValue { .. }
+            | Self::Class { .. }
+            | Self::Annotation { .. }
+            | Self::Array { .. } => return Ok(None),
+        }))
+    }
+}
+
+/// A constant value of a field or annotation element, resolved from the
+/// constant pool. `Byte`, `Char`, `Short` and `Boolean` are narrowed from
+/// `Integer` entries as the JVM does.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constant<'c> {
+    Byte(i8),
+    /// A UTF-16 code unit, which may be half of a surrogate pair.
+    Char(u16),
+    Short(i16),
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    String(Cow<'c, str>),
+}
+
 #[derive(Debug, Clone)]
 pub struct BootstrapMethod {
     pub method_ref: U2,
     pub args: Vec<U2>,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct ModuleRequires {
+    pub requires_index: U2,
+    pub requires_flags: RequiresFlags,
+    pub requires_version_index: U2,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuleExports {
+    pub exports_index: U2,
+    pub exports_flags: ExportsFlags,
+    pub exports_to_index: Vec<U2>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuleOpens {
+    pub opens_index: U2,
+    pub opens_flags: ExportsFlags,
+    pub opens_to_index: Vec<U2>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuleProvides {
+    pub provides_index: U2,
+    pub provides_with_index: Vec<U2>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordComponent<'a> {
+    pub name_index: U2,
+    pub descriptor_index: U2,
+    pub attributes: Vec<Attribute<'a>>,
+    /// As in [`ClassFile::attribute_name_indices`].
+    pub attribute_name_indices: Vec<U2>,
+}
+
+impl RecordComponent<'_> {
+    /// Copies any bytes borrowed from the parsed input.
+    pub fn into_owned(self) -> RecordComponent<'static> {
+        RecordComponent {
+            name_index: self.name_index,
+            descriptor_index: self.descriptor_index,
+            attributes: owned_attributes(self.attributes),
+            attribute_name_indices: self.attribute_name_indices,
+        }
+    }
+
+    /// Parses the component's `Signature` attribute, if it has one.
+    pub fn signature(
+        &self,
+        constant_pool: &ConstantPool,
+    ) -> Result<Option<ReferenceTypeSignature>, ErrorKind> {
+        signature::find(
+            &self.attributes,
+            constant_pool,
+            ReferenceTypeSignature::parse,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MethodParameter {
+    /// Index of the parameter name, or 0 if the parameter is unnamed.
+    pub name_index: U2,
+    pub access_flags: ParameterAccess,
+}
+
 #[derive(Debug)]
-pub struct MethodInfo {
-    pub access_flags: U2,
+pub struct MethodInfo<'a> {
+    pub access_flags: MethodAccess,
     pub name_index: U2,
     pub descriptor_index: U2,
-    pub attributes: Vec<Attribute>,
+    pub attributes: Vec<Attribute<'a>>,
+    /// As in [`ClassFile::attribute_name_indices`].
+    pub attribute_name_indices: Vec<U2>,
+}
+
+impl MethodInfo<'_> {
+    /// Copies any bytes borrowed from the parsed input.
+    pub fn into_owned(self) -> MethodInfo<'static> {
+        MethodInfo {
+            access_flags: self.access_flags,
+            name_index: self.name_index,
+            descriptor_index: self.descriptor_index,
+            attributes: owned_attributes(self.attributes),
+            attribute_name_indices: self.attribute_name_indices,
+        }
+    }
+
+    pub fn descriptor(&self, constant_pool: &ConstantPool) -> Result<MethodDescriptor, ErrorKind> {
+        constant_pool.get_method_descriptor(self.descriptor_index)
+    }
+
+    /// Parses the method's `Signature` attribute, if it has one.
+    pub fn signature(
+        &self,
+        constant_pool: &ConstantPool,
+    ) -> Result<Option<MethodSignature>, ErrorKind> {
+        signature::find(&self.attributes, constant_pool, MethodSignature::parse)
+    }
+
+    /// Resolves the parameter names recorded in the `MethodParameters`
+    /// attribute, or returns `None` if the method has none. Unnamed
+    /// parameters are `None`.
+    pub fn parameter_names<'c>(
+        &self,
+        constant_pool: &'c ConstantPool,
+    ) -> Result<Option<Vec<Option<Cow<'c, str>>>>, ErrorKind> {
+        for attribute in &self.attributes {
+            if let Attribute::MethodParameters { parameters } = attribute {
+                return parameters
+                    .iter()
+                    .map(|parameter| match parameter.name_index {
+                        0 => Ok(None),
+                        index => constant_pool.get_str(index).map(Some),
+                    })
+                    .collect::<Result<_, _>>()
+                    .map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn owned_attributes(attributes: Vec<Attribute>) -> Vec<Attribute<'static>> {
+    attributes.into_iter().map(Attribute::into_owned).collect()
 }
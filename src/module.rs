@@ -0,0 +1,164 @@
+use crate::{
+    access::{ExportsFlags, ModuleFlags, RequiresFlags},
+    constant_pool::ConstantPool,
+    error::ErrorKind,
+    Attribute, ClassFile, U2,
+};
+
+/// The contents of a `module-info.class`, with every name resolved through
+/// the constant pool.
+#[derive(Debug, Clone)]
+pub struct ModuleDescriptor {
+    pub name: String,
+    pub flags: ModuleFlags,
+    pub version: Option<String>,
+    pub requires: Vec<Requires>,
+    pub exports: Vec<Exports>,
+    pub opens: Vec<Opens>,
+    /// Internal names of the service interfaces the module uses.
+    pub uses: Vec<String>,
+    pub provides: Vec<Provides>,
+    /// All packages of the module, from the `ModulePackages` attribute.
+    pub packages: Vec<String>,
+    /// From the `ModuleMainClass` attribute.
+    pub main_class: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Requires {
+    pub module: String,
+    pub flags: RequiresFlags,
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Exports {
+    pub package: String,
+    pub flags: ExportsFlags,
+    /// The modules the package is exported to; empty for an unqualified export.
+    pub to: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Opens {
+    pub package: String,
+    pub flags: ExportsFlags,
+    /// The modules the package is opened to; empty for an unqualified open.
+    pub to: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Provides {
+    pub service: String,
+    pub with: Vec<String>,
+}
+
+impl ModuleDescriptor {
+    /// Builds the descriptor from the class file's `Module` attribute, or
+    /// returns `None` if it has none.
+    pub fn from_class_file(class_file: &ClassFile) -> Result<Option<Self>, ErrorKind> {
+        let cp = &class_file.constant_pool;
+        let mut descriptor = None;
+        let mut packages = Vec::new();
+        let mut main_class = None;
+        for attribute in &class_file.attributes {
+            match attribute {
+                Attribute::Module {
+                    module_name_index,
+                    module_flags,
+                    module_version_index,
+                    requires,
+                    exports,
+                    opens,
+                    uses_index,
+                    provides,
+                } => {
+                    descriptor = Some(Self {
+                        name: cp.get_module_name(*module_name_index)?.into_owned(),
+                        flags: *module_flags,
+                        version: optional_str(cp, *module_version_index)?,
+                        requires: requires
+                            .iter()
+                            .map(|r| {
+                                Ok(Requires {
+                                    module: cp.get_module_name(r.requires_index)?.into_owned(),
+                                    flags: r.requires_flags,
+                                    version: optional_str(cp, r.requires_version_index)?,
+                                })
+                            })
+                            .collect::<Result<_, ErrorKind>>()?,
+                        exports: exports
+                            .iter()
+                            .map(|e| {
+                                Ok(Exports {
+                                    package: cp.get_package_name(e.exports_index)?.into_owned(),
+                                    flags: e.exports_flags,
+                                    to: module_names(cp, &e.exports_to_index)?,
+                                })
+                            })
+                            .collect::<Result<_, ErrorKind>>()?,
+                        opens: opens
+                            .iter()
+                            .map(|o| {
+                                Ok(Opens {
+                                    package: cp.get_package_name(o.opens_index)?.into_owned(),
+                                    flags: o.opens_flags,
+                                    to: module_names(cp, &o.opens_to_index)?,
+                                })
+                            })
+                            .collect::<Result<_, ErrorKind>>()?,
+                        uses: class_names(cp, uses_index)?,
+                        provides: provides
+                            .iter()
+                            .map(|p| {
+                                Ok(Provides {
+                                    service: cp.get_class_name(p.provides_index)?.into_owned(),
+                                    with: class_names(cp, &p.provides_with_index)?,
+                                })
+                            })
+                            .collect::<Result<_, ErrorKind>>()?,
+                        packages: Vec::new(),
+                        main_class: None,
+                    });
+                }
+                Attribute::ModulePackages { package_index } => {
+                    packages = package_index
+                        .iter()
+                        .map(|&index| cp.get_package_name(index).map(|name| name.into_owned()))
+                        .collect::<Result<_, _>>()?;
+                }
+                Attribute::ModuleMainClass { main_class_index } => {
+                    main_class = Some(cp.get_class_name(*main_class_index)?.into_owned());
+                }
+                _ => {}
+            }
+        }
+        Ok(descriptor.map(|descriptor| Self {
+            packages,
+            main_class,
+            ..descriptor
+        }))
+    }
+}
+
+/// Resolves a `Utf8` index where 0 means absent.
+fn optional_str(cp: &ConstantPool, index: U2) -> Result<Option<String>, ErrorKind> {
+    if index == 0 {
+        return Ok(None);
+    }
+    cp.get_str(index).map(|s| Some(s.into_owned()))
+}
+
+fn module_names(cp: &ConstantPool, indices: &[U2]) -> Result<Vec<String>, ErrorKind> {
+    indices
+        .iter()
+        .map(|&index| cp.get_module_name(index).map(|name| name.into_owned()))
+        .collect()
+}
+
+fn class_names(cp: &ConstantPool, indices: &[U2]) -> Result<Vec<String>, ErrorKind> {
+    indices
+        .iter()
+        .map(|&index| cp.get_class_name(index).map(|name| name.into_owned()))
+        .collect()
+}
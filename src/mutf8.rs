@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+
+use crate::error::ErrorKind;
+
+/// Checks that `bytes` is well-formed modified UTF-8.
+///
+/// Modified UTF-8 differs from UTF-8 in encoding NUL as the two bytes
+/// `C0 80` and each half of a surrogate pair separately in three bytes, so
+/// it can hold any Java string, unpaired surrogates included. Fails with
+/// [`ErrorKind::InvalidModifiedUtf8`] at a raw NUL byte or the first byte of
+/// a malformed or overlong sequence.
+pub fn validate(bytes: &[u8]) -> Result<(), ErrorKind> {
+    let mut offset = 0;
+    while offset < bytes.len() {
+        offset += code_unit(bytes, offset)?.1;
+    }
+    Ok(())
+}
+
+/// Decodes modified UTF-8 into a string, borrowing text that has no NUL or
+/// supplementary characters.
+///
+/// Besides malformed bytes, fails on an unpaired surrogate, which a Rust
+/// string cannot hold; [`decode_utf16`] keeps those.
+pub fn decode(bytes: &[u8]) -> Result<Cow<'_, str>, ErrorKind> {
+    if is_plain(bytes) {
+        if let Ok(s) = std::str::from_utf8(bytes) {
+            return Ok(Cow::Borrowed(s));
+        }
+    }
+    let mut decoded = String::with_capacity(bytes.len());
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let (unit, mut len) = code_unit(bytes, offset)?;
+        let c = match unit {
+            0xD800..=0xDBFF => match code_unit(bytes, offset + len) {
+                Ok((low @ 0xDC00..=0xDFFF, low_len)) => {
+                    len += low_len;
+                    char::decode_utf16([unit, low]).next().and_then(Result::ok)
+                }
+                _ => None,
+            },
+            unit => char::from_u32(u32::from(unit)),
+        };
+        decoded.push(c.ok_or(ErrorKind::InvalidModifiedUtf8 {
+            index: None,
+            offset,
+        })?);
+        offset += len;
+    }
+    Ok(Cow::Owned(decoded))
+}
+
+/// Decodes modified UTF-8 like [`decode`], replacing malformed bytes and
+/// unpaired surrogates with U+FFFD.
+pub fn decode_lossy(bytes: &[u8]) -> Cow<'_, str> {
+    if let Ok(decoded) = decode(bytes) {
+        return decoded;
+    }
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut offset = 0;
+    while offset < bytes.len() {
+        match code_unit(bytes, offset) {
+            Ok((unit, len)) => {
+                units.push(unit);
+                offset += len;
+            }
+            Err(_) => {
+                units.push(0xFFFD);
+                offset += 1;
+            }
+        }
+    }
+    Cow::Owned(
+        char::decode_utf16(units)
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect(),
+    )
+}
+
+/// Decodes modified UTF-8 into the UTF-16 code units of a Java string.
+pub fn decode_utf16(bytes: &[u8]) -> Result<Vec<u16>, ErrorKind> {
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let (unit, len) = code_unit(bytes, offset)?;
+        units.push(unit);
+        offset += len;
+    }
+    Ok(units)
+}
+
+/// Encodes `s` as modified UTF-8, borrowing it when the encodings agree.
+pub fn encode(s: &str) -> Cow<'_, [u8]> {
+    if is_plain(s.as_bytes()) {
+        return Cow::Borrowed(s.as_bytes());
+    }
+    Cow::Owned(encode_utf16(&s.encode_utf16().collect::<Vec<_>>()))
+}
+
+/// Encodes the UTF-16 code units of a Java string as modified UTF-8.
+pub fn encode_utf16(units: &[u16]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(units.len());
+    for &unit in units {
+        match unit {
+            0x01..=0x7F => encoded.push(unit as u8),
+            0x00 | 0x80..=0x7FF => {
+                encoded.extend_from_slice(&[0xC0 | (unit >> 6) as u8, 0x80 | (unit & 0x3F) as u8])
+            }
+            _ => encoded.extend_from_slice(&[
+                0xE0 | (unit >> 12) as u8,
+                0x80 | (unit >> 6 & 0x3F) as u8,
+                0x80 | (unit & 0x3F) as u8,
+            ]),
+        }
+    }
+    encoded
+}
+
+/// Whether `bytes` has no NUL and no four-byte sequence, so that modified
+/// UTF-8 and UTF-8 read it the same way.
+fn is_plain(bytes: &[u8]) -> bool {
+    !bytes.iter().any(|&b| b == 0 || b >= 0xF0)
+}
+
+/// Decodes the UTF-16 code unit at `offset`, returning it with the length of
+/// its encoding.
+fn code_unit(bytes: &[u8], offset: usize) -> Result<(u16, usize), ErrorKind> {
+    let invalid = ErrorKind::InvalidModifiedUtf8 {
+        index: None,
+        offset,
+    };
+    let continuation = |i: usize| match bytes.get(offset + i) {
+        Some(&b) if b & 0xC0 == 0x80 => Ok(u16::from(b & 0x3F)),
+        _ => Err(invalid.clone()),
+    };
+    let first = *bytes.get(offset).ok_or(invalid.clone())?;
+    let (unit, len) = match first >> 4 {
+        0x0..=0x7 if first != 0 => (u16::from(first), 1),
+        0xC | 0xD => (u16::from(first & 0x1F) << 6 | continuation(1)?, 2),
+        0xE => (
+            u16::from(first & 0x0F) << 12 | continuation(1)? << 6 | continuation(2)?,
+            3,
+        ),
+        _ => return Err(invalid),
+    };
+    // Each code unit has a single encoding, the shortest one, except that
+    // NUL takes the two bytes `C0 80`.
+    match (unit, len) {
+        (0x01..=0x7F, 2) | (0x00..=0x7FF, 3) => Err(invalid),
+        _ => Ok((unit, len)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid(offset: usize) -> ErrorKind {
+        ErrorKind::InvalidModifiedUtf8 {
+            index: None,
+            offset,
+        }
+    }
+
+    #[test]
+    fn nul_takes_two_bytes() {
+        assert_eq!(encode("a\0b").as_ref(), b"a\xc0\x80b");
+        assert_eq!(decode(b"a\xc0\x80b").unwrap(), "a\0b");
+        assert_eq!(validate(b"a\0b"), Err(invalid(1)));
+        assert_eq!(decode(b"a\0b"), Err(invalid(1)));
+    }
+
+    #[test]
+    fn supplementary_characters_are_surrogate_pairs() {
+        // U+1F600 is D83D DE00 in UTF-16.
+        let encoded = b"\xed\xa0\xbd\xed\xb8\x80";
+        assert_eq!(encode("\u{1f600}").as_ref(), encoded);
+        assert_eq!(decode(encoded).unwrap(), "\u{1f600}");
+        assert_eq!(decode_utf16(encoded).unwrap(), [0xd83d, 0xde00]);
+        // The four-byte UTF-8 form is not modified UTF-8.
+        assert_eq!(validate("\u{1f600}".as_bytes()), Err(invalid(0)));
+    }
+
+    #[test]
+    fn unpaired_surrogates_round_trip_as_utf16() {
+        for units in [&[0xd83d][..], &[0xde00, 0x41], &[0x41, 0xd83d, 0xd83d]] {
+            let encoded = encode_utf16(units);
+            assert_eq!(validate(&encoded), Ok(()));
+            assert_eq!(decode_utf16(&encoded).unwrap(), units);
+        }
+        let encoded = encode_utf16(&[0x41, 0xd83d, 0x42]);
+        assert_eq!(decode(&encoded), Err(invalid(1)));
+        assert_eq!(decode_lossy(&encoded), "A\u{fffd}B");
+    }
+
+    #[test]
+    fn malformed_sequences_fail_at_their_first_byte() {
+        assert_eq!(validate(b"ab\xc3"), Err(invalid(2)));
+        assert_eq!(validate(b"ab\xe2\x82"), Err(invalid(2)));
+        assert_eq!(validate(b"ab\xe2\x41\x82"), Err(invalid(2)));
+        assert_eq!(validate(b"a\x80"), Err(invalid(1)));
+        assert_eq!(validate(b"a\xf0\x9f\x98\x80"), Err(invalid(1)));
+        assert_eq!(decode_utf16(b"abc\xc3"), Err(invalid(3)));
+        assert_eq!(decode_lossy(b"a\x80b"), "a\u{fffd}b");
+    }
+
+    #[test]
+    fn overlong_sequences_are_rejected() {
+        for overlong in [
+            &b"\xc1\x81"[..],
+            b"\xc0\x81",
+            b"\xc1\xbf",
+            b"\xe0\x81\x81",
+            b"\xe0\x80\x80",
+            b"\xe0\x9f\xbf",
+        ] {
+            let bytes = [b"a", overlong].concat();
+            assert_eq!(validate(&bytes), Err(invalid(1)), "{:x?}", overlong);
+            assert_eq!(decode(&bytes), Err(invalid(1)), "{:x?}", overlong);
+            assert_eq!(decode_utf16(&bytes), Err(invalid(1)), "{:x?}", overlong);
+        }
+        assert_eq!(decode_lossy(b"\xc1\x81"), "\u{fffd}\u{fffd}");
+        // The shortest forms on either side of each boundary are fine.
+        assert_eq!(decode(b"\xc0\x80\x7f\xc2\x80").unwrap(), "\0\u{7f}\u{80}");
+        assert_eq!(decode(b"\xdf\xbf\xe0\xa0\x80").unwrap(), "\u{7ff}\u{800}");
+    }
+
+    #[test]
+    fn plain_text_is_borrowed() {
+        let text = "caf\u{e9} \u{20ac}";
+        assert!(matches!(decode(text.as_bytes()), Ok(Cow::Borrowed(s)) if s == text));
+        assert!(matches!(encode(text), Cow::Borrowed(bytes) if bytes == text.as_bytes()));
+        assert!(matches!(decode(b"a\xc0\x80"), Ok(Cow::Owned(_))));
+        assert!(matches!(encode("a\0"), Cow::Owned(_)));
+    }
+}
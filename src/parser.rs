@@ -1,24 +1,127 @@
+use std::{borrow::Cow, cell::RefCell};
+
 use nom::{
-    bytes::streaming::take,
+    bytes::complete::take,
     multi::count,
-    number::streaming::{be_u16, be_u32, be_u8},
-    IResult,
+    number::complete::{be_u16, be_u32, be_u8},
 };
 
 use crate::{
-    constant_pool::{get_utf8, ConstantPoolTag},
-    Annotation, AttributeTag, BootstrapMethod, ElementValue, ElementValuePair, ElementValueTag,
-    ExceptionHandler, InnerClass, LineNumber, LocalVariable, StackMapFrame, VerificationTypeInfo,
-    {Attribute, ClassFile, ConstantPoolType, FieldInfo, Version},
+    access::{
+        ClassAccess, ExportsFlags, FieldAccess, InnerClassAccess, MethodAccess, ModuleFlags,
+        ParameterAccess, RequiresFlags,
+    },
+    constant_pool::{ConstantPool, ConstantPoolTag, ConstantPoolType},
+    error::{context, fail, ClassFileError, ErrorKind, PResult, ParseError},
+    mutf8, Annotation, AttributeTag, BootstrapMethod, ElementValue, ElementValuePair,
+    ElementValueTag, ExceptionHandler, InnerClass, LineNumber, LocalVariable, LocalVariableTarget,
+    MethodInfo, MethodParameter, ModuleExports, ModuleOpens, ModuleProvides, ModuleRequires,
+    RecordComponent, StackMapFrame, TypeAnnotation, TypeAnnotationTarget, TypePath, TypePathEntry,
+    TypePathKind, VerificationTypeInfo, {Attribute, ClassFile, FieldInfo, Mode, Version, U2},
 };
 
-pub fn class_file(input: &[u8]) -> IResult<&[u8], ClassFile> {
+const MAGIC: u32 = 0xCAFEBABE;
+
+/// State shared by the parsers once the version has been read. The constant
+/// pool is filled in as soon as it has been parsed.
+struct Context<'a> {
+    major_version: U2,
+    constant_pool: ConstantPool<'a>,
+    mode: Mode,
+    warnings: RefCell<Vec<ParseError<'a>>>,
+}
+
+impl<'a> Context<'a> {
+    /// Fails in strict mode; in lenient mode records a warning and continues.
+    fn check(&self, input: &'a [u8], kind: ErrorKind) -> Result<(), nom::Err<ParseError<'a>>> {
+        self.tolerate(ParseError::new(input, kind))
+    }
+
+    fn tolerate(&self, error: ParseError<'a>) -> Result<(), nom::Err<ParseError<'a>>> {
+        match self.mode {
+            Mode::Strict => Err(nom::Err::Failure(error)),
+            Mode::Lenient => {
+                self.warnings.borrow_mut().push(error);
+                Ok(())
+            }
+        }
+    }
+
+    /// Runs `f`, recording `segment` in the path of any error or warning it
+    /// produces.
+    fn within<O>(
+        &self,
+        segment: impl Fn() -> String,
+        f: impl FnOnce() -> PResult<'a, O>,
+    ) -> PResult<'a, O> {
+        let before = self.warnings.borrow().len();
+        let result = context(f(), &segment);
+        for warning in &mut self.warnings.borrow_mut()[before..] {
+            warning.path.push(segment());
+        }
+        result
+    }
+
+    /// Like the free function [`indexed`], but also records the element index
+    /// in warnings.
+    fn indexed<'s, O>(
+        &'s self,
+        label: &'static str,
+        mut f: impl FnMut(&'a [u8]) -> PResult<'a, O> + 's,
+        n: usize,
+    ) -> impl FnMut(&'a [u8]) -> PResult<'a, Vec<O>> + 's {
+        move |mut input| {
+            let mut items = Vec::with_capacity(n.min(256));
+            for i in 0..n {
+                let (rest, item) = self.within(|| format!("{} {}", label, i), || f(input))?;
+                items.push(item);
+                input = rest;
+            }
+            Ok((input, items))
+        }
+    }
+}
+
+pub(crate) fn parse(
+    bytes: &[u8],
+    mode: Mode,
+) -> Result<(ClassFile<'_>, Vec<ClassFileError>), ClassFileError> {
+    match class_file(bytes, mode) {
+        Ok(([], (class_file, warnings))) => Ok((
+            class_file,
+            warnings
+                .into_iter()
+                .map(|w| w.into_class_file_error(bytes))
+                .collect(),
+        )),
+        Ok((rest, _)) => Err(ClassFileError {
+            kind: ErrorKind::TrailingBytes,
+            offset: bytes.len() - rest.len(),
+            path: Vec::new(),
+        }),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(e.into_class_file_error(bytes)),
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers never return Incomplete"),
+    }
+}
+
+fn class_file(input: &[u8], mode: Mode) -> PResult<'_, (ClassFile<'_>, Vec<ParseError<'_>>)> {
     // magic
-    let (input, _) = be_u32(input)?;
+    let (rest, magic) = be_u32(input)?;
+    if magic != MAGIC {
+        return fail(input, ErrorKind::BadMagic(magic));
+    }
+    let input = rest;
     // version
     let (input, version) = version(input)?;
+    let mut ctx = Context {
+        major_version: version.major,
+        constant_pool: ConstantPool::new(),
+        mode,
+        warnings: RefCell::new(Vec::new()),
+    };
     // constant pool
-    let (input, constant_pool) = constant_pool(input)?;
+    let (input, constant_pool) = constant_pool(input, &ctx)?;
+    ctx.constant_pool = constant_pool;
     // access flags
     let (input, access_flags) = be_u16(input)?;
     // this class
@@ -28,23 +131,78 @@ pub fn class_file(input: &[u8]) -> IResult<&[u8], ClassFile> {
     // interfaces
     let (input, interfaces) = interfaces(input)?;
     // fields
-    let (input, fields) = fields(input, &constant_pool)?;
-    todo!()
+    let (input, fields) = fields(input, &ctx)?;
+    // methods
+    let (input, methods) = methods(input, &ctx)?;
+    // attributes
+    let (input, (attributes, attribute_name_indices)) = attributes(input, &ctx)?;
+    let Context {
+        constant_pool,
+        warnings,
+        ..
+    } = ctx;
+    let class_file = ClassFile {
+        version,
+        constant_pool,
+        access_flags: ClassAccess(access_flags),
+        this_class,
+        super_class,
+        interfaces,
+        fields,
+        methods,
+        attributes,
+        attribute_name_indices,
+    };
+    Ok((input, (class_file, warnings.into_inner())))
 }
 
-fn version(input: &[u8]) -> IResult<&[u8], Version> {
+fn version(input: &[u8]) -> PResult<'_, Version> {
     let (input, minor) = be_u16(input)?;
     let (input, major) = be_u16(input)?;
     Ok((input, Version { minor, major }))
 }
 
-fn constant_pool(input: &[u8]) -> IResult<&[u8], Vec<ConstantPoolType>> {
-    let (input, pool_count) = be_u16(input)?;
-    let (input, constant_pool) = count(constant_type, pool_count as usize)(input)?;
+fn constant_pool<'a>(input: &'a [u8], ctx: &Context<'a>) -> PResult<'a, ConstantPool<'a>> {
+    let (mut input, pool_count) = be_u16(input)?;
+    let mut constant_pool = ConstantPool::new();
+    while constant_pool.count() < pool_count {
+        let index = constant_pool.count();
+        let (rest, entry) = ctx.within(
+            || format!("constant pool entry #{}", index),
+            || {
+                let (rest, entry) = constant_type(input)?;
+                let tag = entry.tag();
+                if ctx.major_version < tag.since_major_version() {
+                    let kind = ErrorKind::UnsupportedConstantPoolTag {
+                        tag,
+                        major_version: ctx.major_version,
+                    };
+                    ctx.check(input, kind)?;
+                }
+                if let ConstantPoolType::Utf8 { bytes } = &entry {
+                    if let Err(kind @ ErrorKind::InvalidModifiedUtf8 { offset, .. }) =
+                        mutf8::validate(bytes)
+                    {
+                        // the bytes follow the tag and length
+                        ctx.check(&input[3 + offset..], kind.in_entry(index))?;
+                    }
+                }
+                Ok((rest, entry))
+            },
+        )?;
+        // a wide entry in the last slot would leave its second slot dangling
+        if entry.is_wide() && index + 1 == pool_count {
+            return fail(input, ErrorKind::BadConstantPoolIndex(index));
+        }
+        if let Err(kind) = constant_pool.push(entry) {
+            return fail(input, kind);
+        }
+        input = rest;
+    }
     Ok((input, constant_pool))
 }
 
-fn constant_type(input: &[u8]) -> IResult<&[u8], ConstantPoolType> {
+fn constant_type(input: &[u8]) -> PResult<'_, ConstantPoolType<'_>> {
     let (input, tag) = constant_tag(input)?;
     Ok(match tag {
         ConstantPoolTag::Class => {
@@ -118,7 +276,7 @@ fn constant_type(input: &[u8]) -> IResult<&[u8], ConstantPoolType> {
             (
                 input,
                 ConstantPoolType::Utf8 {
-                    bytes: bytes.to_vec(),
+                    bytes: Cow::Borrowed(bytes),
                 },
             )
         }
@@ -137,6 +295,17 @@ fn constant_type(input: &[u8]) -> IResult<&[u8], ConstantPoolType> {
             let (input, descriptor_index) = be_u16(input)?;
             (input, ConstantPoolType::MethodType { descriptor_index })
         }
+        ConstantPoolTag::Dynamic => {
+            let (input, bootstrap_method_attr_index) = be_u16(input)?;
+            let (input, name_and_type_index) = be_u16(input)?;
+            (
+                input,
+                ConstantPoolType::Dynamic {
+                    bootstrap_method_attr_index,
+                    name_and_type_index,
+                },
+            )
+        }
         ConstantPoolTag::InvokeDynamic => {
             let (input, bootstrap_method_attr_index) = be_u16(input)?;
             let (input, name_and_type_index) = be_u16(input)?;
@@ -148,77 +317,163 @@ fn constant_type(input: &[u8]) -> IResult<&[u8], ConstantPoolType> {
                 },
             )
         }
+        ConstantPoolTag::Module => {
+            let (input, name_index) = be_u16(input)?;
+            (input, ConstantPoolType::Module { name_index })
+        }
+        ConstantPoolTag::Package => {
+            let (input, name_index) = be_u16(input)?;
+            (input, ConstantPoolType::Package { name_index })
+        }
     })
 }
 
-fn constant_tag(input: &[u8]) -> IResult<&[u8], ConstantPoolTag> {
-    let (input, byte) = be_u8(input)?;
-    Ok((input, ConstantPoolTag::from(byte)))
+fn constant_tag(input: &[u8]) -> PResult<'_, ConstantPoolTag> {
+    let (rest, byte) = be_u8(input)?;
+    match ConstantPoolTag::try_from(byte) {
+        Ok(tag) => Ok((rest, tag)),
+        Err(kind) => fail(input, kind),
+    }
 }
 
-fn ref_info(input: &[u8]) -> IResult<&[u8], (u16, u16)> {
+fn ref_info(input: &[u8]) -> PResult<'_, (u16, u16)> {
     let (input, class_index) = be_u16(input)?;
     let (input, name_and_type_index) = be_u16(input)?;
     Ok((input, (class_index, name_and_type_index)))
 }
 
-fn take_n<const N: usize>(input: &[u8]) -> IResult<&[u8], [u8; N]> {
+fn take_n<const N: usize>(input: &[u8]) -> PResult<'_, [u8; N]> {
     let (input, bytes) = take(N)(input)?;
-    Ok((input, bytes.try_into().expect("nom error")))
+    let mut array = [0; N];
+    array.copy_from_slice(bytes);
+    Ok((input, array))
 }
 
-fn interfaces(input: &[u8]) -> IResult<&[u8], Vec<u16>> {
+fn interfaces(input: &[u8]) -> PResult<'_, Vec<u16>> {
     let (input, interface_count) = be_u16(input)?;
     let (input, interfaces) = count(be_u16, interface_count as usize)(input)?;
     Ok((input, interfaces))
 }
 
-fn fields<'a>(
-    input: &'a [u8],
-    constant_pool: &[ConstantPoolType],
-) -> IResult<&'a [u8], Vec<FieldInfo>> {
+fn fields<'a>(input: &'a [u8], ctx: &Context<'a>) -> PResult<'a, Vec<FieldInfo<'a>>> {
     let (input, fields_count) = be_u16(input)?;
-    let (input, fields) = count(|i| field_info(i, constant_pool), fields_count as usize)(input)?;
+    let (input, fields) =
+        ctx.indexed("field", |i| field_info(i, ctx), fields_count as usize)(input)?;
     Ok((input, fields))
 }
 
-fn field_info<'a>(
-    input: &'a [u8],
-    constant_pool: &[ConstantPoolType],
-) -> IResult<&'a [u8], FieldInfo> {
+fn field_info<'a>(input: &'a [u8], ctx: &Context<'a>) -> PResult<'a, FieldInfo<'a>> {
     let (input, access_flags) = be_u16(input)?;
     let (input, name_index) = be_u16(input)?;
     let (input, descriptor_index) = be_u16(input)?;
-    let (input, attributes) = attributes(input, constant_pool)?;
+    let (input, (attributes, attribute_name_indices)) = attributes(input, ctx)?;
     Ok((
         input,
         FieldInfo {
-            access_flags,
+            access_flags: FieldAccess(access_flags),
+            name_index,
+            descriptor_index,
+            attributes,
+            attribute_name_indices,
+        },
+    ))
+}
+
+fn methods<'a>(input: &'a [u8], ctx: &Context<'a>) -> PResult<'a, Vec<MethodInfo<'a>>> {
+    let (input, methods_count) = be_u16(input)?;
+    let (input, methods) =
+        ctx.indexed("method", |i| method_info(i, ctx), methods_count as usize)(input)?;
+    Ok((input, methods))
+}
+
+fn method_info<'a>(input: &'a [u8], ctx: &Context<'a>) -> PResult<'a, MethodInfo<'a>> {
+    let (input, access_flags) = be_u16(input)?;
+    let (input, name_index) = be_u16(input)?;
+    let (input, descriptor_index) = be_u16(input)?;
+    let (input, (attributes, attribute_name_indices)) = attributes(input, ctx)?;
+    Ok((
+        input,
+        MethodInfo {
+            access_flags: MethodAccess(access_flags),
             name_index,
             descriptor_index,
             attributes,
+            attribute_name_indices,
         },
     ))
 }
 
+/// Parses an attribute table into the attributes and the
+/// `attribute_name_index` of each.
 fn attributes<'a>(
     input: &'a [u8],
-    constant_pool: &[ConstantPoolType],
-) -> IResult<&'a [u8], Vec<Attribute>> {
+    ctx: &Context<'a>,
+) -> PResult<'a, (Vec<Attribute<'a>>, Vec<U2>)> {
     let (input, attributes_count) = be_u16(input)?;
-    let (input, attributes) =
-        count(|i| attribute(i, constant_pool), attributes_count as usize)(input)?;
-    Ok((input, attributes))
+    let (input, attributes) = count(|i| attribute(i, ctx), attributes_count as usize)(input)?;
+    let (name_indices, attributes) = attributes.into_iter().unzip();
+    Ok((input, (attributes, name_indices)))
 }
 
-fn attribute<'a>(
-    input: &'a [u8],
-    constant_pool: &[ConstantPoolType],
-) -> IResult<&'a [u8], Attribute> {
+fn attribute<'a>(input: &'a [u8], ctx: &Context<'a>) -> PResult<'a, (U2, Attribute<'a>)> {
+    let start = input;
     let (input, attr_name_index) = be_u16(input)?;
     let (input, attribute_length) = be_u32(input)?;
-    let name_bytes = get_utf8(constant_pool, attr_name_index as usize);
-    Ok(match AttributeTag::from(name_bytes) {
+    let name = match ctx.constant_pool.get_utf8(attr_name_index) {
+        Ok(name) => name,
+        Err(kind) => return fail(start, kind),
+    };
+    let (input, body) = take(attribute_length)(input)?;
+    let (_, attribute) = ctx.within(
+        || mutf8::decode_lossy(name).into_owned(),
+        || bounded_attribute(body, attr_name_index, AttributeTag::from(name), ctx),
+    )?;
+    Ok((input, (attr_name_index, attribute)))
+}
+
+/// Parses an attribute from `body`, which holds exactly `attribute_length`
+/// bytes, checking that the attribute consumes all of them.
+fn bounded_attribute<'a>(
+    body: &'a [u8],
+    attr_name_index: u16,
+    tag: AttributeTag,
+    ctx: &Context<'a>,
+) -> PResult<'a, Attribute<'a>> {
+    let declared = body.len() as u32;
+    match attribute_body(body, attr_name_index, tag, declared, ctx) {
+        Ok((rest, attribute)) => {
+            if !rest.is_empty() {
+                let consumed = declared - rest.len() as u32;
+                ctx.check(rest, ErrorKind::AttributeUnderread { declared, consumed })?;
+            }
+            Ok((rest, attribute))
+        }
+        // running out of input within the sub-slice means the attribute
+        // needs more bytes than it declared
+        Err(nom::Err::Error(mut e) | nom::Err::Failure(mut e))
+            if e.kind == ErrorKind::Truncated =>
+        {
+            e.kind = ErrorKind::AttributeOverread { declared };
+            ctx.tolerate(e)?;
+            let attribute = Attribute::Unknown {
+                name_index: attr_name_index,
+                info: Cow::Borrowed(body),
+            };
+            Ok((&body[body.len()..], attribute))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn attribute_body<'a>(
+    input: &'a [u8],
+    attr_name_index: u16,
+    tag: AttributeTag,
+    attribute_length: u32,
+    ctx: &Context<'a>,
+) -> PResult<'a, Attribute<'a>> {
+    let constant_pool = &ctx.constant_pool;
+    Ok(match tag {
         AttributeTag::ConstantValue => {
             let (input, constant_value_index) = be_u16(input)?;
             (
@@ -234,15 +489,16 @@ fn attribute<'a>(
             let (input, code_len) = be_u32(input)?;
             let (input, code) = take(code_len as usize)(input)?;
             let (input, exception_table) = exception_table(input)?;
-            let (input, attributes) = attributes(input, constant_pool)?;
+            let (input, (attributes, attribute_name_indices)) = attributes(input, ctx)?;
             (
                 input,
                 Attribute::Code {
                     max_stack,
                     max_locals,
-                    code: code.to_vec(),
+                    code: Cow::Borrowed(code),
                     exception_table,
                     attributes,
+                    attribute_name_indices,
                 },
             )
         }
@@ -291,7 +547,7 @@ fn attribute<'a>(
             (
                 input,
                 Attribute::SourceDebugExtension {
-                    debug_extension: debug_extension.try_into().expect("nom error"),
+                    debug_extension: Cow::Borrowed(debug_extension),
                 },
             )
         }
@@ -326,24 +582,54 @@ fn attribute<'a>(
         AttributeTag::Deprecated => (input, Attribute::Deprecated),
         AttributeTag::RuntimeVisibleAnnotations => {
             let (input, num_annotations) = be_u16(input)?;
-            let (input, annotations) =
-                count(|i| annotation(i, constant_pool), num_annotations as usize)(input)?;
+            let (input, annotations) = indexed(
+                "annotation",
+                |i| annotation(i, constant_pool),
+                num_annotations as usize,
+            )(input)?;
             (input, Attribute::RuntimeVisibleAnnotations { annotations })
         }
         AttributeTag::RuntimeInvisibleAnnotations => {
             let (input, num_annotations) = be_u16(input)?;
-            let (input, annotations) =
-                count(|i| annotation(i, constant_pool), num_annotations as usize)(input)?;
+            let (input, annotations) = indexed(
+                "annotation",
+                |i| annotation(i, constant_pool),
+                num_annotations as usize,
+            )(input)?;
             (
                 input,
                 Attribute::RuntimeInvisibleAnnotations { annotations },
             )
         }
         AttributeTag::RuntimeVisibleParameterAnnotations => {
-            todo!()
+            let (input, num_parameters) = be_u8(input)?;
+            let (input, parameter_annotations) = indexed(
+                "parameter",
+                |i| parameter_annotations(i, constant_pool),
+                num_parameters as usize,
+            )(input)?;
+            (
+                input,
+                Attribute::RuntimeVisibleParameterAnnotations {
+                    num_parameters,
+                    parameter_annotations,
+                },
+            )
         }
         AttributeTag::RuntimeInvisibleParameterAnnotations => {
-            todo!()
+            let (input, num_parameters) = be_u8(input)?;
+            let (input, parameter_annotations) = indexed(
+                "parameter",
+                |i| parameter_annotations(i, constant_pool),
+                num_parameters as usize,
+            )(input)?;
+            (
+                input,
+                Attribute::RuntimeInvisibleParameterAnnotations {
+                    num_parameters,
+                    parameter_annotations,
+                },
+            )
         }
         AttributeTag::AnnotationDefault => {
             let (input, default_value) = element_value(input, constant_pool)?;
@@ -355,17 +641,142 @@ fn attribute<'a>(
                 count(bootstrap_method, num_bootstrap_methods as usize)(input)?;
             (input, Attribute::BootstrapMethods { bootstrap_methods })
         }
-        _ => unreachable!(),
+        AttributeTag::Module => {
+            let (input, module_name_index) = be_u16(input)?;
+            let (input, module_flags) = be_u16(input)?;
+            let (input, module_version_index) = be_u16(input)?;
+            let (input, requires_count) = be_u16(input)?;
+            let (input, requires) = count(module_requires, requires_count as usize)(input)?;
+            let (input, exports_count) = be_u16(input)?;
+            let (input, exports) = count(module_exports, exports_count as usize)(input)?;
+            let (input, opens_count) = be_u16(input)?;
+            let (input, opens) = count(module_opens, opens_count as usize)(input)?;
+            let (input, uses_count) = be_u16(input)?;
+            let (input, uses_index) = count(be_u16, uses_count as usize)(input)?;
+            let (input, provides_count) = be_u16(input)?;
+            let (input, provides) = count(module_provides, provides_count as usize)(input)?;
+            (
+                input,
+                Attribute::Module {
+                    module_name_index,
+                    module_flags: ModuleFlags(module_flags),
+                    module_version_index,
+                    requires,
+                    exports,
+                    opens,
+                    uses_index,
+                    provides,
+                },
+            )
+        }
+        AttributeTag::ModulePackages => {
+            let (input, package_count) = be_u16(input)?;
+            let (input, package_index) = count(be_u16, package_count as usize)(input)?;
+            (input, Attribute::ModulePackages { package_index })
+        }
+        AttributeTag::ModuleMainClass => {
+            let (input, main_class_index) = be_u16(input)?;
+            (input, Attribute::ModuleMainClass { main_class_index })
+        }
+        AttributeTag::NestHost => {
+            let (input, host_class_index) = be_u16(input)?;
+            (input, Attribute::NestHost { host_class_index })
+        }
+        AttributeTag::NestMembers => {
+            let (input, number_of_classes) = be_u16(input)?;
+            let (input, classes) = count(be_u16, number_of_classes as usize)(input)?;
+            (input, Attribute::NestMembers { classes })
+        }
+        AttributeTag::Record => {
+            let (input, components_count) = be_u16(input)?;
+            let (input, components) = ctx.indexed(
+                "component",
+                |i| record_component(i, ctx),
+                components_count as usize,
+            )(input)?;
+            (input, Attribute::Record { components })
+        }
+        AttributeTag::PermittedSubclasses => {
+            let (input, number_of_classes) = be_u16(input)?;
+            let (input, classes) = count(be_u16, number_of_classes as usize)(input)?;
+            (input, Attribute::PermittedSubclasses { classes })
+        }
+        AttributeTag::MethodParameters => {
+            let (input, parameters_count) = be_u8(input)?;
+            let (input, parameters) = count(method_parameter, parameters_count as usize)(input)?;
+            (input, Attribute::MethodParameters { parameters })
+        }
+        AttributeTag::RuntimeVisibleTypeAnnotations => {
+            let (input, num_annotations) = be_u16(input)?;
+            let (input, annotations) = indexed(
+                "annotation",
+                |i| type_annotation(i, constant_pool),
+                num_annotations as usize,
+            )(input)?;
+            (
+                input,
+                Attribute::RuntimeVisibleTypeAnnotations { annotations },
+            )
+        }
+        AttributeTag::RuntimeInvisibleTypeAnnotations => {
+            let (input, num_annotations) = be_u16(input)?;
+            let (input, annotations) = indexed(
+                "annotation",
+                |i| type_annotation(i, constant_pool),
+                num_annotations as usize,
+            )(input)?;
+            (
+                input,
+                Attribute::RuntimeInvisibleTypeAnnotations { annotations },
+            )
+        }
+        AttributeTag::Unknown => {
+            let (input, info) = take(attribute_length)(input)?;
+            (
+                input,
+                Attribute::Unknown {
+                    name_index: attr_name_index,
+                    info: Cow::Borrowed(info),
+                },
+            )
+        }
     })
 }
 
-fn exception_table(input: &[u8]) -> IResult<&[u8], Vec<ExceptionHandler>> {
+fn record_component<'a>(input: &'a [u8], ctx: &Context<'a>) -> PResult<'a, RecordComponent<'a>> {
+    let (input, name_index) = be_u16(input)?;
+    let (input, descriptor_index) = be_u16(input)?;
+    let (input, (attributes, attribute_name_indices)) = attributes(input, ctx)?;
+    Ok((
+        input,
+        RecordComponent {
+            name_index,
+            descriptor_index,
+            attributes,
+            attribute_name_indices,
+        },
+    ))
+}
+
+fn method_parameter(input: &[u8]) -> PResult<'_, MethodParameter> {
+    let (input, name_index) = be_u16(input)?;
+    let (input, access_flags) = be_u16(input)?;
+    Ok((
+        input,
+        MethodParameter {
+            name_index,
+            access_flags: ParameterAccess(access_flags),
+        },
+    ))
+}
+
+fn exception_table(input: &[u8]) -> PResult<'_, Vec<ExceptionHandler>> {
     let (input, table_len) = be_u16(input)?;
     let (input, table) = count(exception_handler, table_len as usize)(input)?;
     Ok((input, table))
 }
 
-fn exception_handler(input: &[u8]) -> IResult<&[u8], ExceptionHandler> {
+fn exception_handler(input: &[u8]) -> PResult<'_, ExceptionHandler> {
     let (input, start_pc) = be_u16(input)?;
     let (input, end_pc) = be_u16(input)?;
     let (input, handler_pc) = be_u16(input)?;
@@ -381,13 +792,13 @@ fn exception_handler(input: &[u8]) -> IResult<&[u8], ExceptionHandler> {
     ))
 }
 
-fn stack_map_table(input: &[u8]) -> IResult<&[u8], Vec<StackMapFrame>> {
+fn stack_map_table(input: &[u8]) -> PResult<'_, Vec<StackMapFrame>> {
     let (input, entries_count) = be_u16(input)?;
-    let (input, entries) = count(stack_map_frame, entries_count as usize)(input)?;
+    let (input, entries) = indexed("frame", stack_map_frame, entries_count as usize)(input)?;
     Ok((input, entries))
 }
 
-fn stack_map_frame(input: &[u8]) -> IResult<&[u8], StackMapFrame> {
+fn stack_map_frame(input: &[u8]) -> PResult<'_, StackMapFrame> {
     let (input, frame_type) = be_u8(input)?;
     Ok(match frame_type {
         // same
@@ -482,31 +893,31 @@ fn stack_map_frame(input: &[u8]) -> IResult<&[u8], StackMapFrame> {
     })
 }
 
-fn verification_type_info(input: &[u8]) -> IResult<&[u8], VerificationTypeInfo> {
+fn verification_type_info(input: &[u8]) -> PResult<'_, VerificationTypeInfo> {
     use VerificationTypeInfo::*;
 
-    let (input, tag) = be_u8(input)?;
+    let (rest, tag) = be_u8(input)?;
     Ok(match tag {
-        0 => (input, TopVariable),
-        1 => (input, IntegerVariable),
-        2 => (input, FloatVariable),
-        4 => (input, LongVariable),
-        3 => (input, DoubleVariable),
-        5 => (input, NullVariable),
-        6 => (input, UninitializedThisVariable),
+        0 => (rest, TopVariable),
+        1 => (rest, IntegerVariable),
+        2 => (rest, FloatVariable),
+        4 => (rest, LongVariable),
+        3 => (rest, DoubleVariable),
+        5 => (rest, NullVariable),
+        6 => (rest, UninitializedThisVariable),
         7 => {
-            let (input, cpool_index) = be_u16(input)?;
-            (input, ObjectVariable { cpool_index })
+            let (rest, cpool_index) = be_u16(rest)?;
+            (rest, ObjectVariable { cpool_index })
         }
         8 => {
-            let (input, offset) = be_u16(input)?;
-            (input, UninitializedVariable { offset })
+            let (rest, offset) = be_u16(rest)?;
+            (rest, UninitializedVariable { offset })
         }
-        _ => unreachable!(),
+        _ => return fail(input, ErrorKind::UnknownVerificationType(tag)),
     })
 }
 
-fn inner_class(input: &[u8]) -> IResult<&[u8], InnerClass> {
+fn inner_class(input: &[u8]) -> PResult<'_, InnerClass> {
     let (input, inner_class_info) = be_u16(input)?;
     let (input, outer_class_info) = be_u16(input)?;
     let (input, inner_name_index) = be_u16(input)?;
@@ -517,12 +928,12 @@ fn inner_class(input: &[u8]) -> IResult<&[u8], InnerClass> {
             inner_class_info,
             outer_class_info,
             inner_name_index,
-            inner_class_access_flags,
+            inner_class_access_flags: InnerClassAccess(inner_class_access_flags),
         },
     ))
 }
 
-fn line_number(input: &[u8]) -> IResult<&[u8], LineNumber> {
+fn line_number(input: &[u8]) -> PResult<'_, LineNumber> {
     let (input, start_pc) = be_u16(input)?;
     let (input, line_number) = be_u16(input)?;
     Ok((
@@ -534,7 +945,7 @@ fn line_number(input: &[u8]) -> IResult<&[u8], LineNumber> {
     ))
 }
 
-fn local_variable(input: &[u8]) -> IResult<&[u8], LocalVariable> {
+fn local_variable(input: &[u8]) -> PResult<'_, LocalVariable> {
     let (input, start_pc) = be_u16(input)?;
     let (input, length) = be_u16(input)?;
     let (input, name_index) = be_u16(input)?;
@@ -552,30 +963,209 @@ fn local_variable(input: &[u8]) -> IResult<&[u8], LocalVariable> {
     ))
 }
 
-fn annotation<'a>(
+fn parameter_annotations<'a>(
     input: &'a [u8],
-    constant_pool: &[ConstantPoolType],
-) -> IResult<&'a [u8], Annotation> {
+    constant_pool: &ConstantPool,
+) -> PResult<'a, Vec<Annotation>> {
+    let (input, num_annotations) = be_u16(input)?;
+    indexed(
+        "annotation",
+        |i| annotation(i, constant_pool),
+        num_annotations as usize,
+    )(input)
+}
+
+fn annotation<'a>(input: &'a [u8], constant_pool: &ConstantPool) -> PResult<'a, Annotation> {
+    let start = input;
     let (input, type_index) = be_u16(input)?;
     let (input, num_element_value_pairs) = be_u16(input)?;
     let (input, element_value_pairs) = count(
         |i| element_value_pair(i, constant_pool),
         num_element_value_pairs as usize,
     )(input)?;
-    let type_name = get_utf8(constant_pool, type_index as usize);
+    if let Err(kind) = constant_pool.get_utf8(type_index) {
+        return fail(start, kind);
+    }
     Ok((
         input,
         Annotation {
-            type_name: type_name.to_vec(),
+            type_index,
             element_value_pairs,
         },
     ))
 }
 
+fn type_annotation<'a>(
+    input: &'a [u8],
+    constant_pool: &ConstantPool,
+) -> PResult<'a, TypeAnnotation> {
+    let (input, target) = type_annotation_target(input)?;
+    let (input, target_path) = type_path(input)?;
+    let (input, annotation) = annotation(input, constant_pool)?;
+    Ok((
+        input,
+        TypeAnnotation {
+            target,
+            target_path,
+            annotation,
+        },
+    ))
+}
+
+fn type_annotation_target(input: &[u8]) -> PResult<'_, TypeAnnotationTarget> {
+    use TypeAnnotationTarget::*;
+
+    let (rest, target_type) = be_u8(input)?;
+    Ok(match target_type {
+        0x00 | 0x01 => {
+            let (rest, type_parameter_index) = be_u8(rest)?;
+            let target = if target_type == 0x00 {
+                ClassTypeParameter {
+                    type_parameter_index,
+                }
+            } else {
+                MethodTypeParameter {
+                    type_parameter_index,
+                }
+            };
+            (rest, target)
+        }
+        0x10 => {
+            let (rest, supertype_index) = be_u16(rest)?;
+            (rest, Supertype { supertype_index })
+        }
+        0x11 | 0x12 => {
+            let (rest, type_parameter_index) = be_u8(rest)?;
+            let (rest, bound_index) = be_u8(rest)?;
+            let target = if target_type == 0x11 {
+                ClassTypeParameterBound {
+                    type_parameter_index,
+                    bound_index,
+                }
+            } else {
+                MethodTypeParameterBound {
+                    type_parameter_index,
+                    bound_index,
+                }
+            };
+            (rest, target)
+        }
+        0x13 => (rest, Field),
+        0x14 => (rest, Return),
+        0x15 => (rest, Receiver),
+        0x16 => {
+            let (rest, formal_parameter_index) = be_u8(rest)?;
+            (
+                rest,
+                FormalParameter {
+                    formal_parameter_index,
+                },
+            )
+        }
+        0x17 => {
+            let (rest, throws_type_index) = be_u16(rest)?;
+            (rest, Throws { throws_type_index })
+        }
+        0x40 | 0x41 => {
+            let (rest, table_length) = be_u16(rest)?;
+            let (rest, table) = count(local_variable_target, table_length as usize)(rest)?;
+            let target = if target_type == 0x40 {
+                LocalVariable { table }
+            } else {
+                ResourceVariable { table }
+            };
+            (rest, target)
+        }
+        0x42 => {
+            let (rest, exception_table_index) = be_u16(rest)?;
+            (
+                rest,
+                ExceptionParameter {
+                    exception_table_index,
+                },
+            )
+        }
+        0x43..=0x46 => {
+            let (rest, offset) = be_u16(rest)?;
+            let target = match target_type {
+                0x43 => InstanceOf { offset },
+                0x44 => New { offset },
+                0x45 => ConstructorReference { offset },
+                _ => MethodReference { offset },
+            };
+            (rest, target)
+        }
+        0x47..=0x4B => {
+            let (rest, offset) = be_u16(rest)?;
+            let (rest, type_argument_index) = be_u8(rest)?;
+            let target = match target_type {
+                0x47 => Cast {
+                    offset,
+                    type_argument_index,
+                },
+                0x48 => ConstructorInvocationTypeArgument {
+                    offset,
+                    type_argument_index,
+                },
+                0x49 => MethodInvocationTypeArgument {
+                    offset,
+                    type_argument_index,
+                },
+                0x4A => ConstructorReferenceTypeArgument {
+                    offset,
+                    type_argument_index,
+                },
+                _ => MethodReferenceTypeArgument {
+                    offset,
+                    type_argument_index,
+                },
+            };
+            (rest, target)
+        }
+        _ => return fail(input, ErrorKind::UnknownTypeAnnotationTarget(target_type)),
+    })
+}
+
+fn local_variable_target(input: &[u8]) -> PResult<'_, LocalVariableTarget> {
+    let (input, start_pc) = be_u16(input)?;
+    let (input, length) = be_u16(input)?;
+    let (input, index) = be_u16(input)?;
+    Ok((
+        input,
+        LocalVariableTarget {
+            start_pc,
+            length,
+            index,
+        },
+    ))
+}
+
+fn type_path(input: &[u8]) -> PResult<'_, TypePath> {
+    let (input, path_length) = be_u8(input)?;
+    let (input, path) = count(type_path_entry, path_length as usize)(input)?;
+    Ok((input, TypePath { path }))
+}
+
+fn type_path_entry(input: &[u8]) -> PResult<'_, TypePathEntry> {
+    let (rest, kind) = be_u8(input)?;
+    let kind = match TypePathKind::try_from(kind) {
+        Ok(kind) => kind,
+        Err(kind) => return fail(input, kind),
+    };
+    let (rest, type_argument_index) = be_u8(rest)?;
+    Ok((
+        rest,
+        TypePathEntry {
+            kind,
+            type_argument_index,
+        },
+    ))
+}
+
 fn element_value_pair<'a>(
     input: &'a [u8],
-    constant_pool: &[ConstantPoolType],
-) -> IResult<&'a [u8], ElementValuePair> {
+    constant_pool: &ConstantPool,
+) -> PResult<'a, ElementValuePair> {
     let (input, index) = be_u16(input)?;
     let (input, value) = element_value(input, constant_pool)?;
     Ok((
@@ -587,10 +1177,7 @@ fn element_value_pair<'a>(
     ))
 }
 
-fn element_value<'a>(
-    input: &'a [u8],
-    constant_pool: &[ConstantPoolType],
-) -> IResult<&'a [u8], ElementValue> {
+fn element_value<'a>(input: &'a [u8], constant_pool: &ConstantPool) -> PResult<'a, ElementValue> {
     let (input, tag) = element_value_tag(input)?;
     Ok(match tag {
         ElementValueTag::Byte => {
@@ -654,18 +1241,94 @@ fn element_value<'a>(
                 count(|i| element_value(i, constant_pool), num_values as usize)(input)?;
             (input, ElementValue::Array { values })
         }
-        _ => unreachable!(),
     })
 }
 
-fn element_value_tag(input: &[u8]) -> IResult<&[u8], ElementValueTag> {
-    let (input, tag) = be_u8(input)?;
-    Ok((input, ElementValueTag::from(tag)))
+fn element_value_tag(input: &[u8]) -> PResult<'_, ElementValueTag> {
+    let (rest, tag) = be_u8(input)?;
+    match ElementValueTag::try_from(tag) {
+        Ok(tag) => Ok((rest, tag)),
+        Err(kind) => fail(input, kind),
+    }
 }
 
-fn bootstrap_method(input: &[u8]) -> IResult<&[u8], BootstrapMethod> {
+fn bootstrap_method(input: &[u8]) -> PResult<'_, BootstrapMethod> {
     let (input, method_ref) = be_u16(input)?;
     let (input, num_args) = be_u16(input)?;
     let (input, args) = count(be_u16, num_args as usize)(input)?;
     Ok((input, BootstrapMethod { method_ref, args }))
 }
+
+fn module_requires(input: &[u8]) -> PResult<'_, ModuleRequires> {
+    let (input, requires_index) = be_u16(input)?;
+    let (input, requires_flags) = be_u16(input)?;
+    let (input, requires_version_index) = be_u16(input)?;
+    Ok((
+        input,
+        ModuleRequires {
+            requires_index,
+            requires_flags: RequiresFlags(requires_flags),
+            requires_version_index,
+        },
+    ))
+}
+
+fn module_exports(input: &[u8]) -> PResult<'_, ModuleExports> {
+    let (input, exports_index) = be_u16(input)?;
+    let (input, exports_flags) = be_u16(input)?;
+    let (input, exports_to_count) = be_u16(input)?;
+    let (input, exports_to_index) = count(be_u16, exports_to_count as usize)(input)?;
+    Ok((
+        input,
+        ModuleExports {
+            exports_index,
+            exports_flags: ExportsFlags(exports_flags),
+            exports_to_index,
+        },
+    ))
+}
+
+fn module_opens(input: &[u8]) -> PResult<'_, ModuleOpens> {
+    let (input, opens_index) = be_u16(input)?;
+    let (input, opens_flags) = be_u16(input)?;
+    let (input, opens_to_count) = be_u16(input)?;
+    let (input, opens_to_index) = count(be_u16, opens_to_count as usize)(input)?;
+    Ok((
+        input,
+        ModuleOpens {
+            opens_index,
+            opens_flags: ExportsFlags(opens_flags),
+            opens_to_index,
+        },
+    ))
+}
+
+fn module_provides(input: &[u8]) -> PResult<'_, ModuleProvides> {
+    let (input, provides_index) = be_u16(input)?;
+    let (input, provides_with_count) = be_u16(input)?;
+    let (input, provides_with_index) = count(be_u16, provides_with_count as usize)(input)?;
+    Ok((
+        input,
+        ModuleProvides {
+            provides_index,
+            provides_with_index,
+        },
+    ))
+}
+
+/// Like `count`, but records the index of a failing element in the error path.
+fn indexed<'a, O>(
+    label: &'static str,
+    mut f: impl FnMut(&'a [u8]) -> PResult<'a, O>,
+    n: usize,
+) -> impl FnMut(&'a [u8]) -> PResult<'a, Vec<O>> {
+    move |mut input| {
+        let mut items = Vec::with_capacity(n.min(256));
+        for i in 0..n {
+            let (rest, item) = context(f(input), || format!("{} {}", label, i))?;
+            items.push(item);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
@@ -0,0 +1,763 @@
+use std::fmt;
+
+use crate::{constant_pool::ConstantPool, descriptor::BaseType, error::ErrorKind, Attribute};
+
+/// The signature of a generic class or interface, e.g.
+/// `<T:Ljava/lang/Object;>Ljava/util/AbstractList<TT;>;Ljava/io/Serializable;`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassSignature {
+    pub type_params: Vec<TypeParameter>,
+    pub superclass: ClassTypeSignature,
+    pub interfaces: Vec<ClassTypeSignature>,
+}
+
+impl ClassSignature {
+    /// Parses a class signature, failing with
+    /// [`ErrorKind::InvalidSignature`] at the first byte that cannot belong
+    /// to one.
+    pub fn parse(signature: &str) -> Result<Self, ErrorKind> {
+        let mut parser = Parser::new(signature);
+        let type_params = parser.type_params()?;
+        let superclass = parser.class_type()?;
+        let mut interfaces = Vec::new();
+        while parser.peek().is_some() {
+            interfaces.push(parser.class_type()?);
+        }
+        Ok(Self {
+            type_params,
+            superclass,
+            interfaces,
+        })
+    }
+}
+
+impl fmt::Display for ClassSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type_params(f, &self.type_params)?;
+        write!(f, "{}", self.superclass)?;
+        for interface in &self.interfaces {
+            write!(f, "{}", interface)?;
+        }
+        Ok(())
+    }
+}
+
+/// The signature of a generic method, e.g.
+/// `<X:Ljava/lang/Exception;>(Ljava/util/List<+TT;>;)V^TX;`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MethodSignature {
+    pub type_params: Vec<TypeParameter>,
+    pub params: Vec<JavaTypeSignature>,
+    /// The return type, or `None` for `void`.
+    pub ret: Option<JavaTypeSignature>,
+    /// Class types and type variables, never arrays.
+    pub throws: Vec<ReferenceTypeSignature>,
+}
+
+impl MethodSignature {
+    /// Parses a method signature, failing with
+    /// [`ErrorKind::InvalidSignature`] at the first byte that cannot belong
+    /// to one.
+    pub fn parse(signature: &str) -> Result<Self, ErrorKind> {
+        let mut parser = Parser::new(signature);
+        let type_params = parser.type_params()?;
+        parser.expect(b'(')?;
+        let mut params = Vec::new();
+        while parser.peek() != Some(b')') {
+            params.push(parser.java_type()?);
+        }
+        parser.pos += 1;
+        let ret = match parser.peek() {
+            Some(b'V') => {
+                parser.pos += 1;
+                None
+            }
+            _ => Some(parser.java_type()?),
+        };
+        let mut throws = Vec::new();
+        while parser.peek().is_some() {
+            parser.expect(b'^')?;
+            throws.push(match parser.peek() {
+                Some(b'T') => parser.reference_type()?,
+                _ => ReferenceTypeSignature::Class(parser.class_type()?),
+            });
+        }
+        Ok(Self {
+            type_params,
+            params,
+            ret,
+            throws,
+        })
+    }
+}
+
+impl fmt::Display for MethodSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_type_params(f, &self.type_params)?;
+        f.write_str("(")?;
+        for param in &self.params {
+            write!(f, "{}", param)?;
+        }
+        f.write_str(")")?;
+        match &self.ret {
+            Some(ret) => write!(f, "{}", ret)?,
+            None => f.write_str("V")?,
+        }
+        for throws in &self.throws {
+            write!(f, "^{}", throws)?;
+        }
+        Ok(())
+    }
+}
+
+/// A type parameter declaration, e.g. `T:Ljava/lang/Object;`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeParameter {
+    pub name: String,
+    /// The bound after the first colon, absent when the parameter is bounded
+    /// by interfaces only.
+    pub class_bound: Option<ReferenceTypeSignature>,
+    pub interface_bounds: Vec<ReferenceTypeSignature>,
+}
+
+impl TypeParameter {
+    /// Renders the declaration in Java syntax, e.g.
+    /// `T extends java.lang.Number & java.lang.Comparable<T>`.
+    pub fn to_java(&self) -> String {
+        let mut rendered = self.name.clone();
+        let mut separator = " extends ";
+        for bound in self.class_bound.iter().chain(&self.interface_bounds) {
+            rendered.push_str(separator);
+            rendered.push_str(&bound.to_java());
+            separator = " & ";
+        }
+        rendered
+    }
+}
+
+impl fmt::Display for TypeParameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:", self.name)?;
+        if let Some(bound) = &self.class_bound {
+            write!(f, "{}", bound)?;
+        }
+        for bound in &self.interface_bounds {
+            write!(f, ":{}", bound)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JavaTypeSignature {
+    Base(BaseType),
+    Reference(ReferenceTypeSignature),
+}
+
+impl JavaTypeSignature {
+    /// Renders the type in Java syntax, e.g. `java.util.List<? extends T>[]`.
+    pub fn to_java(&self) -> String {
+        match self {
+            Self::Base(base) => base.java_name().to_string(),
+            Self::Reference(reference) => reference.to_java(),
+        }
+    }
+}
+
+impl fmt::Display for JavaTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Base(base) => write!(f, "{}", base.descriptor()),
+            Self::Reference(reference) => write!(f, "{}", reference),
+        }
+    }
+}
+
+/// The type of a field signature, type argument or bound.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferenceTypeSignature {
+    Class(ClassTypeSignature),
+    TypeVariable(String),
+    Array(Box<JavaTypeSignature>),
+}
+
+impl ReferenceTypeSignature {
+    /// Parses a field signature.
+    pub fn parse(signature: &str) -> Result<Self, ErrorKind> {
+        let mut parser = Parser::new(signature);
+        let ty = parser.reference_type()?;
+        parser.end()?;
+        Ok(ty)
+    }
+
+    /// Renders the type in Java syntax, e.g. `java.util.List<? extends T>`.
+    pub fn to_java(&self) -> String {
+        match self {
+            Self::Class(class) => class.to_java(),
+            Self::TypeVariable(name) => name.clone(),
+            Self::Array(element) => format!("{}[]", element.to_java()),
+        }
+    }
+}
+
+impl fmt::Display for ReferenceTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Class(class) => write!(f, "{}", class),
+            Self::TypeVariable(name) => write!(f, "T{};", name),
+            Self::Array(element) => write!(f, "[{}", element),
+        }
+    }
+}
+
+/// A possibly parameterized class type, e.g.
+/// `Ljava/util/Map<TK;TV;>.Entry<TK;TV;>;`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassTypeSignature {
+    /// The internal name of the outermost class, e.g. `java/util/Map`.
+    pub name: String,
+    pub type_args: Vec<TypeArgument>,
+    /// The member classes selected from it, outermost first, e.g. `Entry`.
+    pub nested: Vec<SimpleClassTypeSignature>,
+}
+
+impl ClassTypeSignature {
+    /// Renders the type in Java syntax, e.g.
+    /// `java.util.Map<K, V>.Entry<K, V>`.
+    pub fn to_java(&self) -> String {
+        let mut rendered = self.name.replace('/', ".");
+        push_type_args(&mut rendered, &self.type_args);
+        for nested in &self.nested {
+            rendered.push('.');
+            rendered.push_str(&nested.name);
+            push_type_args(&mut rendered, &nested.type_args);
+        }
+        rendered
+    }
+}
+
+impl fmt::Display for ClassTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "L{}", self.name)?;
+        write_type_args(f, &self.type_args)?;
+        for nested in &self.nested {
+            write!(f, ".{}", nested.name)?;
+            write_type_args(f, &nested.type_args)?;
+        }
+        f.write_str(";")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SimpleClassTypeSignature {
+    pub name: String,
+    pub type_args: Vec<TypeArgument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TypeArgument {
+    /// `?`
+    Wildcard,
+    /// `? extends` the type.
+    Extends(ReferenceTypeSignature),
+    /// `? super` the type.
+    Super(ReferenceTypeSignature),
+    Exact(ReferenceTypeSignature),
+}
+
+impl TypeArgument {
+    pub fn to_java(&self) -> String {
+        match self {
+            Self::Wildcard => "?".to_string(),
+            Self::Extends(bound) => format!("? extends {}", bound.to_java()),
+            Self::Super(bound) => format!("? super {}", bound.to_java()),
+            Self::Exact(ty) => ty.to_java(),
+        }
+    }
+}
+
+impl fmt::Display for TypeArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Wildcard => f.write_str("*"),
+            Self::Extends(bound) => write!(f, "+{}", bound),
+            Self::Super(bound) => write!(f, "-{}", bound),
+            Self::Exact(ty) => write!(f, "{}", ty),
+        }
+    }
+}
+
+/// Parses the `Signature` attribute among `attributes` with `parse`, if
+/// there is one.
+pub(crate) fn find<T>(
+    attributes: &[Attribute],
+    constant_pool: &ConstantPool,
+    parse: impl FnOnce(&str) -> Result<T, ErrorKind>,
+) -> Result<Option<T>, ErrorKind> {
+    for attribute in attributes {
+        if let Attribute::Signature {
+            signature_index: index,
+        } = *attribute
+        {
+            return parse(&constant_pool.get_str(index)?)
+                .map(Some)
+                .map_err(|kind| kind.in_entry(index));
+        }
+    }
+    Ok(None)
+}
+
+fn push_type_args(rendered: &mut String, type_args: &[TypeArgument]) {
+    if !type_args.is_empty() {
+        let type_args = type_args
+            .iter()
+            .map(TypeArgument::to_java)
+            .collect::<Vec<_>>();
+        rendered.push_str(&format!("<{}>", type_args.join(", ")));
+    }
+}
+
+fn write_type_args(f: &mut fmt::Formatter, type_args: &[TypeArgument]) -> fmt::Result {
+    if !type_args.is_empty() {
+        f.write_str("<")?;
+        for type_arg in type_args {
+            write!(f, "{}", type_arg)?;
+        }
+        f.write_str(">")?;
+    }
+    Ok(())
+}
+
+fn write_type_params(f: &mut fmt::Formatter, type_params: &[TypeParameter]) -> fmt::Result {
+    if !type_params.is_empty() {
+        f.write_str("<")?;
+        for type_param in type_params {
+            write!(f, "{}", type_param)?;
+        }
+        f.write_str(">")?;
+    }
+    Ok(())
+}
+
+/// How deeply array element types and type arguments may nest, so that
+/// parsing and rendering a hostile signature cannot exhaust the stack.
+const MAX_DEPTH: usize = 512;
+
+struct Parser<'s> {
+    signature: &'s str,
+    pos: usize,
+    /// The number of enclosing array dimensions and type argument lists.
+    depth: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn new(signature: &'s str) -> Self {
+        Self {
+            signature,
+            pos: 0,
+            depth: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.signature.as_bytes().get(self.pos).copied()
+    }
+
+    fn error(&self) -> ErrorKind {
+        ErrorKind::InvalidSignature {
+            index: None,
+            offset: self.pos,
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), ErrorKind> {
+        if self.peek() != Some(c) {
+            return Err(self.error());
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn end(&self) -> Result<(), ErrorKind> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.error()),
+        }
+    }
+
+    /// Runs `parse` one level deeper, failing once the signature nests more
+    /// than [`MAX_DEPTH`] levels.
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, ErrorKind>,
+    ) -> Result<T, ErrorKind> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error());
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Consumes a non-empty identifier, which runs up to the next character
+    /// with a meaning in signatures.
+    fn identifier(&mut self) -> Result<&str, ErrorKind> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if matches!(c, b'.' | b';' | b'[' | b'/' | b'<' | b'>' | b':') {
+                break;
+            }
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.error());
+        }
+        Ok(&self.signature[start..self.pos])
+    }
+
+    fn type_params(&mut self) -> Result<Vec<TypeParameter>, ErrorKind> {
+        let mut type_params = Vec::new();
+        if self.peek() != Some(b'<') {
+            return Ok(type_params);
+        }
+        self.pos += 1;
+        loop {
+            let name = self.identifier()?.to_string();
+            self.expect(b':')?;
+            let class_bound = match self.peek() {
+                Some(b':') => None,
+                _ => Some(self.reference_type()?),
+            };
+            let mut interface_bounds = Vec::new();
+            while self.peek() == Some(b':') {
+                self.pos += 1;
+                interface_bounds.push(self.reference_type()?);
+            }
+            type_params.push(TypeParameter {
+                name,
+                class_bound,
+                interface_bounds,
+            });
+            if self.peek() == Some(b'>') {
+                self.pos += 1;
+                return Ok(type_params);
+            }
+        }
+    }
+
+    fn java_type(&mut self) -> Result<JavaTypeSignature, ErrorKind> {
+        match self.peek().and_then(BaseType::from_descriptor) {
+            Some(base) => {
+                self.pos += 1;
+                Ok(JavaTypeSignature::Base(base))
+            }
+            None => Ok(JavaTypeSignature::Reference(self.reference_type()?)),
+        }
+    }
+
+    fn reference_type(&mut self) -> Result<ReferenceTypeSignature, ErrorKind> {
+        match self.peek() {
+            Some(b'L') => Ok(ReferenceTypeSignature::Class(self.class_type()?)),
+            Some(b'T') => {
+                self.pos += 1;
+                let name = self.identifier()?.to_string();
+                self.expect(b';')?;
+                Ok(ReferenceTypeSignature::TypeVariable(name))
+            }
+            Some(b'[') => {
+                // Like descriptors, array types have at most 255 dimensions.
+                let dimensions = self.signature.as_bytes()[self.pos..]
+                    .iter()
+                    .take_while(|&&c| c == b'[')
+                    .count();
+                if dimensions > usize::from(u8::MAX) {
+                    return Err(ErrorKind::InvalidSignature {
+                        index: None,
+                        offset: self.pos + usize::from(u8::MAX),
+                    });
+                }
+                self.nested(|parser| {
+                    parser.pos += 1;
+                    Ok(ReferenceTypeSignature::Array(Box::new(parser.java_type()?)))
+                })
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn class_type(&mut self) -> Result<ClassTypeSignature, ErrorKind> {
+        self.expect(b'L')?;
+        let start = self.pos;
+        self.identifier()?;
+        while self.peek() == Some(b'/') {
+            self.pos += 1;
+            self.identifier()?;
+        }
+        let name = self.signature[start..self.pos].to_string();
+        let type_args = self.type_args()?;
+        let mut nested = Vec::new();
+        while self.peek() == Some(b'.') {
+            self.pos += 1;
+            let name = self.identifier()?.to_string();
+            let type_args = self.type_args()?;
+            nested.push(SimpleClassTypeSignature { name, type_args });
+        }
+        self.expect(b';')?;
+        Ok(ClassTypeSignature {
+            name,
+            type_args,
+            nested,
+        })
+    }
+
+    fn type_args(&mut self) -> Result<Vec<TypeArgument>, ErrorKind> {
+        if self.peek() != Some(b'<') {
+            return Ok(Vec::new());
+        }
+        self.nested(Self::type_arg_list)
+    }
+
+    /// Consumes type arguments from the opening `<` to the closing `>`.
+    fn type_arg_list(&mut self) -> Result<Vec<TypeArgument>, ErrorKind> {
+        self.pos += 1;
+        let mut type_args = Vec::new();
+        loop {
+            type_args.push(match self.peek() {
+                Some(b'*') => {
+                    self.pos += 1;
+                    TypeArgument::Wildcard
+                }
+                Some(b'+') => {
+                    self.pos += 1;
+                    TypeArgument::Extends(self.reference_type()?)
+                }
+                Some(b'-') => {
+                    self.pos += 1;
+                    TypeArgument::Super(self.reference_type()?)
+                }
+                _ => TypeArgument::Exact(self.reference_type()?),
+            });
+            if self.peek() == Some(b'>') {
+                self.pos += 1;
+                return Ok(type_args);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn malformed(offset: usize) -> ErrorKind {
+        ErrorKind::InvalidSignature {
+            index: None,
+            offset,
+        }
+    }
+
+    fn class_type(name: &str, type_args: Vec<TypeArgument>) -> ReferenceTypeSignature {
+        ReferenceTypeSignature::Class(ClassTypeSignature {
+            name: name.to_string(),
+            type_args,
+            nested: Vec::new(),
+        })
+    }
+
+    fn variable(name: &str) -> ReferenceTypeSignature {
+        ReferenceTypeSignature::TypeVariable(name.to_string())
+    }
+
+    #[test]
+    fn inner_classes_keep_their_own_type_arguments() {
+        let signature = "Ljava/util/Map<TK;TV;>.Entry<TK;TV;>;";
+        let parsed = ReferenceTypeSignature::parse(signature).unwrap();
+        let args = || {
+            vec![
+                TypeArgument::Exact(variable("K")),
+                TypeArgument::Exact(variable("V")),
+            ]
+        };
+        assert_eq!(
+            parsed,
+            ReferenceTypeSignature::Class(ClassTypeSignature {
+                name: "java/util/Map".to_string(),
+                type_args: args(),
+                nested: vec![SimpleClassTypeSignature {
+                    name: "Entry".to_string(),
+                    type_args: args(),
+                }],
+            })
+        );
+        assert_eq!(parsed.to_string(), signature);
+        assert_eq!(parsed.to_java(), "java.util.Map<K, V>.Entry<K, V>");
+    }
+
+    #[test]
+    fn type_parameters_may_have_interface_bounds_only() {
+        let signature =
+            "<T::Ljava/lang/Comparable<TT;>;U:Ljava/lang/Number;:Ljava/io/Serializable;>\
+                         Ljava/lang/Object;";
+        let parsed = ClassSignature::parse(signature).unwrap();
+        assert_eq!(
+            parsed.type_params,
+            [
+                TypeParameter {
+                    name: "T".to_string(),
+                    class_bound: None,
+                    interface_bounds: vec![class_type(
+                        "java/lang/Comparable",
+                        vec![TypeArgument::Exact(variable("T"))]
+                    )],
+                },
+                TypeParameter {
+                    name: "U".to_string(),
+                    class_bound: Some(class_type("java/lang/Number", Vec::new())),
+                    interface_bounds: vec![class_type("java/io/Serializable", Vec::new())],
+                },
+            ]
+        );
+        assert_eq!(parsed.to_string(), signature);
+        assert_eq!(
+            parsed.type_params[0].to_java(),
+            "T extends java.lang.Comparable<T>"
+        );
+        assert_eq!(
+            parsed.type_params[1].to_java(),
+            "U extends java.lang.Number & java.io.Serializable"
+        );
+    }
+
+    #[test]
+    fn wildcards_keep_their_bounds() {
+        let signature = "Ljava/util/Map<*+Ljava/lang/Number;-[TT;>;";
+        let parsed = ReferenceTypeSignature::parse(signature).unwrap();
+        assert_eq!(
+            parsed,
+            class_type(
+                "java/util/Map",
+                vec![
+                    TypeArgument::Wildcard,
+                    TypeArgument::Extends(class_type("java/lang/Number", Vec::new())),
+                    TypeArgument::Super(ReferenceTypeSignature::Array(Box::new(
+                        JavaTypeSignature::Reference(variable("T"))
+                    ))),
+                ]
+            )
+        );
+        assert_eq!(parsed.to_string(), signature);
+        assert_eq!(
+            parsed.to_java(),
+            "java.util.Map<?, ? extends java.lang.Number, ? super T[]>"
+        );
+    }
+
+    #[test]
+    fn methods_may_throw_type_variables() {
+        let signature =
+            "<X:Ljava/lang/Exception;>(Ljava/util/List<+TT;>;[J)V^TX;^Ljava/io/IOException;";
+        let parsed = MethodSignature::parse(signature).unwrap();
+        assert_eq!(
+            parsed.throws,
+            [variable("X"), class_type("java/io/IOException", Vec::new())]
+        );
+        assert_eq!(parsed.ret, None);
+        assert_eq!(parsed.params.len(), 2);
+        assert_eq!(parsed.to_string(), signature);
+        assert_eq!(parsed.params[0].to_java(), "java.util.List<? extends T>");
+        assert_eq!(parsed.params[1].to_java(), "long[]");
+    }
+
+    #[test]
+    fn signatures_display_as_written() {
+        for signature in [
+            "<E:Ljava/lang/Object;>Ljava/util/AbstractList<TE;>;Ljava/util/List<TE;>;",
+            "Ljava/lang/Enum<LColor;>;",
+        ] {
+            assert_eq!(
+                ClassSignature::parse(signature).unwrap().to_string(),
+                signature
+            );
+        }
+        for signature in [
+            "()V",
+            "<T:Ljava/lang/Object;>([TT;)[TT;",
+            "(ILjava/util/function/Function<-TT;+TR;>;)Ljava/util/Optional<TR;>;",
+        ] {
+            assert_eq!(
+                MethodSignature::parse(signature).unwrap().to_string(),
+                signature
+            );
+        }
+    }
+
+    #[test]
+    fn errors_point_at_the_first_bad_byte() {
+        let reference = ReferenceTypeSignature::parse;
+        assert_eq!(reference("Ljava/util/List<>;"), Err(malformed(16)));
+        assert_eq!(reference("Ljava/util/List<TT;>"), Err(malformed(20)));
+        assert_eq!(reference("Ljava//Object;"), Err(malformed(6)));
+        assert_eq!(reference("Ljava/util/List;X"), Err(malformed(16)));
+        assert_eq!(reference("TT"), Err(malformed(2)));
+        assert_eq!(reference("I"), Err(malformed(0)));
+        assert_eq!(MethodSignature::parse("(TT;"), Err(malformed(4)));
+        assert_eq!(MethodSignature::parse("()V^[I"), Err(malformed(4)));
+        assert_eq!(MethodSignature::parse("()VI"), Err(malformed(3)));
+        assert_eq!(
+            ClassSignature::parse("<T>Ljava/lang/Object;"),
+            Err(malformed(2))
+        );
+        assert_eq!(ClassSignature::parse(""), Err(malformed(0)));
+    }
+
+    #[test]
+    fn arrays_have_at_most_255_dimensions() {
+        let signature = "[".repeat(255) + "TT;";
+        assert_eq!(
+            ReferenceTypeSignature::parse(&signature)
+                .unwrap()
+                .to_string(),
+            signature
+        );
+        let signature = "[".repeat(256) + "TT;";
+        assert_eq!(
+            ReferenceTypeSignature::parse(&signature),
+            Err(malformed(255))
+        );
+        let signature = "[".repeat(1 << 20) + "I";
+        assert_eq!(
+            ReferenceTypeSignature::parse(&signature),
+            Err(malformed(255))
+        );
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested = |depth| "Ljava/util/List<".repeat(depth) + "TT;" + &">;".repeat(depth);
+        let signature = nested(MAX_DEPTH);
+        assert_eq!(
+            ReferenceTypeSignature::parse(&signature)
+                .unwrap()
+                .to_string(),
+            signature
+        );
+        // The error points at the `<` that opens one level too many.
+        let signature = nested(MAX_DEPTH + 1);
+        assert_eq!(
+            ReferenceTypeSignature::parse(&signature),
+            Err(malformed((MAX_DEPTH + 1) * "Ljava/util/List<".len() - 1))
+        );
+        // Arrays of generic types count both.
+        let signature = "[Ljava/util/List<".repeat(MAX_DEPTH / 2) + "[I";
+        assert_eq!(
+            ReferenceTypeSignature::parse(&signature),
+            Err(malformed(MAX_DEPTH / 2 * "[Ljava/util/List<".len()))
+        );
+        let signature = "Ljava/util/List<".repeat(1 << 16);
+        assert_eq!(
+            ReferenceTypeSignature::parse(&signature),
+            Err(malformed((MAX_DEPTH + 1) * "Ljava/util/List<".len() - 1))
+        );
+    }
+}
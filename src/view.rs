@@ -0,0 +1,366 @@
+use std::borrow::Cow;
+
+use crate::{
+    access::{ClassAccess, FieldAccess, MethodAccess},
+    bytecode::{instructions, Instructions},
+    constant_pool::ConstantPool,
+    descriptor::{FieldType, MethodDescriptor},
+    error::ErrorKind,
+    signature::{ClassSignature, MethodSignature, ReferenceTypeSignature},
+    Annotation, Attribute, ClassFile, Constant, ElementValue, ExceptionHandler, FieldInfo,
+    LineNumber, MethodInfo, Version, U1, U2,
+};
+
+/// A class file read by name: every accessor resolves its indices through
+/// the constant pool.
+#[derive(Debug, Clone, Copy)]
+pub struct ClassView<'a> {
+    class_file: &'a ClassFile<'a>,
+}
+
+impl<'a> ClassView<'a> {
+    pub fn new(class_file: &'a ClassFile<'a>) -> Self {
+        Self { class_file }
+    }
+
+    pub fn class_file(self) -> &'a ClassFile<'a> {
+        self.class_file
+    }
+
+    pub fn version(self) -> &'a Version {
+        &self.class_file.version
+    }
+
+    pub fn access_flags(self) -> ClassAccess {
+        self.class_file.access_flags
+    }
+
+    /// The internal name of the class, e.g. `java/lang/String`.
+    pub fn name(self) -> Result<Cow<'a, str>, ErrorKind> {
+        self.constant_pool()
+            .get_class_name(self.class_file.this_class)
+    }
+
+    /// The internal name of the superclass, or `None` for `java/lang/Object`
+    /// and modules.
+    pub fn super_name(self) -> Result<Option<Cow<'a, str>>, ErrorKind> {
+        match self.class_file.super_class {
+            0 => Ok(None),
+            index => self.constant_pool().get_class_name(index).map(Some),
+        }
+    }
+
+    pub fn interface_names(self) -> Result<Vec<Cow<'a, str>>, ErrorKind> {
+        self.class_file
+            .interfaces
+            .iter()
+            .map(|&index| self.constant_pool().get_class_name(index))
+            .collect()
+    }
+
+    /// The name of the source file from the `SourceFile` attribute, without
+    /// any directory.
+    pub fn source_file(self) -> Result<Option<Cow<'a, str>>, ErrorKind> {
+        for attribute in &self.class_file.attributes {
+            if let Attribute::SourceFile { source_file_index } = attribute {
+                return self.constant_pool().get_str(*source_file_index).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn signature(self) -> Result<Option<ClassSignature>, ErrorKind> {
+        self.class_file.signature()
+    }
+
+    /// The visible and invisible annotations on the class.
+    pub fn annotations(self) -> Vec<AnnotationView<'a>> {
+        annotations(&self.class_file.attributes, self.constant_pool())
+    }
+
+    pub fn fields(self) -> impl Iterator<Item = FieldView<'a>> {
+        let constant_pool = self.constant_pool();
+        self.class_file.fields.iter().map(move |field| FieldView {
+            field,
+            constant_pool,
+        })
+    }
+
+    pub fn methods(self) -> impl Iterator<Item = MethodView<'a>> {
+        let constant_pool = self.constant_pool();
+        self.class_file
+            .methods
+            .iter()
+            .map(move |method| MethodView {
+                method,
+                constant_pool,
+            })
+    }
+
+    /// Finds the first field named `name`. A class file may declare several
+    /// fields of the same name with different descriptors.
+    pub fn field(self, name: &str) -> Result<Option<FieldView<'a>>, ErrorKind> {
+        for field in self.fields() {
+            if field.name()? == name {
+                return Ok(Some(field));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Finds the method with the given name and descriptor, e.g. `main` and
+    /// `([Ljava/lang/String;)V`.
+    pub fn method(self, name: &str, descriptor: &str) -> Result<Option<MethodView<'a>>, ErrorKind> {
+        for method in self.methods() {
+            if method.name()? == name && method.descriptor_str()? == descriptor {
+                return Ok(Some(method));
+            }
+        }
+        Ok(None)
+    }
+
+    fn constant_pool(self) -> &'a ConstantPool<'a> {
+        &self.class_file.constant_pool
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FieldView<'a> {
+    field: &'a FieldInfo<'a>,
+    constant_pool: &'a ConstantPool<'a>,
+}
+
+impl<'a> FieldView<'a> {
+    pub fn field_info(self) -> &'a FieldInfo<'a> {
+        self.field
+    }
+
+    pub fn access_flags(self) -> FieldAccess {
+        self.field.access_flags
+    }
+
+    pub fn name(self) -> Result<Cow<'a, str>, ErrorKind> {
+        self.constant_pool.get_str(self.field.name_index)
+    }
+
+    pub fn descriptor(self) -> Result<FieldType, ErrorKind> {
+        self.field.descriptor(self.constant_pool)
+    }
+
+    /// The descriptor as written, e.g. `[Ljava/lang/String;`.
+    pub fn descriptor_str(self) -> Result<Cow<'a, str>, ErrorKind> {
+        self.constant_pool.get_str(self.field.descriptor_index)
+    }
+
+    pub fn signature(self) -> Result<Option<ReferenceTypeSignature>, ErrorKind> {
+        self.field.signature(self.constant_pool)
+    }
+
+    pub fn constant_value(self) -> Result<Option<Constant<'a>>, ErrorKind> {
+        self.field.constant_value(self.constant_pool)
+    }
+
+    /// The visible and invisible annotations on the field.
+    pub fn annotations(self) -> Vec<AnnotationView<'a>> {
+        annotations(&self.field.attributes, self.constant_pool)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MethodView<'a> {
+    method: &'a MethodInfo<'a>,
+    constant_pool: &'a ConstantPool<'a>,
+}
+
+impl<'a> MethodView<'a> {
+    pub fn method_info(self) -> &'a MethodInfo<'a> {
+        self.method
+    }
+
+    pub fn access_flags(self) -> MethodAccess {
+        self.method.access_flags
+    }
+
+    pub fn name(self) -> Result<Cow<'a, str>, ErrorKind> {
+        self.constant_pool.get_str(self.method.name_index)
+    }
+
+    pub fn descriptor(self) -> Result<MethodDescriptor, ErrorKind> {
+        self.method.descriptor(self.constant_pool)
+    }
+
+    /// The descriptor as written, e.g. `([Ljava/lang/String;)V`.
+    pub fn descriptor_str(self) -> Result<Cow<'a, str>, ErrorKind> {
+        self.constant_pool.get_str(self.method.descriptor_index)
+    }
+
+    pub fn signature(self) -> Result<Option<MethodSignature>, ErrorKind> {
+        self.method.signature(self.constant_pool)
+    }
+
+    /// The internal names of the checked exceptions in the `Exceptions`
+    /// attribute.
+    pub fn exceptions(self) -> Result<Vec<Cow<'a, str>>, ErrorKind> {
+        for attribute in &self.method.attributes {
+            if let Attribute::Exceptions {
+                exception_index_table,
+                ..
+            } = attribute
+            {
+                return exception_index_table
+                    .iter()
+                    .map(|&index| self.constant_pool.get_class_name(index))
+                    .collect();
+            }
+        }
+        Ok(Vec::new())
+    }
+
+    pub fn parameter_names(self) -> Result<Option<Vec<Option<Cow<'a, str>>>>, ErrorKind> {
+        self.method.parameter_names(self.constant_pool)
+    }
+
+    /// The visible and invisible annotations on the method, not on its
+    /// parameters.
+    pub fn annotations(self) -> Vec<AnnotationView<'a>> {
+        annotations(&self.method.attributes, self.constant_pool)
+    }
+
+    /// The method body, or `None` for abstract and native methods.
+    pub fn code(self) -> Option<CodeView<'a>> {
+        self.method
+            .attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Code {
+                    max_stack,
+                    max_locals,
+                    code,
+                    exception_table,
+                    attributes,
+                    ..
+                } => Some(CodeView {
+                    max_stack: *max_stack,
+                    max_locals: *max_locals,
+                    code,
+                    exception_table,
+                    attributes,
+                    constant_pool: self.constant_pool,
+                }),
+                _ => None,
+            })
+    }
+}
+
+/// The `Code` attribute of a method.
+#[derive(Debug, Clone, Copy)]
+pub struct CodeView<'a> {
+    pub max_stack: U2,
+    pub max_locals: U2,
+    pub code: &'a [U1],
+    pub exception_table: &'a [ExceptionHandler],
+    pub attributes: &'a [Attribute<'a>],
+    constant_pool: &'a ConstantPool<'a>,
+}
+
+impl<'a> CodeView<'a> {
+    pub fn instructions(self) -> Instructions<'a> {
+        instructions(self.code)
+    }
+
+    /// The exception table, with each catch type resolved to an internal
+    /// class name, or `None` for a handler that catches everything.
+    pub fn handlers(self) -> Result<Vec<Handler<'a>>, ErrorKind> {
+        self.exception_table
+            .iter()
+            .map(|handler| {
+                Ok(Handler {
+                    start_pc: handler.start_pc,
+                    end_pc: handler.end_pc,
+                    handler_pc: handler.handler_pc,
+                    catch_type: match handler.catch_type {
+                        0 => None,
+                        index => Some(self.constant_pool.get_class_name(index)?),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// The entries of every `LineNumberTable` attribute, in order.
+    pub fn line_numbers(self) -> impl Iterator<Item = &'a LineNumber> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::LineNumberTable { line_number_table } => Some(line_number_table),
+                _ => None,
+            })
+            .flatten()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Handler<'a> {
+    pub start_pc: U2,
+    pub end_pc: U2,
+    pub handler_pc: U2,
+    pub catch_type: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AnnotationView<'a> {
+    annotation: &'a Annotation,
+    visible: bool,
+    constant_pool: &'a ConstantPool<'a>,
+}
+
+impl<'a> AnnotationView<'a> {
+    pub fn annotation(self) -> &'a Annotation {
+        self.annotation
+    }
+
+    /// Whether the annotation is retained at run time, i.e. came from a
+    /// `RuntimeVisibleAnnotations` attribute.
+    pub fn is_visible(self) -> bool {
+        self.visible
+    }
+
+    /// The annotation interface, e.g. `Ljava/lang/Deprecated;`.
+    pub fn descriptor(self) -> Result<FieldType, ErrorKind> {
+        self.constant_pool
+            .get_field_type(self.annotation.type_index)
+    }
+
+    /// The element values by element name, in declaration order.
+    pub fn elements(self) -> Result<Vec<(Cow<'a, str>, &'a ElementValue)>, ErrorKind> {
+        self.annotation
+            .element_value_pairs
+            .iter()
+            .map(|pair| {
+                let name = self.constant_pool.get_str(pair.element_name_index)?;
+                Ok((name, &pair.value))
+            })
+            .collect()
+    }
+}
+
+fn annotations<'a>(
+    attributes: &'a [Attribute<'a>],
+    constant_pool: &'a ConstantPool<'a>,
+) -> Vec<AnnotationView<'a>> {
+    attributes
+        .iter()
+        .filter_map(|attribute| match attribute {
+            Attribute::RuntimeVisibleAnnotations { annotations } => Some((annotations, true)),
+            Attribute::RuntimeInvisibleAnnotations { annotations } => Some((annotations, false)),
+            _ => None,
+        })
+        .flat_map(|(annotations, visible)| {
+            annotations.iter().map(move |annotation| AnnotationView {
+                annotation,
+                visible,
+                constant_pool,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,692 @@
+use std::collections::HashMap;
+
+use crate::{
+    constant_pool::{ConstantPool, ConstantPoolType},
+    error::ErrorKind,
+    Annotation, Attribute, BootstrapMethod, ClassFile, ElementValue, ExceptionHandler, FieldInfo,
+    InnerClass, LineNumber, LocalVariable, MethodInfo, RecordComponent, StackMapFrame,
+    TypeAnnotation, TypeAnnotationTarget, TypePath, VerificationTypeInfo, U1, U2, U4,
+};
+
+const MAGIC: u32 = 0xCAFEBABE;
+
+pub(crate) fn write(class_file: &ClassFile) -> Result<Vec<u8>, ErrorKind> {
+    let mut writer = Writer {
+        constant_pool: &class_file.constant_pool,
+        attribute_names: HashMap::new(),
+        out: Vec::new(),
+    };
+    writer.class_file(class_file)?;
+    Ok(writer.out)
+}
+
+struct Writer<'c> {
+    constant_pool: &'c ConstantPool<'c>,
+    /// The constant pool index of each attribute name written so far.
+    attribute_names: HashMap<&'static str, U2>,
+    out: Vec<u8>,
+}
+
+impl Writer<'_> {
+    fn class_file(&mut self, class_file: &ClassFile) -> Result<(), ErrorKind> {
+        self.u4(MAGIC);
+        self.u2(class_file.version.minor);
+        self.u2(class_file.version.major);
+        self.constant_pool()?;
+        self.u2(class_file.access_flags.0);
+        self.u2(class_file.this_class);
+        self.u2(class_file.super_class);
+        self.u2s(&class_file.interfaces)?;
+        self.count(class_file.fields.len())?;
+        for field in &class_file.fields {
+            self.field_info(field)?;
+        }
+        self.count(class_file.methods.len())?;
+        for method in &class_file.methods {
+            self.method_info(method)?;
+        }
+        self.attributes(&class_file.attributes, &class_file.attribute_name_indices)
+    }
+
+    fn constant_pool(&mut self) -> Result<(), ErrorKind> {
+        let constant_pool = self.constant_pool;
+        self.count(constant_pool.slots())?;
+        for (_, entry) in constant_pool.iter() {
+            self.u1(entry.tag().into());
+            match entry {
+                ConstantPoolType::Class { name_index }
+                | ConstantPoolType::Module { name_index }
+                | ConstantPoolType::Package { name_index } => self.u2(*name_index),
+                ConstantPoolType::Fieldref {
+                    class_index,
+                    name_and_type_index,
+                }
+                | ConstantPoolType::Methodref {
+                    class_index,
+                    name_and_type_index,
+                }
+                | ConstantPoolType::InterfaceMethodref {
+                    class_index,
+                    name_and_type_index,
+                } => {
+                    self.u2(*class_index);
+                    self.u2(*name_and_type_index);
+                }
+                ConstantPoolType::String { string_index } => self.u2(*string_index),
+                ConstantPoolType::Integer { bytes } | ConstantPoolType::Float { bytes } => {
+                    self.out.extend_from_slice(bytes)
+                }
+                ConstantPoolType::Long { val } | ConstantPoolType::Double { val } => {
+                    self.out.extend_from_slice(val)
+                }
+                ConstantPoolType::NameAndType {
+                    name_index,
+                    descriptor_index,
+                } => {
+                    self.u2(*name_index);
+                    self.u2(*descriptor_index);
+                }
+                ConstantPoolType::Utf8 { bytes } => {
+                    self.count(bytes.len())?;
+                    self.out.extend_from_slice(bytes);
+                }
+                ConstantPoolType::MethodHandle {
+                    reference_kind,
+                    reference_index,
+                } => {
+                    self.u1(*reference_kind);
+                    self.u2(*reference_index);
+                }
+                ConstantPoolType::MethodType { descriptor_index } => self.u2(*descriptor_index),
+                ConstantPoolType::Dynamic {
+                    bootstrap_method_attr_index,
+                    name_and_type_index,
+                }
+                | ConstantPoolType::InvokeDynamic {
+                    bootstrap_method_attr_index,
+                    name_and_type_index,
+                } => {
+                    self.u2(*bootstrap_method_attr_index);
+                    self.u2(*name_and_type_index);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn field_info(&mut self, field: &FieldInfo) -> Result<(), ErrorKind> {
+        self.u2(field.access_flags.0);
+        self.u2(field.name_index);
+        self.u2(field.descriptor_index);
+        self.attributes(&field.attributes, &field.attribute_name_indices)
+    }
+
+    fn method_info(&mut self, method: &MethodInfo) -> Result<(), ErrorKind> {
+        self.u2(method.access_flags.0);
+        self.u2(method.name_index);
+        self.u2(method.descriptor_index);
+        self.attributes(&method.attributes, &method.attribute_name_indices)
+    }
+
+    fn attributes(
+        &mut self,
+        attributes: &[Attribute],
+        name_indices: &[U2],
+    ) -> Result<(), ErrorKind> {
+        self.count(attributes.len())?;
+        for (i, attribute) in attributes.iter().enumerate() {
+            self.attribute(attribute, name_indices.get(i).copied())?;
+        }
+        Ok(())
+    }
+
+    /// Writes an attribute under `name_index`, its index when parsed, if
+    /// that still holds the attribute's name.
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        name_index: Option<U2>,
+    ) -> Result<(), ErrorKind> {
+        let name_index = match (attribute, attribute.tag().name()) {
+            (Attribute::Unknown { name_index, .. }, _) => *name_index,
+            (_, Some(name)) => match name_index {
+                Some(index) if self.constant_pool.get_utf8(index) == Ok(name.as_bytes()) => index,
+                _ => self.attribute_name(name)?,
+            },
+            (_, None) => unreachable!("only unknown attributes have no name"),
+        };
+        self.u2(name_index);
+        // patched once the body has been written
+        let length_at = self.out.len();
+        self.u4(0);
+        self.attribute_body(attribute)?;
+        let length = U4::try_from(self.out.len() - length_at - 4)
+            .map_err(|_| ErrorKind::TooManyItems(self.out.len() - length_at - 4))?;
+        self.out[length_at..length_at + 4].copy_from_slice(&length.to_be_bytes());
+        Ok(())
+    }
+
+    fn attribute_body(&mut self, attribute: &Attribute) -> Result<(), ErrorKind> {
+        match attribute {
+            Attribute::ConstantValue {
+                constant_value_index,
+            } => self.u2(*constant_value_index),
+            Attribute::Code {
+                max_stack,
+                max_locals,
+                code,
+                exception_table,
+                attributes,
+                attribute_name_indices,
+            } => {
+                self.u2(*max_stack);
+                self.u2(*max_locals);
+                let code_len =
+                    U4::try_from(code.len()).map_err(|_| ErrorKind::TooManyItems(code.len()))?;
+                self.u4(code_len);
+                self.out.extend_from_slice(code);
+                self.count(exception_table.len())?;
+                for handler in exception_table {
+                    self.exception_handler(handler);
+                }
+                self.attributes(attributes, attribute_name_indices)?;
+            }
+            Attribute::StackMapTable { entries } => {
+                self.count(entries.len())?;
+                for frame in entries {
+                    self.stack_map_frame(frame)?;
+                }
+            }
+            Attribute::Exceptions {
+                exception_index_table,
+                ..
+            } => self.u2s(exception_index_table)?,
+            Attribute::InnerClasses { classes } => {
+                self.count(classes.len())?;
+                for class in classes {
+                    self.inner_class(class);
+                }
+            }
+            Attribute::EnclosingMethod {
+                class_index,
+                method_index,
+            } => {
+                self.u2(*class_index);
+                self.u2(*method_index);
+            }
+            Attribute::Synthetic | Attribute::Deprecated => {}
+            Attribute::Signature { signature_index } => self.u2(*signature_index),
+            Attribute::SourceFile { source_file_index } => self.u2(*source_file_index),
+            Attribute::SourceDebugExtension { debug_extension } => {
+                self.out.extend_from_slice(debug_extension)
+            }
+            Attribute::LineNumberTable { line_number_table } => {
+                self.count(line_number_table.len())?;
+                for line_number in line_number_table {
+                    self.line_number(line_number);
+                }
+            }
+            Attribute::LocalVariableTable {
+                local_variable_table: table,
+            }
+            | Attribute::LocalVariableTypeTable {
+                local_variable_type_table: table,
+            } => {
+                self.count(table.len())?;
+                for local_variable in table {
+                    self.local_variable(local_variable);
+                }
+            }
+            Attribute::RuntimeVisibleAnnotations { annotations }
+            | Attribute::RuntimeInvisibleAnnotations { annotations } => {
+                self.annotations(annotations)?
+            }
+            Attribute::RuntimeVisibleParameterAnnotations {
+                parameter_annotations,
+                ..
+            }
+            | Attribute::RuntimeInvisibleParameterAnnotations {
+                parameter_annotations,
+                ..
+            } => {
+                self.count_u1(parameter_annotations.len())?;
+                for annotations in parameter_annotations {
+                    self.annotations(annotations)?;
+                }
+            }
+            Attribute::AnnotationDefault { default_value } => self.element_value(default_value)?,
+            Attribute::BootstrapMethods { bootstrap_methods } => {
+                self.count(bootstrap_methods.len())?;
+                for bootstrap_method in bootstrap_methods {
+                    self.bootstrap_method(bootstrap_method)?;
+                }
+            }
+            Attribute::Module {
+                module_name_index,
+                module_flags,
+                module_version_index,
+                requires,
+                exports,
+                opens,
+                uses_index,
+                provides,
+            } => {
+                self.u2(*module_name_index);
+                self.u2(module_flags.0);
+                self.u2(*module_version_index);
+                self.count(requires.len())?;
+                for require in requires {
+                    self.u2(require.requires_index);
+                    self.u2(require.requires_flags.0);
+                    self.u2(require.requires_version_index);
+                }
+                self.count(exports.len())?;
+                for export in exports {
+                    self.u2(export.exports_index);
+                    self.u2(export.exports_flags.0);
+                    self.u2s(&export.exports_to_index)?;
+                }
+                self.count(opens.len())?;
+                for open in opens {
+                    self.u2(open.opens_index);
+                    self.u2(open.opens_flags.0);
+                    self.u2s(&open.opens_to_index)?;
+                }
+                self.u2s(uses_index)?;
+                self.count(provides.len())?;
+                for provide in provides {
+                    self.u2(provide.provides_index);
+                    self.u2s(&provide.provides_with_index)?;
+                }
+            }
+            Attribute::ModulePackages { package_index } => self.u2s(package_index)?,
+            Attribute::ModuleMainClass { main_class_index } => self.u2(*main_class_index),
+            Attribute::NestHost { host_class_index } => self.u2(*host_class_index),
+            Attribute::NestMembers { classes } | Attribute::PermittedSubclasses { classes } => {
+                self.u2s(classes)?
+            }
+            Attribute::Record { components } => {
+                self.count(components.len())?;
+                for component in components {
+                    self.record_component(component)?;
+                }
+            }
+            Attribute::MethodParameters { parameters } => {
+                self.count_u1(parameters.len())?;
+                for parameter in parameters {
+                    self.u2(parameter.name_index);
+                    self.u2(parameter.access_flags.0);
+                }
+            }
+            Attribute::RuntimeVisibleTypeAnnotations { annotations }
+            | Attribute::RuntimeInvisibleTypeAnnotations { annotations } => {
+                self.count(annotations.len())?;
+                for annotation in annotations {
+                    self.type_annotation(annotation)?;
+                }
+            }
+            Attribute::Unknown { info, .. } => self.out.extend_from_slice(info),
+        }
+        Ok(())
+    }
+
+    /// Finds the `Utf8` entry holding the name of an attribute built by
+    /// hand. Should the name appear more than once, the first entry is used.
+    fn attribute_name(&mut self, name: &'static str) -> Result<U2, ErrorKind> {
+        if let Some(&index) = self.attribute_names.get(name) {
+            return Ok(index);
+        }
+        let index = self
+            .constant_pool
+            .find_utf8(name.as_bytes())
+            .ok_or(ErrorKind::MissingAttributeName(name))?;
+        self.attribute_names.insert(name, index);
+        Ok(index)
+    }
+
+    fn exception_handler(&mut self, handler: &ExceptionHandler) {
+        self.u2(handler.start_pc);
+        self.u2(handler.end_pc);
+        self.u2(handler.handler_pc);
+        self.u2(handler.catch_type);
+    }
+
+    /// Writes a frame with its stored `tag`, which has to agree with the
+    /// rest of the frame.
+    fn stack_map_frame(&mut self, frame: &StackMapFrame) -> Result<(), ErrorKind> {
+        match frame {
+            StackMapFrame::Same { tag, .. } => self.u1(*tag),
+            StackMapFrame::SameLocals1StackItem { tag, stack, .. } => {
+                self.u1(*tag);
+                self.verification_type_info(&stack[0]);
+            }
+            StackMapFrame::Reserved(tag) => self.u1(*tag),
+            StackMapFrame::SameLocalsStackItemExtended {
+                tag,
+                offset_delta,
+                stack,
+            } => {
+                self.u1(*tag);
+                self.u2(*offset_delta);
+                self.verification_type_info(&stack[0]);
+            }
+            StackMapFrame::Chop { tag, offset_delta }
+            | StackMapFrame::SameExtended { tag, offset_delta } => {
+                self.u1(*tag);
+                self.u2(*offset_delta);
+            }
+            StackMapFrame::Append {
+                tag,
+                offset_delta,
+                locals,
+            } => {
+                self.u1(*tag);
+                self.u2(*offset_delta);
+                for local in locals {
+                    self.verification_type_info(local);
+                }
+            }
+            StackMapFrame::Full {
+                tag,
+                offset_delta,
+                locals,
+                stack,
+            } => {
+                self.u1(*tag);
+                self.u2(*offset_delta);
+                self.count(locals.len())?;
+                for local in locals {
+                    self.verification_type_info(local);
+                }
+                self.count(stack.len())?;
+                for item in stack {
+                    self.verification_type_info(item);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn verification_type_info(&mut self, info: &VerificationTypeInfo) {
+        use VerificationTypeInfo::*;
+
+        match info {
+            TopVariable => self.u1(0),
+            IntegerVariable => self.u1(1),
+            FloatVariable => self.u1(2),
+            DoubleVariable => self.u1(3),
+            LongVariable => self.u1(4),
+            NullVariable => self.u1(5),
+            UninitializedThisVariable => self.u1(6),
+            ObjectVariable { cpool_index } => {
+                self.u1(7);
+                self.u2(*cpool_index);
+            }
+            UninitializedVariable { offset } => {
+                self.u1(8);
+                self.u2(*offset);
+            }
+        }
+    }
+
+    fn inner_class(&mut self, class: &InnerClass) {
+        self.u2(class.inner_class_info);
+        self.u2(class.outer_class_info);
+        self.u2(class.inner_name_index);
+        self.u2(class.inner_class_access_flags.0);
+    }
+
+    fn line_number(&mut self, line_number: &LineNumber) {
+        self.u2(line_number.start_pc);
+        self.u2(line_number.line_number);
+    }
+
+    fn local_variable(&mut self, local_variable: &LocalVariable) {
+        self.u2(local_variable.start_pc);
+        self.u2(local_variable.length);
+        self.u2(local_variable.name_index);
+        self.u2(local_variable.descriptor_index);
+        self.u2(local_variable.index);
+    }
+
+    fn record_component(&mut self, component: &RecordComponent) -> Result<(), ErrorKind> {
+        self.u2(component.name_index);
+        self.u2(component.descriptor_index);
+        self.attributes(&component.attributes, &component.attribute_name_indices)
+    }
+
+    fn bootstrap_method(&mut self, bootstrap_method: &BootstrapMethod) -> Result<(), ErrorKind> {
+        self.u2(bootstrap_method.method_ref);
+        self.u2s(&bootstrap_method.args)
+    }
+
+    fn annotations(&mut self, annotations: &[Annotation]) -> Result<(), ErrorKind> {
+        self.count(annotations.len())?;
+        for annotation in annotations {
+            self.annotation(annotation)?;
+        }
+        Ok(())
+    }
+
+    fn annotation(&mut self, annotation: &Annotation) -> Result<(), ErrorKind> {
+        self.u2(annotation.type_index);
+        self.count(annotation.element_value_pairs.len())?;
+        for pair in &annotation.element_value_pairs {
+            self.u2(pair.element_name_index);
+            self.element_value(&pair.value)?;
+        }
+        Ok(())
+    }
+
+    fn type_annotation(&mut self, annotation: &TypeAnnotation) -> Result<(), ErrorKind> {
+        self.type_annotation_target(&annotation.target)?;
+        self.type_path(&annotation.target_path)?;
+        self.annotation(&annotation.annotation)
+    }
+
+    fn type_annotation_target(&mut self, target: &TypeAnnotationTarget) -> Result<(), ErrorKind> {
+        use TypeAnnotationTarget::*;
+
+        self.u1(target.target_type());
+        match target {
+            ClassTypeParameter {
+                type_parameter_index,
+            }
+            | MethodTypeParameter {
+                type_parameter_index,
+            } => self.u1(*type_parameter_index),
+            Supertype { supertype_index } => self.u2(*supertype_index),
+            ClassTypeParameterBound {
+                type_parameter_index,
+                bound_index,
+            }
+            | MethodTypeParameterBound {
+                type_parameter_index,
+                bound_index,
+            } => {
+                self.u1(*type_parameter_index);
+                self.u1(*bound_index);
+            }
+            Field | Return | Receiver => {}
+            FormalParameter {
+                formal_parameter_index,
+            } => self.u1(*formal_parameter_index),
+            Throws { throws_type_index } => self.u2(*throws_type_index),
+            LocalVariable { table } | ResourceVariable { table } => {
+                self.count(table.len())?;
+                for range in table {
+                    self.u2(range.start_pc);
+                    self.u2(range.length);
+                    self.u2(range.index);
+                }
+            }
+            ExceptionParameter {
+                exception_table_index,
+            } => self.u2(*exception_table_index),
+            InstanceOf { offset }
+            | New { offset }
+            | ConstructorReference { offset }
+            | MethodReference { offset } => self.u2(*offset),
+            Cast {
+                offset,
+                type_argument_index,
+            }
+            | ConstructorInvocationTypeArgument {
+                offset,
+                type_argument_index,
+            }
+            | MethodInvocationTypeArgument {
+                offset,
+                type_argument_index,
+            }
+            | ConstructorReferenceTypeArgument {
+                offset,
+                type_argument_index,
+            }
+            | MethodReferenceTypeArgument {
+                offset,
+                type_argument_index,
+            } => {
+                self.u2(*offset);
+                self.u1(*type_argument_index);
+            }
+        }
+        Ok(())
+    }
+
+    fn type_path(&mut self, type_path: &TypePath) -> Result<(), ErrorKind> {
+        self.count_u1(type_path.path.len())?;
+        for entry in &type_path.path {
+            self.u1(entry.kind.into());
+            self.u1(entry.type_argument_index);
+        }
+        Ok(())
+    }
+
+    fn element_value(&mut self, value: &ElementValue) -> Result<(), ErrorKind> {
+        let (tag, index) = match value {
+            ElementValue::Byte { index } => (b'B', index),
+            ElementValue::Char { index } => (b'C', index),
+            ElementValue::Double { index } => (b'D', index),
+            ElementValue::Float { index } => (b'F', index),
+            ElementValue::Int { index } => (b'I', index),
+            ElementValue::Long { index } => (b'J', index),
+            ElementValue::Short { index } => (b'S', index),
+            ElementValue::Boolean { index } => (b'Z', index),
+            ElementValue::String { index } => (b's', index),
+            ElementValue::Class { index } => (b'c', index),
+            ElementValue::EnumConstValue {
+                type_name_index,
+                const_name_index,
+            } => {
+                self.u1(b'e');
+                self.u2(*type_name_index);
+                self.u2(*const_name_index);
+                return Ok(());
+            }
+            ElementValue::Annotation { annotation } => {
+                self.u1(b'@');
+                return self.annotation(annotation);
+            }
+            ElementValue::Array { values } => {
+                self.u1(b'[');
+                self.count(values.len())?;
+                for value in values {
+                    self.element_value(value)?;
+                }
+                return Ok(());
+            }
+        };
+        self.u1(tag);
+        self.u2(*index);
+        Ok(())
+    }
+
+    /// Writes a table of indices preceded by its length.
+    fn u2s(&mut self, values: &[U2]) -> Result<(), ErrorKind> {
+        self.count(values.len())?;
+        for &value in values {
+            self.u2(value);
+        }
+        Ok(())
+    }
+
+    /// Writes a `u2` length or count.
+    fn count(&mut self, len: usize) -> Result<(), ErrorKind> {
+        let len = U2::try_from(len).map_err(|_| ErrorKind::TooManyItems(len))?;
+        self.u2(len);
+        Ok(())
+    }
+
+    /// Writes a `u1` length or count.
+    fn count_u1(&mut self, len: usize) -> Result<(), ErrorKind> {
+        let len = U1::try_from(len).map_err(|_| ErrorKind::TooManyItems(len))?;
+        self.u1(len);
+        Ok(())
+    }
+
+    fn u1(&mut self, value: U1) {
+        self.out.push(value);
+    }
+
+    fn u2(&mut self, value: U2) {
+        self.out.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn u4(&mut self, value: U4) {
+        self.out.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{constant_pool::ConstantPoolType, parse, parse_with_mode, AttributeTag, Mode};
+
+    const ANONYMOUS: &[u8] =
+        include_bytes!("../tests/corpus/classes/release-17/java7/Basics$1.class");
+
+    /// `ANONYMOUS` with a second `SourceFile` entry at the end of the
+    /// constant pool, which the `SourceFile` attribute is named by.
+    fn duplicate_source_file_name() -> (Vec<u8>, u16) {
+        let mut class_file = parse(ANONYMOUS).unwrap();
+        let duplicate = class_file
+            .constant_pool
+            .push(ConstantPoolType::utf8("SourceFile"))
+            .unwrap();
+        assert!(class_file.constant_pool.find_utf8(b"SourceFile").unwrap() < duplicate);
+        let at = class_file
+            .attributes
+            .iter()
+            .position(|attribute| matches!(attribute.tag(), AttributeTag::SourceFile))
+            .unwrap();
+        class_file.attribute_name_indices[at] = duplicate;
+        (class_file.to_bytes().unwrap(), duplicate)
+    }
+
+    #[test]
+    fn attributes_keep_their_name_index() {
+        let (bytes, duplicate) = duplicate_source_file_name();
+        let (class_file, warnings) = parse_with_mode(&bytes, Mode::Strict).unwrap();
+        assert!(warnings.is_empty());
+        assert!(class_file.attribute_name_indices.contains(&duplicate));
+        assert_eq!(class_file.to_bytes().unwrap(), bytes);
+        assert_eq!(class_file.into_owned().to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn attributes_without_a_name_index_use_the_first_entry() {
+        let (bytes, duplicate) = duplicate_source_file_name();
+        let mut class_file = parse(&bytes).unwrap();
+        let first = class_file.constant_pool.find_utf8(b"SourceFile").unwrap();
+
+        // An index that no longer names the attribute is not reused.
+        for indices in [Vec::new(), vec![1; class_file.attributes.len()]] {
+            class_file.attribute_name_indices = indices;
+            let written = parse(&class_file.to_bytes().unwrap())
+                .unwrap()
+                .attribute_name_indices;
+            assert!(written.contains(&first));
+            assert!(!written.contains(&duplicate));
+        }
+    }
+}
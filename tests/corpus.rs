@@ -0,0 +1,107 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use class_file_parser::disassembler::disassemble;
+
+fn corpus() -> Vec<PathBuf> {
+    fn walk(dir: &Path, classes: &mut Vec<PathBuf>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                walk(&path, classes);
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "class")
+            {
+                classes.push(path);
+            }
+        }
+    }
+
+    let mut classes = Vec::new();
+    walk(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus/classes"),
+        &mut classes,
+    );
+    classes.sort();
+    assert!(!classes.is_empty(), "the corpus is empty");
+    classes
+}
+
+/// Runs `check` on every class in the corpus, failing with all the errors
+/// at once.
+fn check_corpus(check: impl Fn(&Path, &[u8]) -> Result<(), String>) {
+    let failures = corpus()
+        .iter()
+        .filter_map(|path| {
+            let bytes = fs::read(path).unwrap();
+            check(path, &bytes)
+                .err()
+                .map(|err| format!("{}: {}", path.display(), err))
+        })
+        .collect::<Vec<_>>();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn round_trips_byte_for_byte() {
+    check_corpus(|_, bytes| {
+        let class_file = class_file_parser::parse(bytes).map_err(|err| err.to_string())?;
+        let written = class_file.to_bytes().map_err(|err| err.to_string())?;
+        if written == bytes {
+            return Ok(());
+        }
+        let offset = written
+            .iter()
+            .zip(bytes)
+            .position(|(written, original)| written != original)
+            .unwrap_or(written.len().min(bytes.len()));
+        Err(format!("written bytes differ from offset {}", offset))
+    });
+}
+
+#[test]
+fn owned_copies_outlive_their_input() {
+    check_corpus(|_, bytes| {
+        let input = bytes.to_vec();
+        let class_file = class_file_parser::parse(&input)
+            .map_err(|err| err.to_string())?
+            .into_owned();
+        drop(input);
+        let written = class_file.to_bytes().map_err(|err| err.to_string())?;
+        if written != bytes {
+            return Err("the owned copy writes different bytes".to_string());
+        }
+        Ok(())
+    });
+}
+
+#[test]
+fn disassembly_matches_javap() {
+    check_corpus(|path, bytes| {
+        let golden =
+            fs::read_to_string(path.with_extension("javap")).map_err(|err| err.to_string())?;
+        let class_file = class_file_parser::parse(bytes).map_err(|err| err.to_string())?;
+        let disassembly = disassemble(&class_file).map_err(|err| err.to_string())?;
+        match golden
+            .lines()
+            .zip(disassembly.lines())
+            .position(|(a, b)| a != b)
+        {
+            Some(i) => Err(format!(
+                "line {} differs\n  javap: {}\n  ours:  {}",
+                i + 1,
+                golden.lines().nth(i).unwrap_or_default(),
+                disassembly.lines().nth(i).unwrap_or_default()
+            )),
+            None if golden.lines().count() != disassembly.lines().count() => Err(format!(
+                "javap printed {} lines, we printed {}",
+                golden.lines().count(),
+                disassembly.lines().count()
+            )),
+            None => Ok(()),
+        }
+    });
+}
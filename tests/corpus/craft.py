@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+"""Writes classes/crafted/Custom.class: a compiled `Simple` class with an
+extra 23-byte class attribute named `Custom`, which no compiler emits.
+
+Usage: craft.py <Simple.class> <Custom.class>
+"""
+import struct
+import sys
+
+CUSTOM = bytes(range(0x14)) + b"\xff\x80\xab"
+
+
+def main(simple, custom):
+    with open(simple, "rb") as f:
+        data = f.read()
+
+    # Find the end of the constant pool, where the access flags start.
+    (count,) = struct.unpack_from(">H", data, 8)
+    at, index = 10, 1
+    while index < count:
+        tag = data[at]
+        if tag == 1:
+            (length,) = struct.unpack_from(">H", data, at + 1)
+            at += 3 + length
+        else:
+            at += {3: 5, 4: 5, 5: 9, 6: 9, 7: 3, 8: 3, 9: 5, 10: 5, 11: 5,
+                   12: 5, 15: 4, 16: 3, 17: 5, 18: 5, 19: 3, 20: 3}[tag]
+        index += 2 if tag in (5, 6) else 1
+    name = b"\x01" + struct.pack(">H", len(b"Custom")) + b"Custom"
+
+    # The class attributes come last; their count follows the methods.
+    body = data[at:]
+    attributes = attributes_count_offset(body)
+    (attribute_count,) = struct.unpack_from(">H", body, attributes)
+    attribute = struct.pack(">HI", count, len(CUSTOM)) + CUSTOM
+
+    out = (data[:8] + struct.pack(">H", count + 1) + data[10:at] + name
+           + body[:attributes] + struct.pack(">H", attribute_count + 1)
+           + body[attributes + 2:] + attribute)
+    with open(custom, "wb") as f:
+        f.write(out)
+
+
+def attributes_count_offset(body):
+    """The offset of the class attributes_count from the access flags."""
+    (interfaces,) = struct.unpack_from(">H", body, 6)
+    at = 8 + 2 * interfaces
+    for _ in range(2):  # fields, then methods
+        (members,) = struct.unpack_from(">H", body, at)
+        at += 2
+        for _ in range(members):
+            at = skip_attributes(body, at + 6)
+    return at
+
+
+def skip_attributes(body, at):
+    (attributes,) = struct.unpack_from(">H", body, at)
+    at += 2
+    for _ in range(attributes):
+        (length,) = struct.unpack_from(">I", body, at + 2)
+        at += 6 + length
+    return at
+
+
+if __name__ == "__main__":
+    main(*sys.argv[1:])
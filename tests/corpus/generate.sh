@@ -0,0 +1,47 @@
+#!/bin/sh
+# Regenerates classes/ from sources/ with the JDK on the PATH. Each class
+# file gets a golden `.javap` snapshot: the output of `javap -c -v -p`
+# without its first three lines, which describe the file on disk.
+set -eu
+cd "$(dirname "$0")"
+
+compile() {
+    out="classes/$1"
+    shift
+    mkdir -p "$out"
+    javac -encoding UTF-8 -Xlint:-options -d "$out" "$@"
+}
+
+rm -rf classes/release-*
+for release in 7 8 11 17; do
+    case $release in
+        7) flags="-g" ;;
+        8) flags="-g -parameters" ;;
+        11) flags="-g:none" ;;
+        17) flags="-g -parameters" ;;
+    esac
+    for group in java7 java8 java11 java17; do
+        since=${group#java}
+        if [ "$release" -ge "$since" ]; then
+            # shellcheck disable=SC2086
+            compile "release-$release/$group" --release "$release" $flags sources/$group/*.java
+        fi
+    done
+    if [ "$release" -ge 9 ]; then
+        # shellcheck disable=SC2086
+        compile "release-$release/module" --release "$release" $flags \
+            $(find sources/module -name '*.java')
+    fi
+done
+
+# Custom.class carries an attribute no compiler emits; see craft.py.
+crafted=$(mktemp -d)
+javac -encoding UTF-8 --release 17 -d "$crafted" sources/crafted/Simple.java
+./craft.py "$crafted/Simple.class" classes/crafted/Custom.class
+rm -rf "$crafted"
+
+# classes/jdk-asm is checked in as dumped; see the README.
+find classes -name '*.class' | while read -r class; do
+    javap -J-Dfile.encoding=UTF-8 -J-Dsun.stdout.encoding=UTF-8 -c -v -p "$class" \
+        | tail -n +4 > "${class%.class}.javap"
+done
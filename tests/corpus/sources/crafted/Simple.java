@@ -0,0 +1,3 @@
+public class Simple {
+    int x;
+}
@@ -0,0 +1,41 @@
+import java.util.List;
+
+/** Nest mates, private interface methods and indy string concatenation. */
+public class Nests {
+    private int secret = 7;
+
+    interface Helper {
+        private int twice(int x) {
+            return 2 * x;
+        }
+
+        default int quadruple(int x) {
+            return twice(twice(x));
+        }
+    }
+
+    class Member {
+        int peek() {
+            return secret;
+        }
+    }
+
+    static class Other {
+        int peek(Nests outer) {
+            return outer.secret;
+        }
+    }
+
+    String concat(String a, int b, long c, char d, Object e) {
+        return "a=" + a + ", b=" + b + ", c=" + c + ", d=" + d + ", e=" + e;
+    }
+
+    int inferred() {
+        var items = List.of(1, 2, 3);
+        var total = 0;
+        for (var item : items) {
+            total += item;
+        }
+        return total;
+    }
+}
@@ -0,0 +1,49 @@
+import java.util.List;
+
+/** Records, sealed types, switch expressions, patterns and text blocks. */
+public class Modern {
+    sealed interface Shape permits Circle, Square, Other {}
+
+    record Circle(double radius) implements Shape {}
+
+    record Square(double side) implements Shape {
+        Square {
+            if (side < 0) {
+                throw new IllegalArgumentException("negative side");
+            }
+        }
+    }
+
+    static non-sealed class Other implements Shape {}
+
+    record Pair<A, B>(A first, List<B> second) {}
+
+    static double area(Shape shape) {
+        if (shape instanceof Circle c) {
+            return Math.PI * c.radius() * c.radius();
+        }
+        if (shape instanceof Square s) {
+            return s.side() * s.side();
+        }
+        return 0;
+    }
+
+    static String name(int day) {
+        return switch (day) {
+            case 1, 7 -> "weekend";
+            case 2, 3, 4, 5, 6 -> {
+                String kind = "week";
+                yield kind + "day";
+            }
+            default -> throw new IllegalArgumentException("day " + day);
+        };
+    }
+
+    static String text() {
+        return """
+            Hello,
+              "text" block\
+             continued
+            """;
+    }
+}
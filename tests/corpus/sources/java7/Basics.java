@@ -0,0 +1,130 @@
+import java.io.IOException;
+import java.io.Serializable;
+import java.util.AbstractList;
+import java.util.List;
+import java.util.Map;
+
+/** Core language features available since Java 7. */
+@Deprecated
+public abstract class Basics<T extends Comparable<T>, U> extends AbstractList<T>
+        implements Serializable, Cloneable {
+    public static final int INT = 42;
+    static final long LONG = 1234567890123L;
+    static final float FLOAT = 1.5f;
+    static final double DOUBLE = 2.25;
+    static final double SMALL = 1.0E-5;
+    static final double LARGE = 6.02E23;
+    static final float NOT_A_NUMBER = Float.NaN;
+    static final double INFINITE = Double.NEGATIVE_INFINITY;
+    static final String STRING = "tab\tquote\"newline\n";
+    static final char CHAR = 'x';
+    static final boolean BOOLEAN = true;
+    protected transient volatile Map<String, List<? super T>> map;
+    private int[][] grid;
+    @Deprecated
+    public Object deprecated;
+
+    enum Color { RED, GREEN, BLUE }
+
+    interface Face { int f(); }
+
+    class Inner { int y = INT; }
+
+    static class Nested<V extends Number & Comparable<V>> {
+        V value;
+    }
+
+    public abstract T get(int i);
+
+    public int size() { return 0; }
+
+    synchronized native void nat();
+
+    static strictfp double strict(double a) { return a * 2; }
+
+    <X extends Exception> void thrower() throws X, IOException {}
+
+    static int sum(int... values) {
+        int total = 0;
+        for (int value : values) {
+            total += value;
+        }
+        return total;
+    }
+
+    int table(int k) {
+        switch (k) {
+            case 1: return 10;
+            case 2: return 20;
+            case 3: return 30;
+            default: return -1;
+        }
+    }
+
+    int lookup(int k) {
+        switch (k) {
+            case 1: return 10;
+            case 1000: return 20;
+            case -50000: return 30;
+            default: return -1;
+        }
+    }
+
+    String strings(String s) {
+        switch (s) {
+            case "a": return "A";
+            case "b": return "B";
+            default: return s;
+        }
+    }
+
+    long arithmetic(long a, float f, double d, Object o) throws Exception {
+        long r = a + 100000L;
+        int i = 70000;
+        short sh = 300;
+        byte by = -5;
+        r += (long) f + (long) d + i + sh + by;
+        r <<= 3;
+        r ^= ~r >>> 7;
+        int[] ints = new int[3];
+        String[][] matrix = new String[2][3];
+        Object[] objects = new Object[4];
+        if (o instanceof String) {
+            r++;
+        }
+        try {
+            r /= ints.length + matrix.length + objects.length;
+        } catch (ArithmeticException | IllegalStateException e) {
+            r = -1;
+        } finally {
+            r--;
+        }
+        synchronized (this) {
+            r *= 2;
+        }
+        assert r != 0 : "zero";
+        return r;
+    }
+
+    @Override
+    public Basics<T, U> clone() throws CloneNotSupportedException {
+        @SuppressWarnings("unchecked")
+        Basics<T, U> copy = (Basics<T, U>) super.clone();
+        return copy;
+    }
+
+    Runnable anonymous(final int captured) {
+        return new Runnable() {
+            public void run() {
+                System.out.println(captured);
+            }
+        };
+    }
+
+    Object local() {
+        class Local {
+            int value = INT;
+        }
+        return new Local();
+    }
+}
@@ -0,0 +1,19 @@
+/** Strings whose modified UTF-8 differs from UTF-8. */
+public class Text {
+    static final String NUL = "before\0after";
+    static final String SUPPLEMENTARY = "clef 𝄞 and emoji 😀";
+    static final String HIGH_SURROGATE = "x\uD800y";
+    static final String LOW_SURROGATE = "x\uDC00y";
+    static final String MIXED = "café € \0 𐐷";
+    static final char NUL_CHAR = '\0';
+
+    int 𐐷field;
+
+    String 𝒜method(String 𝒟parameter) {
+        return 𝒟parameter + NUL + 𐐷field;
+    }
+
+    <𝒯 extends Comparable<𝒯>> 𝒯 generic(java.util.List<? extends 𝒯> values) {
+        return values.get(0);
+    }
+}
@@ -0,0 +1,1736 @@
+/** A method with more than 256 locals and a large branch, forcing wide and goto_w forms. */
+public class Wide {
+    static int many(int seed) {
+        int a0 = seed, a1 = a0 + 1, a2 = a1 + 1, a3 = a2 + 1, a4 = a3 + 1, a5 = a4 + 1, a6 = a5 + 1, a7 = a6 + 1;
+        long b0 = a7, b1 = b0 + 1, b2 = b1 + 1, b3 = b2 + 1, b4 = b3 + 1, b5 = b4 + 1, b6 = b5 + 1, b7 = b6 + 1;
+        long c0 = b7, c1 = c0 + 1, c2 = c1 + 1, c3 = c2 + 1, c4 = c3 + 1, c5 = c4 + 1, c6 = c5 + 1, c7 = c6 + 1;
+        long d0 = c7, d1 = d0 + 1, d2 = d1 + 1, d3 = d2 + 1, d4 = d3 + 1, d5 = d4 + 1, d6 = d5 + 1, d7 = d6 + 1;
+        long e0 = d7, e1 = e0 + 1, e2 = e1 + 1, e3 = e2 + 1, e4 = e3 + 1, e5 = e4 + 1, e6 = e5 + 1, e7 = e6 + 1;
+        long f0 = e7, f1 = f0 + 1, f2 = f1 + 1, f3 = f2 + 1, f4 = f3 + 1, f5 = f4 + 1, f6 = f5 + 1, f7 = f6 + 1;
+        long g0 = f7, g1 = g0 + 1, g2 = g1 + 1, g3 = g2 + 1, g4 = g3 + 1, g5 = g4 + 1, g6 = g5 + 1, g7 = g6 + 1;
+        long h0 = g7, h1 = h0 + 1, h2 = h1 + 1, h3 = h2 + 1, h4 = h3 + 1, h5 = h4 + 1, h6 = h5 + 1, h7 = h6 + 1;
+        long i0 = h7, i1 = i0 + 1, i2 = i1 + 1, i3 = i2 + 1, i4 = i3 + 1, i5 = i4 + 1, i6 = i5 + 1, i7 = i6 + 1;
+        long j0 = i7, j1 = j0 + 1, j2 = j1 + 1, j3 = j2 + 1, j4 = j3 + 1, j5 = j4 + 1, j6 = j5 + 1, j7 = j6 + 1;
+        long k0 = j7, k1 = k0 + 1, k2 = k1 + 1, k3 = k2 + 1, k4 = k3 + 1, k5 = k4 + 1, k6 = k5 + 1, k7 = k6 + 1;
+        long l0 = k7, l1 = l0 + 1, l2 = l1 + 1, l3 = l2 + 1, l4 = l3 + 1, l5 = l4 + 1, l6 = l5 + 1, l7 = l6 + 1;
+        long m0 = l7, m1 = m0 + 1, m2 = m1 + 1, m3 = m2 + 1, m4 = m3 + 1, m5 = m4 + 1, m6 = m5 + 1, m7 = m6 + 1;
+        long n0 = m7, n1 = n0 + 1, n2 = n1 + 1, n3 = n2 + 1, n4 = n3 + 1, n5 = n4 + 1, n6 = n5 + 1, n7 = n6 + 1;
+        long o0 = n7, o1 = o0 + 1, o2 = o1 + 1, o3 = o2 + 1, o4 = o3 + 1, o5 = o4 + 1, o6 = o5 + 1, o7 = o6 + 1;
+        long p0 = o7, p1 = p0 + 1, p2 = p1 + 1, p3 = p2 + 1, p4 = p3 + 1, p5 = p4 + 1, p6 = p5 + 1, p7 = p6 + 1;
+        long q0 = p7, q1 = q0 + 1, q2 = q1 + 1, q3 = q2 + 1, q4 = q3 + 1, q5 = q4 + 1, q6 = q5 + 1, q7 = q6 + 1;
+        long last = q7;
+        int counter = a0;
+        counter += 1000;
+        last += counter;
+        return (int) last;
+    }
+}
+
+class LongLoop {
+    static long loop(int[] values, int n) {
+        long total = 0;
+        for (int i = 0; i < n; i++) {
+            total += values[0] * 100000L + values[3] * 300000L;
+            total += values[1] * 100001L + values[4] * 300001L;
+            total += values[2] * 100002L + values[5] * 300002L;
+            total += values[3] * 100003L + values[6] * 300003L;
+            total += values[4] * 100004L + values[0] * 300004L;
+            total += values[5] * 100005L + values[1] * 300005L;
+            total += values[6] * 100006L + values[2] * 300006L;
+            total += values[0] * 100007L + values[3] * 300007L;
+            total += values[1] * 100008L + values[4] * 300008L;
+            total += values[2] * 100009L + values[5] * 300009L;
+            total += values[3] * 100010L + values[6] * 300010L;
+            total += values[4] * 100011L + values[0] * 300011L;
+            total += values[5] * 100012L + values[1] * 300012L;
+            total += values[6] * 100013L + values[2] * 300013L;
+            total += values[0] * 100014L + values[3] * 300014L;
+            total += values[1] * 100015L + values[4] * 300015L;
+            total += values[2] * 100016L + values[5] * 300016L;
+            total += values[3] * 100017L + values[6] * 300017L;
+            total += values[4] * 100018L + values[0] * 300018L;
+            total += values[5] * 100019L + values[1] * 300019L;
+            total += values[6] * 100020L + values[2] * 300020L;
+            total += values[0] * 100021L + values[3] * 300021L;
+            total += values[1] * 100022L + values[4] * 300022L;
+            total += values[2] * 100023L + values[5] * 300023L;
+            total += values[3] * 100024L + values[6] * 300024L;
+            total += values[4] * 100025L + values[0] * 300025L;
+            total += values[5] * 100026L + values[1] * 300026L;
+            total += values[6] * 100027L + values[2] * 300027L;
+            total += values[0] * 100028L + values[3] * 300028L;
+            total += values[1] * 100029L + values[4] * 300029L;
+            total += values[2] * 100030L + values[5] * 300030L;
+            total += values[3] * 100031L + values[6] * 300031L;
+            total += values[4] * 100032L + values[0] * 300032L;
+            total += values[5] * 100033L + values[1] * 300033L;
+            total += values[6] * 100034L + values[2] * 300034L;
+            total += values[0] * 100035L + values[3] * 300035L;
+            total += values[1] * 100036L + values[4] * 300036L;
+            total += values[2] * 100037L + values[5] * 300037L;
+            total += values[3] * 100038L + values[6] * 300038L;
+            total += values[4] * 100039L + values[0] * 300039L;
+            total += values[5] * 100040L + values[1] * 300040L;
+            total += values[6] * 100041L + values[2] * 300041L;
+            total += values[0] * 100042L + values[3] * 300042L;
+            total += values[1] * 100043L + values[4] * 300043L;
+            total += values[2] * 100044L + values[5] * 300044L;
+            total += values[3] * 100045L + values[6] * 300045L;
+            total += values[4] * 100046L + values[0] * 300046L;
+            total += values[5] * 100047L + values[1] * 300047L;
+            total += values[6] * 100048L + values[2] * 300048L;
+            total += values[0] * 100049L + values[3] * 300049L;
+            total += values[1] * 100050L + values[4] * 300050L;
+            total += values[2] * 100051L + values[5] * 300051L;
+            total += values[3] * 100052L + values[6] * 300052L;
+            total += values[4] * 100053L + values[0] * 300053L;
+            total += values[5] * 100054L + values[1] * 300054L;
+            total += values[6] * 100055L + values[2] * 300055L;
+            total += values[0] * 100056L + values[3] * 300056L;
+            total += values[1] * 100057L + values[4] * 300057L;
+            total += values[2] * 100058L + values[5] * 300058L;
+            total += values[3] * 100059L + values[6] * 300059L;
+            total += values[4] * 100060L + values[0] * 300060L;
+            total += values[5] * 100061L + values[1] * 300061L;
+            total += values[6] * 100062L + values[2] * 300062L;
+            total += values[0] * 100063L + values[3] * 300063L;
+            total += values[1] * 100064L + values[4] * 300064L;
+            total += values[2] * 100065L + values[5] * 300065L;
+            total += values[3] * 100066L + values[6] * 300066L;
+            total += values[4] * 100067L + values[0] * 300067L;
+            total += values[5] * 100068L + values[1] * 300068L;
+            total += values[6] * 100069L + values[2] * 300069L;
+            total += values[0] * 100070L + values[3] * 300070L;
+            total += values[1] * 100071L + values[4] * 300071L;
+            total += values[2] * 100072L + values[5] * 300072L;
+            total += values[3] * 100073L + values[6] * 300073L;
+            total += values[4] * 100074L + values[0] * 300074L;
+            total += values[5] * 100075L + values[1] * 300075L;
+            total += values[6] * 100076L + values[2] * 300076L;
+            total += values[0] * 100077L + values[3] * 300077L;
+            total += values[1] * 100078L + values[4] * 300078L;
+            total += values[2] * 100079L + values[5] * 300079L;
+            total += values[3] * 100080L + values[6] * 300080L;
+            total += values[4] * 100081L + values[0] * 300081L;
+            total += values[5] * 100082L + values[1] * 300082L;
+            total += values[6] * 100083L + values[2] * 300083L;
+            total += values[0] * 100084L + values[3] * 300084L;
+            total += values[1] * 100085L + values[4] * 300085L;
+            total += values[2] * 100086L + values[5] * 300086L;
+            total += values[3] * 100087L + values[6] * 300087L;
+            total += values[4] * 100088L + values[0] * 300088L;
+            total += values[5] * 100089L + values[1] * 300089L;
+            total += values[6] * 100090L + values[2] * 300090L;
+            total += values[0] * 100091L + values[3] * 300091L;
+            total += values[1] * 100092L + values[4] * 300092L;
+            total += values[2] * 100093L + values[5] * 300093L;
+            total += values[3] * 100094L + values[6] * 300094L;
+            total += values[4] * 100095L + values[0] * 300095L;
+            total += values[5] * 100096L + values[1] * 300096L;
+            total += values[6] * 100097L + values[2] * 300097L;
+            total += values[0] * 100098L + values[3] * 300098L;
+            total += values[1] * 100099L + values[4] * 300099L;
+            total += values[2] * 100100L + values[5] * 300100L;
+            total += values[3] * 100101L + values[6] * 300101L;
+            total += values[4] * 100102L + values[0] * 300102L;
+            total += values[5] * 100103L + values[1] * 300103L;
+            total += values[6] * 100104L + values[2] * 300104L;
+            total += values[0] * 100105L + values[3] * 300105L;
+            total += values[1] * 100106L + values[4] * 300106L;
+            total += values[2] * 100107L + values[5] * 300107L;
+            total += values[3] * 100108L + values[6] * 300108L;
+            total += values[4] * 100109L + values[0] * 300109L;
+            total += values[5] * 100110L + values[1] * 300110L;
+            total += values[6] * 100111L + values[2] * 300111L;
+            total += values[0] * 100112L + values[3] * 300112L;
+            total += values[1] * 100113L + values[4] * 300113L;
+            total += values[2] * 100114L + values[5] * 300114L;
+            total += values[3] * 100115L + values[6] * 300115L;
+            total += values[4] * 100116L + values[0] * 300116L;
+            total += values[5] * 100117L + values[1] * 300117L;
+            total += values[6] * 100118L + values[2] * 300118L;
+            total += values[0] * 100119L + values[3] * 300119L;
+            total += values[1] * 100120L + values[4] * 300120L;
+            total += values[2] * 100121L + values[5] * 300121L;
+            total += values[3] * 100122L + values[6] * 300122L;
+            total += values[4] * 100123L + values[0] * 300123L;
+            total += values[5] * 100124L + values[1] * 300124L;
+            total += values[6] * 100125L + values[2] * 300125L;
+            total += values[0] * 100126L + values[3] * 300126L;
+            total += values[1] * 100127L + values[4] * 300127L;
+            total += values[2] * 100128L + values[5] * 300128L;
+            total += values[3] * 100129L + values[6] * 300129L;
+            total += values[4] * 100130L + values[0] * 300130L;
+            total += values[5] * 100131L + values[1] * 300131L;
+            total += values[6] * 100132L + values[2] * 300132L;
+            total += values[0] * 100133L + values[3] * 300133L;
+            total += values[1] * 100134L + values[4] * 300134L;
+            total += values[2] * 100135L + values[5] * 300135L;
+            total += values[3] * 100136L + values[6] * 300136L;
+            total += values[4] * 100137L + values[0] * 300137L;
+            total += values[5] * 100138L + values[1] * 300138L;
+            total += values[6] * 100139L + values[2] * 300139L;
+            total += values[0] * 100140L + values[3] * 300140L;
+            total += values[1] * 100141L + values[4] * 300141L;
+            total += values[2] * 100142L + values[5] * 300142L;
+            total += values[3] * 100143L + values[6] * 300143L;
+            total += values[4] * 100144L + values[0] * 300144L;
+            total += values[5] * 100145L + values[1] * 300145L;
+            total += values[6] * 100146L + values[2] * 300146L;
+            total += values[0] * 100147L + values[3] * 300147L;
+            total += values[1] * 100148L + values[4] * 300148L;
+            total += values[2] * 100149L + values[5] * 300149L;
+            total += values[3] * 100150L + values[6] * 300150L;
+            total += values[4] * 100151L + values[0] * 300151L;
+            total += values[5] * 100152L + values[1] * 300152L;
+            total += values[6] * 100153L + values[2] * 300153L;
+            total += values[0] * 100154L + values[3] * 300154L;
+            total += values[1] * 100155L + values[4] * 300155L;
+            total += values[2] * 100156L + values[5] * 300156L;
+            total += values[3] * 100157L + values[6] * 300157L;
+            total += values[4] * 100158L + values[0] * 300158L;
+            total += values[5] * 100159L + values[1] * 300159L;
+            total += values[6] * 100160L + values[2] * 300160L;
+            total += values[0] * 100161L + values[3] * 300161L;
+            total += values[1] * 100162L + values[4] * 300162L;
+            total += values[2] * 100163L + values[5] * 300163L;
+            total += values[3] * 100164L + values[6] * 300164L;
+            total += values[4] * 100165L + values[0] * 300165L;
+            total += values[5] * 100166L + values[1] * 300166L;
+            total += values[6] * 100167L + values[2] * 300167L;
+            total += values[0] * 100168L + values[3] * 300168L;
+            total += values[1] * 100169L + values[4] * 300169L;
+            total += values[2] * 100170L + values[5] * 300170L;
+            total += values[3] * 100171L + values[6] * 300171L;
+            total += values[4] * 100172L + values[0] * 300172L;
+            total += values[5] * 100173L + values[1] * 300173L;
+            total += values[6] * 100174L + values[2] * 300174L;
+            total += values[0] * 100175L + values[3] * 300175L;
+            total += values[1] * 100176L + values[4] * 300176L;
+            total += values[2] * 100177L + values[5] * 300177L;
+            total += values[3] * 100178L + values[6] * 300178L;
+            total += values[4] * 100179L + values[0] * 300179L;
+            total += values[5] * 100180L + values[1] * 300180L;
+            total += values[6] * 100181L + values[2] * 300181L;
+            total += values[0] * 100182L + values[3] * 300182L;
+            total += values[1] * 100183L + values[4] * 300183L;
+            total += values[2] * 100184L + values[5] * 300184L;
+            total += values[3] * 100185L + values[6] * 300185L;
+            total += values[4] * 100186L + values[0] * 300186L;
+            total += values[5] * 100187L + values[1] * 300187L;
+            total += values[6] * 100188L + values[2] * 300188L;
+            total += values[0] * 100189L + values[3] * 300189L;
+            total += values[1] * 100190L + values[4] * 300190L;
+            total += values[2] * 100191L + values[5] * 300191L;
+            total += values[3] * 100192L + values[6] * 300192L;
+            total += values[4] * 100193L + values[0] * 300193L;
+            total += values[5] * 100194L + values[1] * 300194L;
+            total += values[6] * 100195L + values[2] * 300195L;
+            total += values[0] * 100196L + values[3] * 300196L;
+            total += values[1] * 100197L + values[4] * 300197L;
+            total += values[2] * 100198L + values[5] * 300198L;
+            total += values[3] * 100199L + values[6] * 300199L;
+            total += values[4] * 100200L + values[0] * 300200L;
+            total += values[5] * 100201L + values[1] * 300201L;
+            total += values[6] * 100202L + values[2] * 300202L;
+            total += values[0] * 100203L + values[3] * 300203L;
+            total += values[1] * 100204L + values[4] * 300204L;
+            total += values[2] * 100205L + values[5] * 300205L;
+            total += values[3] * 100206L + values[6] * 300206L;
+            total += values[4] * 100207L + values[0] * 300207L;
+            total += values[5] * 100208L + values[1] * 300208L;
+            total += values[6] * 100209L + values[2] * 300209L;
+            total += values[0] * 100210L + values[3] * 300210L;
+            total += values[1] * 100211L + values[4] * 300211L;
+            total += values[2] * 100212L + values[5] * 300212L;
+            total += values[3] * 100213L + values[6] * 300213L;
+            total += values[4] * 100214L + values[0] * 300214L;
+            total += values[5] * 100215L + values[1] * 300215L;
+            total += values[6] * 100216L + values[2] * 300216L;
+            total += values[0] * 100217L + values[3] * 300217L;
+            total += values[1] * 100218L + values[4] * 300218L;
+            total += values[2] * 100219L + values[5] * 300219L;
+            total += values[3] * 100220L + values[6] * 300220L;
+            total += values[4] * 100221L + values[0] * 300221L;
+            total += values[5] * 100222L + values[1] * 300222L;
+            total += values[6] * 100223L + values[2] * 300223L;
+            total += values[0] * 100224L + values[3] * 300224L;
+            total += values[1] * 100225L + values[4] * 300225L;
+            total += values[2] * 100226L + values[5] * 300226L;
+            total += values[3] * 100227L + values[6] * 300227L;
+            total += values[4] * 100228L + values[0] * 300228L;
+            total += values[5] * 100229L + values[1] * 300229L;
+            total += values[6] * 100230L + values[2] * 300230L;
+            total += values[0] * 100231L + values[3] * 300231L;
+            total += values[1] * 100232L + values[4] * 300232L;
+            total += values[2] * 100233L + values[5] * 300233L;
+            total += values[3] * 100234L + values[6] * 300234L;
+            total += values[4] * 100235L + values[0] * 300235L;
+            total += values[5] * 100236L + values[1] * 300236L;
+            total += values[6] * 100237L + values[2] * 300237L;
+            total += values[0] * 100238L + values[3] * 300238L;
+            total += values[1] * 100239L + values[4] * 300239L;
+            total += values[2] * 100240L + values[5] * 300240L;
+            total += values[3] * 100241L + values[6] * 300241L;
+            total += values[4] * 100242L + values[0] * 300242L;
+            total += values[5] * 100243L + values[1] * 300243L;
+            total += values[6] * 100244L + values[2] * 300244L;
+            total += values[0] * 100245L + values[3] * 300245L;
+            total += values[1] * 100246L + values[4] * 300246L;
+            total += values[2] * 100247L + values[5] * 300247L;
+            total += values[3] * 100248L + values[6] * 300248L;
+            total += values[4] * 100249L + values[0] * 300249L;
+            total += values[5] * 100250L + values[1] * 300250L;
+            total += values[6] * 100251L + values[2] * 300251L;
+            total += values[0] * 100252L + values[3] * 300252L;
+            total += values[1] * 100253L + values[4] * 300253L;
+            total += values[2] * 100254L + values[5] * 300254L;
+            total += values[3] * 100255L + values[6] * 300255L;
+            total += values[4] * 100256L + values[0] * 300256L;
+            total += values[5] * 100257L + values[1] * 300257L;
+            total += values[6] * 100258L + values[2] * 300258L;
+            total += values[0] * 100259L + values[3] * 300259L;
+            total += values[1] * 100260L + values[4] * 300260L;
+            total += values[2] * 100261L + values[5] * 300261L;
+            total += values[3] * 100262L + values[6] * 300262L;
+            total += values[4] * 100263L + values[0] * 300263L;
+            total += values[5] * 100264L + values[1] * 300264L;
+            total += values[6] * 100265L + values[2] * 300265L;
+            total += values[0] * 100266L + values[3] * 300266L;
+            total += values[1] * 100267L + values[4] * 300267L;
+            total += values[2] * 100268L + values[5] * 300268L;
+            total += values[3] * 100269L + values[6] * 300269L;
+            total += values[4] * 100270L + values[0] * 300270L;
+            total += values[5] * 100271L + values[1] * 300271L;
+            total += values[6] * 100272L + values[2] * 300272L;
+            total += values[0] * 100273L + values[3] * 300273L;
+            total += values[1] * 100274L + values[4] * 300274L;
+            total += values[2] * 100275L + values[5] * 300275L;
+            total += values[3] * 100276L + values[6] * 300276L;
+            total += values[4] * 100277L + values[0] * 300277L;
+            total += values[5] * 100278L + values[1] * 300278L;
+            total += values[6] * 100279L + values[2] * 300279L;
+            total += values[0] * 100280L + values[3] * 300280L;
+            total += values[1] * 100281L + values[4] * 300281L;
+            total += values[2] * 100282L + values[5] * 300282L;
+            total += values[3] * 100283L + values[6] * 300283L;
+            total += values[4] * 100284L + values[0] * 300284L;
+            total += values[5] * 100285L + values[1] * 300285L;
+            total += values[6] * 100286L + values[2] * 300286L;
+            total += values[0] * 100287L + values[3] * 300287L;
+            total += values[1] * 100288L + values[4] * 300288L;
+            total += values[2] * 100289L + values[5] * 300289L;
+            total += values[3] * 100290L + values[6] * 300290L;
+            total += values[4] * 100291L + values[0] * 300291L;
+            total += values[5] * 100292L + values[1] * 300292L;
+            total += values[6] * 100293L + values[2] * 300293L;
+            total += values[0] * 100294L + values[3] * 300294L;
+            total += values[1] * 100295L + values[4] * 300295L;
+            total += values[2] * 100296L + values[5] * 300296L;
+            total += values[3] * 100297L + values[6] * 300297L;
+            total += values[4] * 100298L + values[0] * 300298L;
+            total += values[5] * 100299L + values[1] * 300299L;
+            total += values[6] * 100300L + values[2] * 300300L;
+            total += values[0] * 100301L + values[3] * 300301L;
+            total += values[1] * 100302L + values[4] * 300302L;
+            total += values[2] * 100303L + values[5] * 300303L;
+            total += values[3] * 100304L + values[6] * 300304L;
+            total += values[4] * 100305L + values[0] * 300305L;
+            total += values[5] * 100306L + values[1] * 300306L;
+            total += values[6] * 100307L + values[2] * 300307L;
+            total += values[0] * 100308L + values[3] * 300308L;
+            total += values[1] * 100309L + values[4] * 300309L;
+            total += values[2] * 100310L + values[5] * 300310L;
+            total += values[3] * 100311L + values[6] * 300311L;
+            total += values[4] * 100312L + values[0] * 300312L;
+            total += values[5] * 100313L + values[1] * 300313L;
+            total += values[6] * 100314L + values[2] * 300314L;
+            total += values[0] * 100315L + values[3] * 300315L;
+            total += values[1] * 100316L + values[4] * 300316L;
+            total += values[2] * 100317L + values[5] * 300317L;
+            total += values[3] * 100318L + values[6] * 300318L;
+            total += values[4] * 100319L + values[0] * 300319L;
+            total += values[5] * 100320L + values[1] * 300320L;
+            total += values[6] * 100321L + values[2] * 300321L;
+            total += values[0] * 100322L + values[3] * 300322L;
+            total += values[1] * 100323L + values[4] * 300323L;
+            total += values[2] * 100324L + values[5] * 300324L;
+            total += values[3] * 100325L + values[6] * 300325L;
+            total += values[4] * 100326L + values[0] * 300326L;
+            total += values[5] * 100327L + values[1] * 300327L;
+            total += values[6] * 100328L + values[2] * 300328L;
+            total += values[0] * 100329L + values[3] * 300329L;
+            total += values[1] * 100330L + values[4] * 300330L;
+            total += values[2] * 100331L + values[5] * 300331L;
+            total += values[3] * 100332L + values[6] * 300332L;
+            total += values[4] * 100333L + values[0] * 300333L;
+            total += values[5] * 100334L + values[1] * 300334L;
+            total += values[6] * 100335L + values[2] * 300335L;
+            total += values[0] * 100336L + values[3] * 300336L;
+            total += values[1] * 100337L + values[4] * 300337L;
+            total += values[2] * 100338L + values[5] * 300338L;
+            total += values[3] * 100339L + values[6] * 300339L;
+            total += values[4] * 100340L + values[0] * 300340L;
+            total += values[5] * 100341L + values[1] * 300341L;
+            total += values[6] * 100342L + values[2] * 300342L;
+            total += values[0] * 100343L + values[3] * 300343L;
+            total += values[1] * 100344L + values[4] * 300344L;
+            total += values[2] * 100345L + values[5] * 300345L;
+            total += values[3] * 100346L + values[6] * 300346L;
+            total += values[4] * 100347L + values[0] * 300347L;
+            total += values[5] * 100348L + values[1] * 300348L;
+            total += values[6] * 100349L + values[2] * 300349L;
+            total += values[0] * 100350L + values[3] * 300350L;
+            total += values[1] * 100351L + values[4] * 300351L;
+            total += values[2] * 100352L + values[5] * 300352L;
+            total += values[3] * 100353L + values[6] * 300353L;
+            total += values[4] * 100354L + values[0] * 300354L;
+            total += values[5] * 100355L + values[1] * 300355L;
+            total += values[6] * 100356L + values[2] * 300356L;
+            total += values[0] * 100357L + values[3] * 300357L;
+            total += values[1] * 100358L + values[4] * 300358L;
+            total += values[2] * 100359L + values[5] * 300359L;
+            total += values[3] * 100360L + values[6] * 300360L;
+            total += values[4] * 100361L + values[0] * 300361L;
+            total += values[5] * 100362L + values[1] * 300362L;
+            total += values[6] * 100363L + values[2] * 300363L;
+            total += values[0] * 100364L + values[3] * 300364L;
+            total += values[1] * 100365L + values[4] * 300365L;
+            total += values[2] * 100366L + values[5] * 300366L;
+            total += values[3] * 100367L + values[6] * 300367L;
+            total += values[4] * 100368L + values[0] * 300368L;
+            total += values[5] * 100369L + values[1] * 300369L;
+            total += values[6] * 100370L + values[2] * 300370L;
+            total += values[0] * 100371L + values[3] * 300371L;
+            total += values[1] * 100372L + values[4] * 300372L;
+            total += values[2] * 100373L + values[5] * 300373L;
+            total += values[3] * 100374L + values[6] * 300374L;
+            total += values[4] * 100375L + values[0] * 300375L;
+            total += values[5] * 100376L + values[1] * 300376L;
+            total += values[6] * 100377L + values[2] * 300377L;
+            total += values[0] * 100378L + values[3] * 300378L;
+            total += values[1] * 100379L + values[4] * 300379L;
+            total += values[2] * 100380L + values[5] * 300380L;
+            total += values[3] * 100381L + values[6] * 300381L;
+            total += values[4] * 100382L + values[0] * 300382L;
+            total += values[5] * 100383L + values[1] * 300383L;
+            total += values[6] * 100384L + values[2] * 300384L;
+            total += values[0] * 100385L + values[3] * 300385L;
+            total += values[1] * 100386L + values[4] * 300386L;
+            total += values[2] * 100387L + values[5] * 300387L;
+            total += values[3] * 100388L + values[6] * 300388L;
+            total += values[4] * 100389L + values[0] * 300389L;
+            total += values[5] * 100390L + values[1] * 300390L;
+            total += values[6] * 100391L + values[2] * 300391L;
+            total += values[0] * 100392L + values[3] * 300392L;
+            total += values[1] * 100393L + values[4] * 300393L;
+            total += values[2] * 100394L + values[5] * 300394L;
+            total += values[3] * 100395L + values[6] * 300395L;
+            total += values[4] * 100396L + values[0] * 300396L;
+            total += values[5] * 100397L + values[1] * 300397L;
+            total += values[6] * 100398L + values[2] * 300398L;
+            total += values[0] * 100399L + values[3] * 300399L;
+            total += values[1] * 100400L + values[4] * 300400L;
+            total += values[2] * 100401L + values[5] * 300401L;
+            total += values[3] * 100402L + values[6] * 300402L;
+            total += values[4] * 100403L + values[0] * 300403L;
+            total += values[5] * 100404L + values[1] * 300404L;
+            total += values[6] * 100405L + values[2] * 300405L;
+            total += values[0] * 100406L + values[3] * 300406L;
+            total += values[1] * 100407L + values[4] * 300407L;
+            total += values[2] * 100408L + values[5] * 300408L;
+            total += values[3] * 100409L + values[6] * 300409L;
+            total += values[4] * 100410L + values[0] * 300410L;
+            total += values[5] * 100411L + values[1] * 300411L;
+            total += values[6] * 100412L + values[2] * 300412L;
+            total += values[0] * 100413L + values[3] * 300413L;
+            total += values[1] * 100414L + values[4] * 300414L;
+            total += values[2] * 100415L + values[5] * 300415L;
+            total += values[3] * 100416L + values[6] * 300416L;
+            total += values[4] * 100417L + values[0] * 300417L;
+            total += values[5] * 100418L + values[1] * 300418L;
+            total += values[6] * 100419L + values[2] * 300419L;
+            total += values[0] * 100420L + values[3] * 300420L;
+            total += values[1] * 100421L + values[4] * 300421L;
+            total += values[2] * 100422L + values[5] * 300422L;
+            total += values[3] * 100423L + values[6] * 300423L;
+            total += values[4] * 100424L + values[0] * 300424L;
+            total += values[5] * 100425L + values[1] * 300425L;
+            total += values[6] * 100426L + values[2] * 300426L;
+            total += values[0] * 100427L + values[3] * 300427L;
+            total += values[1] * 100428L + values[4] * 300428L;
+            total += values[2] * 100429L + values[5] * 300429L;
+            total += values[3] * 100430L + values[6] * 300430L;
+            total += values[4] * 100431L + values[0] * 300431L;
+            total += values[5] * 100432L + values[1] * 300432L;
+            total += values[6] * 100433L + values[2] * 300433L;
+            total += values[0] * 100434L + values[3] * 300434L;
+            total += values[1] * 100435L + values[4] * 300435L;
+            total += values[2] * 100436L + values[5] * 300436L;
+            total += values[3] * 100437L + values[6] * 300437L;
+            total += values[4] * 100438L + values[0] * 300438L;
+            total += values[5] * 100439L + values[1] * 300439L;
+            total += values[6] * 100440L + values[2] * 300440L;
+            total += values[0] * 100441L + values[3] * 300441L;
+            total += values[1] * 100442L + values[4] * 300442L;
+            total += values[2] * 100443L + values[5] * 300443L;
+            total += values[3] * 100444L + values[6] * 300444L;
+            total += values[4] * 100445L + values[0] * 300445L;
+            total += values[5] * 100446L + values[1] * 300446L;
+            total += values[6] * 100447L + values[2] * 300447L;
+            total += values[0] * 100448L + values[3] * 300448L;
+            total += values[1] * 100449L + values[4] * 300449L;
+            total += values[2] * 100450L + values[5] * 300450L;
+            total += values[3] * 100451L + values[6] * 300451L;
+            total += values[4] * 100452L + values[0] * 300452L;
+            total += values[5] * 100453L + values[1] * 300453L;
+            total += values[6] * 100454L + values[2] * 300454L;
+            total += values[0] * 100455L + values[3] * 300455L;
+            total += values[1] * 100456L + values[4] * 300456L;
+            total += values[2] * 100457L + values[5] * 300457L;
+            total += values[3] * 100458L + values[6] * 300458L;
+            total += values[4] * 100459L + values[0] * 300459L;
+            total += values[5] * 100460L + values[1] * 300460L;
+            total += values[6] * 100461L + values[2] * 300461L;
+            total += values[0] * 100462L + values[3] * 300462L;
+            total += values[1] * 100463L + values[4] * 300463L;
+            total += values[2] * 100464L + values[5] * 300464L;
+            total += values[3] * 100465L + values[6] * 300465L;
+            total += values[4] * 100466L + values[0] * 300466L;
+            total += values[5] * 100467L + values[1] * 300467L;
+            total += values[6] * 100468L + values[2] * 300468L;
+            total += values[0] * 100469L + values[3] * 300469L;
+            total += values[1] * 100470L + values[4] * 300470L;
+            total += values[2] * 100471L + values[5] * 300471L;
+            total += values[3] * 100472L + values[6] * 300472L;
+            total += values[4] * 100473L + values[0] * 300473L;
+            total += values[5] * 100474L + values[1] * 300474L;
+            total += values[6] * 100475L + values[2] * 300475L;
+            total += values[0] * 100476L + values[3] * 300476L;
+            total += values[1] * 100477L + values[4] * 300477L;
+            total += values[2] * 100478L + values[5] * 300478L;
+            total += values[3] * 100479L + values[6] * 300479L;
+            total += values[4] * 100480L + values[0] * 300480L;
+            total += values[5] * 100481L + values[1] * 300481L;
+            total += values[6] * 100482L + values[2] * 300482L;
+            total += values[0] * 100483L + values[3] * 300483L;
+            total += values[1] * 100484L + values[4] * 300484L;
+            total += values[2] * 100485L + values[5] * 300485L;
+            total += values[3] * 100486L + values[6] * 300486L;
+            total += values[4] * 100487L + values[0] * 300487L;
+            total += values[5] * 100488L + values[1] * 300488L;
+            total += values[6] * 100489L + values[2] * 300489L;
+            total += values[0] * 100490L + values[3] * 300490L;
+            total += values[1] * 100491L + values[4] * 300491L;
+            total += values[2] * 100492L + values[5] * 300492L;
+            total += values[3] * 100493L + values[6] * 300493L;
+            total += values[4] * 100494L + values[0] * 300494L;
+            total += values[5] * 100495L + values[1] * 300495L;
+            total += values[6] * 100496L + values[2] * 300496L;
+            total += values[0] * 100497L + values[3] * 300497L;
+            total += values[1] * 100498L + values[4] * 300498L;
+            total += values[2] * 100499L + values[5] * 300499L;
+            total += values[3] * 100500L + values[6] * 300500L;
+            total += values[4] * 100501L + values[0] * 300501L;
+            total += values[5] * 100502L + values[1] * 300502L;
+            total += values[6] * 100503L + values[2] * 300503L;
+            total += values[0] * 100504L + values[3] * 300504L;
+            total += values[1] * 100505L + values[4] * 300505L;
+            total += values[2] * 100506L + values[5] * 300506L;
+            total += values[3] * 100507L + values[6] * 300507L;
+            total += values[4] * 100508L + values[0] * 300508L;
+            total += values[5] * 100509L + values[1] * 300509L;
+            total += values[6] * 100510L + values[2] * 300510L;
+            total += values[0] * 100511L + values[3] * 300511L;
+            total += values[1] * 100512L + values[4] * 300512L;
+            total += values[2] * 100513L + values[5] * 300513L;
+            total += values[3] * 100514L + values[6] * 300514L;
+            total += values[4] * 100515L + values[0] * 300515L;
+            total += values[5] * 100516L + values[1] * 300516L;
+            total += values[6] * 100517L + values[2] * 300517L;
+            total += values[0] * 100518L + values[3] * 300518L;
+            total += values[1] * 100519L + values[4] * 300519L;
+            total += values[2] * 100520L + values[5] * 300520L;
+            total += values[3] * 100521L + values[6] * 300521L;
+            total += values[4] * 100522L + values[0] * 300522L;
+            total += values[5] * 100523L + values[1] * 300523L;
+            total += values[6] * 100524L + values[2] * 300524L;
+            total += values[0] * 100525L + values[3] * 300525L;
+            total += values[1] * 100526L + values[4] * 300526L;
+            total += values[2] * 100527L + values[5] * 300527L;
+            total += values[3] * 100528L + values[6] * 300528L;
+            total += values[4] * 100529L + values[0] * 300529L;
+            total += values[5] * 100530L + values[1] * 300530L;
+            total += values[6] * 100531L + values[2] * 300531L;
+            total += values[0] * 100532L + values[3] * 300532L;
+            total += values[1] * 100533L + values[4] * 300533L;
+            total += values[2] * 100534L + values[5] * 300534L;
+            total += values[3] * 100535L + values[6] * 300535L;
+            total += values[4] * 100536L + values[0] * 300536L;
+            total += values[5] * 100537L + values[1] * 300537L;
+            total += values[6] * 100538L + values[2] * 300538L;
+            total += values[0] * 100539L + values[3] * 300539L;
+            total += values[1] * 100540L + values[4] * 300540L;
+            total += values[2] * 100541L + values[5] * 300541L;
+            total += values[3] * 100542L + values[6] * 300542L;
+            total += values[4] * 100543L + values[0] * 300543L;
+            total += values[5] * 100544L + values[1] * 300544L;
+            total += values[6] * 100545L + values[2] * 300545L;
+            total += values[0] * 100546L + values[3] * 300546L;
+            total += values[1] * 100547L + values[4] * 300547L;
+            total += values[2] * 100548L + values[5] * 300548L;
+            total += values[3] * 100549L + values[6] * 300549L;
+            total += values[4] * 100550L + values[0] * 300550L;
+            total += values[5] * 100551L + values[1] * 300551L;
+            total += values[6] * 100552L + values[2] * 300552L;
+            total += values[0] * 100553L + values[3] * 300553L;
+            total += values[1] * 100554L + values[4] * 300554L;
+            total += values[2] * 100555L + values[5] * 300555L;
+            total += values[3] * 100556L + values[6] * 300556L;
+            total += values[4] * 100557L + values[0] * 300557L;
+            total += values[5] * 100558L + values[1] * 300558L;
+            total += values[6] * 100559L + values[2] * 300559L;
+            total += values[0] * 100560L + values[3] * 300560L;
+            total += values[1] * 100561L + values[4] * 300561L;
+            total += values[2] * 100562L + values[5] * 300562L;
+            total += values[3] * 100563L + values[6] * 300563L;
+            total += values[4] * 100564L + values[0] * 300564L;
+            total += values[5] * 100565L + values[1] * 300565L;
+            total += values[6] * 100566L + values[2] * 300566L;
+            total += values[0] * 100567L + values[3] * 300567L;
+            total += values[1] * 100568L + values[4] * 300568L;
+            total += values[2] * 100569L + values[5] * 300569L;
+            total += values[3] * 100570L + values[6] * 300570L;
+            total += values[4] * 100571L + values[0] * 300571L;
+            total += values[5] * 100572L + values[1] * 300572L;
+            total += values[6] * 100573L + values[2] * 300573L;
+            total += values[0] * 100574L + values[3] * 300574L;
+            total += values[1] * 100575L + values[4] * 300575L;
+            total += values[2] * 100576L + values[5] * 300576L;
+            total += values[3] * 100577L + values[6] * 300577L;
+            total += values[4] * 100578L + values[0] * 300578L;
+            total += values[5] * 100579L + values[1] * 300579L;
+            total += values[6] * 100580L + values[2] * 300580L;
+            total += values[0] * 100581L + values[3] * 300581L;
+            total += values[1] * 100582L + values[4] * 300582L;
+            total += values[2] * 100583L + values[5] * 300583L;
+            total += values[3] * 100584L + values[6] * 300584L;
+            total += values[4] * 100585L + values[0] * 300585L;
+            total += values[5] * 100586L + values[1] * 300586L;
+            total += values[6] * 100587L + values[2] * 300587L;
+            total += values[0] * 100588L + values[3] * 300588L;
+            total += values[1] * 100589L + values[4] * 300589L;
+            total += values[2] * 100590L + values[5] * 300590L;
+            total += values[3] * 100591L + values[6] * 300591L;
+            total += values[4] * 100592L + values[0] * 300592L;
+            total += values[5] * 100593L + values[1] * 300593L;
+            total += values[6] * 100594L + values[2] * 300594L;
+            total += values[0] * 100595L + values[3] * 300595L;
+            total += values[1] * 100596L + values[4] * 300596L;
+            total += values[2] * 100597L + values[5] * 300597L;
+            total += values[3] * 100598L + values[6] * 300598L;
+            total += values[4] * 100599L + values[0] * 300599L;
+            total += values[5] * 100600L + values[1] * 300600L;
+            total += values[6] * 100601L + values[2] * 300601L;
+            total += values[0] * 100602L + values[3] * 300602L;
+            total += values[1] * 100603L + values[4] * 300603L;
+            total += values[2] * 100604L + values[5] * 300604L;
+            total += values[3] * 100605L + values[6] * 300605L;
+            total += values[4] * 100606L + values[0] * 300606L;
+            total += values[5] * 100607L + values[1] * 300607L;
+            total += values[6] * 100608L + values[2] * 300608L;
+            total += values[0] * 100609L + values[3] * 300609L;
+            total += values[1] * 100610L + values[4] * 300610L;
+            total += values[2] * 100611L + values[5] * 300611L;
+            total += values[3] * 100612L + values[6] * 300612L;
+            total += values[4] * 100613L + values[0] * 300613L;
+            total += values[5] * 100614L + values[1] * 300614L;
+            total += values[6] * 100615L + values[2] * 300615L;
+            total += values[0] * 100616L + values[3] * 300616L;
+            total += values[1] * 100617L + values[4] * 300617L;
+            total += values[2] * 100618L + values[5] * 300618L;
+            total += values[3] * 100619L + values[6] * 300619L;
+            total += values[4] * 100620L + values[0] * 300620L;
+            total += values[5] * 100621L + values[1] * 300621L;
+            total += values[6] * 100622L + values[2] * 300622L;
+            total += values[0] * 100623L + values[3] * 300623L;
+            total += values[1] * 100624L + values[4] * 300624L;
+            total += values[2] * 100625L + values[5] * 300625L;
+            total += values[3] * 100626L + values[6] * 300626L;
+            total += values[4] * 100627L + values[0] * 300627L;
+            total += values[5] * 100628L + values[1] * 300628L;
+            total += values[6] * 100629L + values[2] * 300629L;
+            total += values[0] * 100630L + values[3] * 300630L;
+            total += values[1] * 100631L + values[4] * 300631L;
+            total += values[2] * 100632L + values[5] * 300632L;
+            total += values[3] * 100633L + values[6] * 300633L;
+            total += values[4] * 100634L + values[0] * 300634L;
+            total += values[5] * 100635L + values[1] * 300635L;
+            total += values[6] * 100636L + values[2] * 300636L;
+            total += values[0] * 100637L + values[3] * 300637L;
+            total += values[1] * 100638L + values[4] * 300638L;
+            total += values[2] * 100639L + values[5] * 300639L;
+            total += values[3] * 100640L + values[6] * 300640L;
+            total += values[4] * 100641L + values[0] * 300641L;
+            total += values[5] * 100642L + values[1] * 300642L;
+            total += values[6] * 100643L + values[2] * 300643L;
+            total += values[0] * 100644L + values[3] * 300644L;
+            total += values[1] * 100645L + values[4] * 300645L;
+            total += values[2] * 100646L + values[5] * 300646L;
+            total += values[3] * 100647L + values[6] * 300647L;
+            total += values[4] * 100648L + values[0] * 300648L;
+            total += values[5] * 100649L + values[1] * 300649L;
+            total += values[6] * 100650L + values[2] * 300650L;
+            total += values[0] * 100651L + values[3] * 300651L;
+            total += values[1] * 100652L + values[4] * 300652L;
+            total += values[2] * 100653L + values[5] * 300653L;
+            total += values[3] * 100654L + values[6] * 300654L;
+            total += values[4] * 100655L + values[0] * 300655L;
+            total += values[5] * 100656L + values[1] * 300656L;
+            total += values[6] * 100657L + values[2] * 300657L;
+            total += values[0] * 100658L + values[3] * 300658L;
+            total += values[1] * 100659L + values[4] * 300659L;
+            total += values[2] * 100660L + values[5] * 300660L;
+            total += values[3] * 100661L + values[6] * 300661L;
+            total += values[4] * 100662L + values[0] * 300662L;
+            total += values[5] * 100663L + values[1] * 300663L;
+            total += values[6] * 100664L + values[2] * 300664L;
+            total += values[0] * 100665L + values[3] * 300665L;
+            total += values[1] * 100666L + values[4] * 300666L;
+            total += values[2] * 100667L + values[5] * 300667L;
+            total += values[3] * 100668L + values[6] * 300668L;
+            total += values[4] * 100669L + values[0] * 300669L;
+            total += values[5] * 100670L + values[1] * 300670L;
+            total += values[6] * 100671L + values[2] * 300671L;
+            total += values[0] * 100672L + values[3] * 300672L;
+            total += values[1] * 100673L + values[4] * 300673L;
+            total += values[2] * 100674L + values[5] * 300674L;
+            total += values[3] * 100675L + values[6] * 300675L;
+            total += values[4] * 100676L + values[0] * 300676L;
+            total += values[5] * 100677L + values[1] * 300677L;
+            total += values[6] * 100678L + values[2] * 300678L;
+            total += values[0] * 100679L + values[3] * 300679L;
+            total += values[1] * 100680L + values[4] * 300680L;
+            total += values[2] * 100681L + values[5] * 300681L;
+            total += values[3] * 100682L + values[6] * 300682L;
+            total += values[4] * 100683L + values[0] * 300683L;
+            total += values[5] * 100684L + values[1] * 300684L;
+            total += values[6] * 100685L + values[2] * 300685L;
+            total += values[0] * 100686L + values[3] * 300686L;
+            total += values[1] * 100687L + values[4] * 300687L;
+            total += values[2] * 100688L + values[5] * 300688L;
+            total += values[3] * 100689L + values[6] * 300689L;
+            total += values[4] * 100690L + values[0] * 300690L;
+            total += values[5] * 100691L + values[1] * 300691L;
+            total += values[6] * 100692L + values[2] * 300692L;
+            total += values[0] * 100693L + values[3] * 300693L;
+            total += values[1] * 100694L + values[4] * 300694L;
+            total += values[2] * 100695L + values[5] * 300695L;
+            total += values[3] * 100696L + values[6] * 300696L;
+            total += values[4] * 100697L + values[0] * 300697L;
+            total += values[5] * 100698L + values[1] * 300698L;
+            total += values[6] * 100699L + values[2] * 300699L;
+            total += values[0] * 100700L + values[3] * 300700L;
+            total += values[1] * 100701L + values[4] * 300701L;
+            total += values[2] * 100702L + values[5] * 300702L;
+            total += values[3] * 100703L + values[6] * 300703L;
+            total += values[4] * 100704L + values[0] * 300704L;
+            total += values[5] * 100705L + values[1] * 300705L;
+            total += values[6] * 100706L + values[2] * 300706L;
+            total += values[0] * 100707L + values[3] * 300707L;
+            total += values[1] * 100708L + values[4] * 300708L;
+            total += values[2] * 100709L + values[5] * 300709L;
+            total += values[3] * 100710L + values[6] * 300710L;
+            total += values[4] * 100711L + values[0] * 300711L;
+            total += values[5] * 100712L + values[1] * 300712L;
+            total += values[6] * 100713L + values[2] * 300713L;
+            total += values[0] * 100714L + values[3] * 300714L;
+            total += values[1] * 100715L + values[4] * 300715L;
+            total += values[2] * 100716L + values[5] * 300716L;
+            total += values[3] * 100717L + values[6] * 300717L;
+            total += values[4] * 100718L + values[0] * 300718L;
+            total += values[5] * 100719L + values[1] * 300719L;
+            total += values[6] * 100720L + values[2] * 300720L;
+            total += values[0] * 100721L + values[3] * 300721L;
+            total += values[1] * 100722L + values[4] * 300722L;
+            total += values[2] * 100723L + values[5] * 300723L;
+            total += values[3] * 100724L + values[6] * 300724L;
+            total += values[4] * 100725L + values[0] * 300725L;
+            total += values[5] * 100726L + values[1] * 300726L;
+            total += values[6] * 100727L + values[2] * 300727L;
+            total += values[0] * 100728L + values[3] * 300728L;
+            total += values[1] * 100729L + values[4] * 300729L;
+            total += values[2] * 100730L + values[5] * 300730L;
+            total += values[3] * 100731L + values[6] * 300731L;
+            total += values[4] * 100732L + values[0] * 300732L;
+            total += values[5] * 100733L + values[1] * 300733L;
+            total += values[6] * 100734L + values[2] * 300734L;
+            total += values[0] * 100735L + values[3] * 300735L;
+            total += values[1] * 100736L + values[4] * 300736L;
+            total += values[2] * 100737L + values[5] * 300737L;
+            total += values[3] * 100738L + values[6] * 300738L;
+            total += values[4] * 100739L + values[0] * 300739L;
+            total += values[5] * 100740L + values[1] * 300740L;
+            total += values[6] * 100741L + values[2] * 300741L;
+            total += values[0] * 100742L + values[3] * 300742L;
+            total += values[1] * 100743L + values[4] * 300743L;
+            total += values[2] * 100744L + values[5] * 300744L;
+            total += values[3] * 100745L + values[6] * 300745L;
+            total += values[4] * 100746L + values[0] * 300746L;
+            total += values[5] * 100747L + values[1] * 300747L;
+            total += values[6] * 100748L + values[2] * 300748L;
+            total += values[0] * 100749L + values[3] * 300749L;
+            total += values[1] * 100750L + values[4] * 300750L;
+            total += values[2] * 100751L + values[5] * 300751L;
+            total += values[3] * 100752L + values[6] * 300752L;
+            total += values[4] * 100753L + values[0] * 300753L;
+            total += values[5] * 100754L + values[1] * 300754L;
+            total += values[6] * 100755L + values[2] * 300755L;
+            total += values[0] * 100756L + values[3] * 300756L;
+            total += values[1] * 100757L + values[4] * 300757L;
+            total += values[2] * 100758L + values[5] * 300758L;
+            total += values[3] * 100759L + values[6] * 300759L;
+            total += values[4] * 100760L + values[0] * 300760L;
+            total += values[5] * 100761L + values[1] * 300761L;
+            total += values[6] * 100762L + values[2] * 300762L;
+            total += values[0] * 100763L + values[3] * 300763L;
+            total += values[1] * 100764L + values[4] * 300764L;
+            total += values[2] * 100765L + values[5] * 300765L;
+            total += values[3] * 100766L + values[6] * 300766L;
+            total += values[4] * 100767L + values[0] * 300767L;
+            total += values[5] * 100768L + values[1] * 300768L;
+            total += values[6] * 100769L + values[2] * 300769L;
+            total += values[0] * 100770L + values[3] * 300770L;
+            total += values[1] * 100771L + values[4] * 300771L;
+            total += values[2] * 100772L + values[5] * 300772L;
+            total += values[3] * 100773L + values[6] * 300773L;
+            total += values[4] * 100774L + values[0] * 300774L;
+            total += values[5] * 100775L + values[1] * 300775L;
+            total += values[6] * 100776L + values[2] * 300776L;
+            total += values[0] * 100777L + values[3] * 300777L;
+            total += values[1] * 100778L + values[4] * 300778L;
+            total += values[2] * 100779L + values[5] * 300779L;
+            total += values[3] * 100780L + values[6] * 300780L;
+            total += values[4] * 100781L + values[0] * 300781L;
+            total += values[5] * 100782L + values[1] * 300782L;
+            total += values[6] * 100783L + values[2] * 300783L;
+            total += values[0] * 100784L + values[3] * 300784L;
+            total += values[1] * 100785L + values[4] * 300785L;
+            total += values[2] * 100786L + values[5] * 300786L;
+            total += values[3] * 100787L + values[6] * 300787L;
+            total += values[4] * 100788L + values[0] * 300788L;
+            total += values[5] * 100789L + values[1] * 300789L;
+            total += values[6] * 100790L + values[2] * 300790L;
+            total += values[0] * 100791L + values[3] * 300791L;
+            total += values[1] * 100792L + values[4] * 300792L;
+            total += values[2] * 100793L + values[5] * 300793L;
+            total += values[3] * 100794L + values[6] * 300794L;
+            total += values[4] * 100795L + values[0] * 300795L;
+            total += values[5] * 100796L + values[1] * 300796L;
+            total += values[6] * 100797L + values[2] * 300797L;
+            total += values[0] * 100798L + values[3] * 300798L;
+            total += values[1] * 100799L + values[4] * 300799L;
+            total += values[2] * 100800L + values[5] * 300800L;
+            total += values[3] * 100801L + values[6] * 300801L;
+            total += values[4] * 100802L + values[0] * 300802L;
+            total += values[5] * 100803L + values[1] * 300803L;
+            total += values[6] * 100804L + values[2] * 300804L;
+            total += values[0] * 100805L + values[3] * 300805L;
+            total += values[1] * 100806L + values[4] * 300806L;
+            total += values[2] * 100807L + values[5] * 300807L;
+            total += values[3] * 100808L + values[6] * 300808L;
+            total += values[4] * 100809L + values[0] * 300809L;
+            total += values[5] * 100810L + values[1] * 300810L;
+            total += values[6] * 100811L + values[2] * 300811L;
+            total += values[0] * 100812L + values[3] * 300812L;
+            total += values[1] * 100813L + values[4] * 300813L;
+            total += values[2] * 100814L + values[5] * 300814L;
+            total += values[3] * 100815L + values[6] * 300815L;
+            total += values[4] * 100816L + values[0] * 300816L;
+            total += values[5] * 100817L + values[1] * 300817L;
+            total += values[6] * 100818L + values[2] * 300818L;
+            total += values[0] * 100819L + values[3] * 300819L;
+            total += values[1] * 100820L + values[4] * 300820L;
+            total += values[2] * 100821L + values[5] * 300821L;
+            total += values[3] * 100822L + values[6] * 300822L;
+            total += values[4] * 100823L + values[0] * 300823L;
+            total += values[5] * 100824L + values[1] * 300824L;
+            total += values[6] * 100825L + values[2] * 300825L;
+            total += values[0] * 100826L + values[3] * 300826L;
+            total += values[1] * 100827L + values[4] * 300827L;
+            total += values[2] * 100828L + values[5] * 300828L;
+            total += values[3] * 100829L + values[6] * 300829L;
+            total += values[4] * 100830L + values[0] * 300830L;
+            total += values[5] * 100831L + values[1] * 300831L;
+            total += values[6] * 100832L + values[2] * 300832L;
+            total += values[0] * 100833L + values[3] * 300833L;
+            total += values[1] * 100834L + values[4] * 300834L;
+            total += values[2] * 100835L + values[5] * 300835L;
+            total += values[3] * 100836L + values[6] * 300836L;
+            total += values[4] * 100837L + values[0] * 300837L;
+            total += values[5] * 100838L + values[1] * 300838L;
+            total += values[6] * 100839L + values[2] * 300839L;
+            total += values[0] * 100840L + values[3] * 300840L;
+            total += values[1] * 100841L + values[4] * 300841L;
+            total += values[2] * 100842L + values[5] * 300842L;
+            total += values[3] * 100843L + values[6] * 300843L;
+            total += values[4] * 100844L + values[0] * 300844L;
+            total += values[5] * 100845L + values[1] * 300845L;
+            total += values[6] * 100846L + values[2] * 300846L;
+            total += values[0] * 100847L + values[3] * 300847L;
+            total += values[1] * 100848L + values[4] * 300848L;
+            total += values[2] * 100849L + values[5] * 300849L;
+            total += values[3] * 100850L + values[6] * 300850L;
+            total += values[4] * 100851L + values[0] * 300851L;
+            total += values[5] * 100852L + values[1] * 300852L;
+            total += values[6] * 100853L + values[2] * 300853L;
+            total += values[0] * 100854L + values[3] * 300854L;
+            total += values[1] * 100855L + values[4] * 300855L;
+            total += values[2] * 100856L + values[5] * 300856L;
+            total += values[3] * 100857L + values[6] * 300857L;
+            total += values[4] * 100858L + values[0] * 300858L;
+            total += values[5] * 100859L + values[1] * 300859L;
+            total += values[6] * 100860L + values[2] * 300860L;
+            total += values[0] * 100861L + values[3] * 300861L;
+            total += values[1] * 100862L + values[4] * 300862L;
+            total += values[2] * 100863L + values[5] * 300863L;
+            total += values[3] * 100864L + values[6] * 300864L;
+            total += values[4] * 100865L + values[0] * 300865L;
+            total += values[5] * 100866L + values[1] * 300866L;
+            total += values[6] * 100867L + values[2] * 300867L;
+            total += values[0] * 100868L + values[3] * 300868L;
+            total += values[1] * 100869L + values[4] * 300869L;
+            total += values[2] * 100870L + values[5] * 300870L;
+            total += values[3] * 100871L + values[6] * 300871L;
+            total += values[4] * 100872L + values[0] * 300872L;
+            total += values[5] * 100873L + values[1] * 300873L;
+            total += values[6] * 100874L + values[2] * 300874L;
+            total += values[0] * 100875L + values[3] * 300875L;
+            total += values[1] * 100876L + values[4] * 300876L;
+            total += values[2] * 100877L + values[5] * 300877L;
+            total += values[3] * 100878L + values[6] * 300878L;
+            total += values[4] * 100879L + values[0] * 300879L;
+            total += values[5] * 100880L + values[1] * 300880L;
+            total += values[6] * 100881L + values[2] * 300881L;
+            total += values[0] * 100882L + values[3] * 300882L;
+            total += values[1] * 100883L + values[4] * 300883L;
+            total += values[2] * 100884L + values[5] * 300884L;
+            total += values[3] * 100885L + values[6] * 300885L;
+            total += values[4] * 100886L + values[0] * 300886L;
+            total += values[5] * 100887L + values[1] * 300887L;
+            total += values[6] * 100888L + values[2] * 300888L;
+            total += values[0] * 100889L + values[3] * 300889L;
+            total += values[1] * 100890L + values[4] * 300890L;
+            total += values[2] * 100891L + values[5] * 300891L;
+            total += values[3] * 100892L + values[6] * 300892L;
+            total += values[4] * 100893L + values[0] * 300893L;
+            total += values[5] * 100894L + values[1] * 300894L;
+            total += values[6] * 100895L + values[2] * 300895L;
+            total += values[0] * 100896L + values[3] * 300896L;
+            total += values[1] * 100897L + values[4] * 300897L;
+            total += values[2] * 100898L + values[5] * 300898L;
+            total += values[3] * 100899L + values[6] * 300899L;
+            total += values[4] * 100900L + values[0] * 300900L;
+            total += values[5] * 100901L + values[1] * 300901L;
+            total += values[6] * 100902L + values[2] * 300902L;
+            total += values[0] * 100903L + values[3] * 300903L;
+            total += values[1] * 100904L + values[4] * 300904L;
+            total += values[2] * 100905L + values[5] * 300905L;
+            total += values[3] * 100906L + values[6] * 300906L;
+            total += values[4] * 100907L + values[0] * 300907L;
+            total += values[5] * 100908L + values[1] * 300908L;
+            total += values[6] * 100909L + values[2] * 300909L;
+            total += values[0] * 100910L + values[3] * 300910L;
+            total += values[1] * 100911L + values[4] * 300911L;
+            total += values[2] * 100912L + values[5] * 300912L;
+            total += values[3] * 100913L + values[6] * 300913L;
+            total += values[4] * 100914L + values[0] * 300914L;
+            total += values[5] * 100915L + values[1] * 300915L;
+            total += values[6] * 100916L + values[2] * 300916L;
+            total += values[0] * 100917L + values[3] * 300917L;
+            total += values[1] * 100918L + values[4] * 300918L;
+            total += values[2] * 100919L + values[5] * 300919L;
+            total += values[3] * 100920L + values[6] * 300920L;
+            total += values[4] * 100921L + values[0] * 300921L;
+            total += values[5] * 100922L + values[1] * 300922L;
+            total += values[6] * 100923L + values[2] * 300923L;
+            total += values[0] * 100924L + values[3] * 300924L;
+            total += values[1] * 100925L + values[4] * 300925L;
+            total += values[2] * 100926L + values[5] * 300926L;
+            total += values[3] * 100927L + values[6] * 300927L;
+            total += values[4] * 100928L + values[0] * 300928L;
+            total += values[5] * 100929L + values[1] * 300929L;
+            total += values[6] * 100930L + values[2] * 300930L;
+            total += values[0] * 100931L + values[3] * 300931L;
+            total += values[1] * 100932L + values[4] * 300932L;
+            total += values[2] * 100933L + values[5] * 300933L;
+            total += values[3] * 100934L + values[6] * 300934L;
+            total += values[4] * 100935L + values[0] * 300935L;
+            total += values[5] * 100936L + values[1] * 300936L;
+            total += values[6] * 100937L + values[2] * 300937L;
+            total += values[0] * 100938L + values[3] * 300938L;
+            total += values[1] * 100939L + values[4] * 300939L;
+            total += values[2] * 100940L + values[5] * 300940L;
+            total += values[3] * 100941L + values[6] * 300941L;
+            total += values[4] * 100942L + values[0] * 300942L;
+            total += values[5] * 100943L + values[1] * 300943L;
+            total += values[6] * 100944L + values[2] * 300944L;
+            total += values[0] * 100945L + values[3] * 300945L;
+            total += values[1] * 100946L + values[4] * 300946L;
+            total += values[2] * 100947L + values[5] * 300947L;
+            total += values[3] * 100948L + values[6] * 300948L;
+            total += values[4] * 100949L + values[0] * 300949L;
+            total += values[5] * 100950L + values[1] * 300950L;
+            total += values[6] * 100951L + values[2] * 300951L;
+            total += values[0] * 100952L + values[3] * 300952L;
+            total += values[1] * 100953L + values[4] * 300953L;
+            total += values[2] * 100954L + values[5] * 300954L;
+            total += values[3] * 100955L + values[6] * 300955L;
+            total += values[4] * 100956L + values[0] * 300956L;
+            total += values[5] * 100957L + values[1] * 300957L;
+            total += values[6] * 100958L + values[2] * 300958L;
+            total += values[0] * 100959L + values[3] * 300959L;
+            total += values[1] * 100960L + values[4] * 300960L;
+            total += values[2] * 100961L + values[5] * 300961L;
+            total += values[3] * 100962L + values[6] * 300962L;
+            total += values[4] * 100963L + values[0] * 300963L;
+            total += values[5] * 100964L + values[1] * 300964L;
+            total += values[6] * 100965L + values[2] * 300965L;
+            total += values[0] * 100966L + values[3] * 300966L;
+            total += values[1] * 100967L + values[4] * 300967L;
+            total += values[2] * 100968L + values[5] * 300968L;
+            total += values[3] * 100969L + values[6] * 300969L;
+            total += values[4] * 100970L + values[0] * 300970L;
+            total += values[5] * 100971L + values[1] * 300971L;
+            total += values[6] * 100972L + values[2] * 300972L;
+            total += values[0] * 100973L + values[3] * 300973L;
+            total += values[1] * 100974L + values[4] * 300974L;
+            total += values[2] * 100975L + values[5] * 300975L;
+            total += values[3] * 100976L + values[6] * 300976L;
+            total += values[4] * 100977L + values[0] * 300977L;
+            total += values[5] * 100978L + values[1] * 300978L;
+            total += values[6] * 100979L + values[2] * 300979L;
+            total += values[0] * 100980L + values[3] * 300980L;
+            total += values[1] * 100981L + values[4] * 300981L;
+            total += values[2] * 100982L + values[5] * 300982L;
+            total += values[3] * 100983L + values[6] * 300983L;
+            total += values[4] * 100984L + values[0] * 300984L;
+            total += values[5] * 100985L + values[1] * 300985L;
+            total += values[6] * 100986L + values[2] * 300986L;
+            total += values[0] * 100987L + values[3] * 300987L;
+            total += values[1] * 100988L + values[4] * 300988L;
+            total += values[2] * 100989L + values[5] * 300989L;
+            total += values[3] * 100990L + values[6] * 300990L;
+            total += values[4] * 100991L + values[0] * 300991L;
+            total += values[5] * 100992L + values[1] * 300992L;
+            total += values[6] * 100993L + values[2] * 300993L;
+            total += values[0] * 100994L + values[3] * 300994L;
+            total += values[1] * 100995L + values[4] * 300995L;
+            total += values[2] * 100996L + values[5] * 300996L;
+            total += values[3] * 100997L + values[6] * 300997L;
+            total += values[4] * 100998L + values[0] * 300998L;
+            total += values[5] * 100999L + values[1] * 300999L;
+            total += values[6] * 101000L + values[2] * 301000L;
+            total += values[0] * 101001L + values[3] * 301001L;
+            total += values[1] * 101002L + values[4] * 301002L;
+            total += values[2] * 101003L + values[5] * 301003L;
+            total += values[3] * 101004L + values[6] * 301004L;
+            total += values[4] * 101005L + values[0] * 301005L;
+            total += values[5] * 101006L + values[1] * 301006L;
+            total += values[6] * 101007L + values[2] * 301007L;
+            total += values[0] * 101008L + values[3] * 301008L;
+            total += values[1] * 101009L + values[4] * 301009L;
+            total += values[2] * 101010L + values[5] * 301010L;
+            total += values[3] * 101011L + values[6] * 301011L;
+            total += values[4] * 101012L + values[0] * 301012L;
+            total += values[5] * 101013L + values[1] * 301013L;
+            total += values[6] * 101014L + values[2] * 301014L;
+            total += values[0] * 101015L + values[3] * 301015L;
+            total += values[1] * 101016L + values[4] * 301016L;
+            total += values[2] * 101017L + values[5] * 301017L;
+            total += values[3] * 101018L + values[6] * 301018L;
+            total += values[4] * 101019L + values[0] * 301019L;
+            total += values[5] * 101020L + values[1] * 301020L;
+            total += values[6] * 101021L + values[2] * 301021L;
+            total += values[0] * 101022L + values[3] * 301022L;
+            total += values[1] * 101023L + values[4] * 301023L;
+            total += values[2] * 101024L + values[5] * 301024L;
+            total += values[3] * 101025L + values[6] * 301025L;
+            total += values[4] * 101026L + values[0] * 301026L;
+            total += values[5] * 101027L + values[1] * 301027L;
+            total += values[6] * 101028L + values[2] * 301028L;
+            total += values[0] * 101029L + values[3] * 301029L;
+            total += values[1] * 101030L + values[4] * 301030L;
+            total += values[2] * 101031L + values[5] * 301031L;
+            total += values[3] * 101032L + values[6] * 301032L;
+            total += values[4] * 101033L + values[0] * 301033L;
+            total += values[5] * 101034L + values[1] * 301034L;
+            total += values[6] * 101035L + values[2] * 301035L;
+            total += values[0] * 101036L + values[3] * 301036L;
+            total += values[1] * 101037L + values[4] * 301037L;
+            total += values[2] * 101038L + values[5] * 301038L;
+            total += values[3] * 101039L + values[6] * 301039L;
+            total += values[4] * 101040L + values[0] * 301040L;
+            total += values[5] * 101041L + values[1] * 301041L;
+            total += values[6] * 101042L + values[2] * 301042L;
+            total += values[0] * 101043L + values[3] * 301043L;
+            total += values[1] * 101044L + values[4] * 301044L;
+            total += values[2] * 101045L + values[5] * 301045L;
+            total += values[3] * 101046L + values[6] * 301046L;
+            total += values[4] * 101047L + values[0] * 301047L;
+            total += values[5] * 101048L + values[1] * 301048L;
+            total += values[6] * 101049L + values[2] * 301049L;
+            total += values[0] * 101050L + values[3] * 301050L;
+            total += values[1] * 101051L + values[4] * 301051L;
+            total += values[2] * 101052L + values[5] * 301052L;
+            total += values[3] * 101053L + values[6] * 301053L;
+            total += values[4] * 101054L + values[0] * 301054L;
+            total += values[5] * 101055L + values[1] * 301055L;
+            total += values[6] * 101056L + values[2] * 301056L;
+            total += values[0] * 101057L + values[3] * 301057L;
+            total += values[1] * 101058L + values[4] * 301058L;
+            total += values[2] * 101059L + values[5] * 301059L;
+            total += values[3] * 101060L + values[6] * 301060L;
+            total += values[4] * 101061L + values[0] * 301061L;
+            total += values[5] * 101062L + values[1] * 301062L;
+            total += values[6] * 101063L + values[2] * 301063L;
+            total += values[0] * 101064L + values[3] * 301064L;
+            total += values[1] * 101065L + values[4] * 301065L;
+            total += values[2] * 101066L + values[5] * 301066L;
+            total += values[3] * 101067L + values[6] * 301067L;
+            total += values[4] * 101068L + values[0] * 301068L;
+            total += values[5] * 101069L + values[1] * 301069L;
+            total += values[6] * 101070L + values[2] * 301070L;
+            total += values[0] * 101071L + values[3] * 301071L;
+            total += values[1] * 101072L + values[4] * 301072L;
+            total += values[2] * 101073L + values[5] * 301073L;
+            total += values[3] * 101074L + values[6] * 301074L;
+            total += values[4] * 101075L + values[0] * 301075L;
+            total += values[5] * 101076L + values[1] * 301076L;
+            total += values[6] * 101077L + values[2] * 301077L;
+            total += values[0] * 101078L + values[3] * 301078L;
+            total += values[1] * 101079L + values[4] * 301079L;
+            total += values[2] * 101080L + values[5] * 301080L;
+            total += values[3] * 101081L + values[6] * 301081L;
+            total += values[4] * 101082L + values[0] * 301082L;
+            total += values[5] * 101083L + values[1] * 301083L;
+            total += values[6] * 101084L + values[2] * 301084L;
+            total += values[0] * 101085L + values[3] * 301085L;
+            total += values[1] * 101086L + values[4] * 301086L;
+            total += values[2] * 101087L + values[5] * 301087L;
+            total += values[3] * 101088L + values[6] * 301088L;
+            total += values[4] * 101089L + values[0] * 301089L;
+            total += values[5] * 101090L + values[1] * 301090L;
+            total += values[6] * 101091L + values[2] * 301091L;
+            total += values[0] * 101092L + values[3] * 301092L;
+            total += values[1] * 101093L + values[4] * 301093L;
+            total += values[2] * 101094L + values[5] * 301094L;
+            total += values[3] * 101095L + values[6] * 301095L;
+            total += values[4] * 101096L + values[0] * 301096L;
+            total += values[5] * 101097L + values[1] * 301097L;
+            total += values[6] * 101098L + values[2] * 301098L;
+            total += values[0] * 101099L + values[3] * 301099L;
+            total += values[1] * 101100L + values[4] * 301100L;
+            total += values[2] * 101101L + values[5] * 301101L;
+            total += values[3] * 101102L + values[6] * 301102L;
+            total += values[4] * 101103L + values[0] * 301103L;
+            total += values[5] * 101104L + values[1] * 301104L;
+            total += values[6] * 101105L + values[2] * 301105L;
+            total += values[0] * 101106L + values[3] * 301106L;
+            total += values[1] * 101107L + values[4] * 301107L;
+            total += values[2] * 101108L + values[5] * 301108L;
+            total += values[3] * 101109L + values[6] * 301109L;
+            total += values[4] * 101110L + values[0] * 301110L;
+            total += values[5] * 101111L + values[1] * 301111L;
+            total += values[6] * 101112L + values[2] * 301112L;
+            total += values[0] * 101113L + values[3] * 301113L;
+            total += values[1] * 101114L + values[4] * 301114L;
+            total += values[2] * 101115L + values[5] * 301115L;
+            total += values[3] * 101116L + values[6] * 301116L;
+            total += values[4] * 101117L + values[0] * 301117L;
+            total += values[5] * 101118L + values[1] * 301118L;
+            total += values[6] * 101119L + values[2] * 301119L;
+            total += values[0] * 101120L + values[3] * 301120L;
+            total += values[1] * 101121L + values[4] * 301121L;
+            total += values[2] * 101122L + values[5] * 301122L;
+            total += values[3] * 101123L + values[6] * 301123L;
+            total += values[4] * 101124L + values[0] * 301124L;
+            total += values[5] * 101125L + values[1] * 301125L;
+            total += values[6] * 101126L + values[2] * 301126L;
+            total += values[0] * 101127L + values[3] * 301127L;
+            total += values[1] * 101128L + values[4] * 301128L;
+            total += values[2] * 101129L + values[5] * 301129L;
+            total += values[3] * 101130L + values[6] * 301130L;
+            total += values[4] * 101131L + values[0] * 301131L;
+            total += values[5] * 101132L + values[1] * 301132L;
+            total += values[6] * 101133L + values[2] * 301133L;
+            total += values[0] * 101134L + values[3] * 301134L;
+            total += values[1] * 101135L + values[4] * 301135L;
+            total += values[2] * 101136L + values[5] * 301136L;
+            total += values[3] * 101137L + values[6] * 301137L;
+            total += values[4] * 101138L + values[0] * 301138L;
+            total += values[5] * 101139L + values[1] * 301139L;
+            total += values[6] * 101140L + values[2] * 301140L;
+            total += values[0] * 101141L + values[3] * 301141L;
+            total += values[1] * 101142L + values[4] * 301142L;
+            total += values[2] * 101143L + values[5] * 301143L;
+            total += values[3] * 101144L + values[6] * 301144L;
+            total += values[4] * 101145L + values[0] * 301145L;
+            total += values[5] * 101146L + values[1] * 301146L;
+            total += values[6] * 101147L + values[2] * 301147L;
+            total += values[0] * 101148L + values[3] * 301148L;
+            total += values[1] * 101149L + values[4] * 301149L;
+            total += values[2] * 101150L + values[5] * 301150L;
+            total += values[3] * 101151L + values[6] * 301151L;
+            total += values[4] * 101152L + values[0] * 301152L;
+            total += values[5] * 101153L + values[1] * 301153L;
+            total += values[6] * 101154L + values[2] * 301154L;
+            total += values[0] * 101155L + values[3] * 301155L;
+            total += values[1] * 101156L + values[4] * 301156L;
+            total += values[2] * 101157L + values[5] * 301157L;
+            total += values[3] * 101158L + values[6] * 301158L;
+            total += values[4] * 101159L + values[0] * 301159L;
+            total += values[5] * 101160L + values[1] * 301160L;
+            total += values[6] * 101161L + values[2] * 301161L;
+            total += values[0] * 101162L + values[3] * 301162L;
+            total += values[1] * 101163L + values[4] * 301163L;
+            total += values[2] * 101164L + values[5] * 301164L;
+            total += values[3] * 101165L + values[6] * 301165L;
+            total += values[4] * 101166L + values[0] * 301166L;
+            total += values[5] * 101167L + values[1] * 301167L;
+            total += values[6] * 101168L + values[2] * 301168L;
+            total += values[0] * 101169L + values[3] * 301169L;
+            total += values[1] * 101170L + values[4] * 301170L;
+            total += values[2] * 101171L + values[5] * 301171L;
+            total += values[3] * 101172L + values[6] * 301172L;
+            total += values[4] * 101173L + values[0] * 301173L;
+            total += values[5] * 101174L + values[1] * 301174L;
+            total += values[6] * 101175L + values[2] * 301175L;
+            total += values[0] * 101176L + values[3] * 301176L;
+            total += values[1] * 101177L + values[4] * 301177L;
+            total += values[2] * 101178L + values[5] * 301178L;
+            total += values[3] * 101179L + values[6] * 301179L;
+            total += values[4] * 101180L + values[0] * 301180L;
+            total += values[5] * 101181L + values[1] * 301181L;
+            total += values[6] * 101182L + values[2] * 301182L;
+            total += values[0] * 101183L + values[3] * 301183L;
+            total += values[1] * 101184L + values[4] * 301184L;
+            total += values[2] * 101185L + values[5] * 301185L;
+            total += values[3] * 101186L + values[6] * 301186L;
+            total += values[4] * 101187L + values[0] * 301187L;
+            total += values[5] * 101188L + values[1] * 301188L;
+            total += values[6] * 101189L + values[2] * 301189L;
+            total += values[0] * 101190L + values[3] * 301190L;
+            total += values[1] * 101191L + values[4] * 301191L;
+            total += values[2] * 101192L + values[5] * 301192L;
+            total += values[3] * 101193L + values[6] * 301193L;
+            total += values[4] * 101194L + values[0] * 301194L;
+            total += values[5] * 101195L + values[1] * 301195L;
+            total += values[6] * 101196L + values[2] * 301196L;
+            total += values[0] * 101197L + values[3] * 301197L;
+            total += values[1] * 101198L + values[4] * 301198L;
+            total += values[2] * 101199L + values[5] * 301199L;
+            total += values[3] * 101200L + values[6] * 301200L;
+            total += values[4] * 101201L + values[0] * 301201L;
+            total += values[5] * 101202L + values[1] * 301202L;
+            total += values[6] * 101203L + values[2] * 301203L;
+            total += values[0] * 101204L + values[3] * 301204L;
+            total += values[1] * 101205L + values[4] * 301205L;
+            total += values[2] * 101206L + values[5] * 301206L;
+            total += values[3] * 101207L + values[6] * 301207L;
+            total += values[4] * 101208L + values[0] * 301208L;
+            total += values[5] * 101209L + values[1] * 301209L;
+            total += values[6] * 101210L + values[2] * 301210L;
+            total += values[0] * 101211L + values[3] * 301211L;
+            total += values[1] * 101212L + values[4] * 301212L;
+            total += values[2] * 101213L + values[5] * 301213L;
+            total += values[3] * 101214L + values[6] * 301214L;
+            total += values[4] * 101215L + values[0] * 301215L;
+            total += values[5] * 101216L + values[1] * 301216L;
+            total += values[6] * 101217L + values[2] * 301217L;
+            total += values[0] * 101218L + values[3] * 301218L;
+            total += values[1] * 101219L + values[4] * 301219L;
+            total += values[2] * 101220L + values[5] * 301220L;
+            total += values[3] * 101221L + values[6] * 301221L;
+            total += values[4] * 101222L + values[0] * 301222L;
+            total += values[5] * 101223L + values[1] * 301223L;
+            total += values[6] * 101224L + values[2] * 301224L;
+            total += values[0] * 101225L + values[3] * 301225L;
+            total += values[1] * 101226L + values[4] * 301226L;
+            total += values[2] * 101227L + values[5] * 301227L;
+            total += values[3] * 101228L + values[6] * 301228L;
+            total += values[4] * 101229L + values[0] * 301229L;
+            total += values[5] * 101230L + values[1] * 301230L;
+            total += values[6] * 101231L + values[2] * 301231L;
+            total += values[0] * 101232L + values[3] * 301232L;
+            total += values[1] * 101233L + values[4] * 301233L;
+            total += values[2] * 101234L + values[5] * 301234L;
+            total += values[3] * 101235L + values[6] * 301235L;
+            total += values[4] * 101236L + values[0] * 301236L;
+            total += values[5] * 101237L + values[1] * 301237L;
+            total += values[6] * 101238L + values[2] * 301238L;
+            total += values[0] * 101239L + values[3] * 301239L;
+            total += values[1] * 101240L + values[4] * 301240L;
+            total += values[2] * 101241L + values[5] * 301241L;
+            total += values[3] * 101242L + values[6] * 301242L;
+            total += values[4] * 101243L + values[0] * 301243L;
+            total += values[5] * 101244L + values[1] * 301244L;
+            total += values[6] * 101245L + values[2] * 301245L;
+            total += values[0] * 101246L + values[3] * 301246L;
+            total += values[1] * 101247L + values[4] * 301247L;
+            total += values[2] * 101248L + values[5] * 301248L;
+            total += values[3] * 101249L + values[6] * 301249L;
+            total += values[4] * 101250L + values[0] * 301250L;
+            total += values[5] * 101251L + values[1] * 301251L;
+            total += values[6] * 101252L + values[2] * 301252L;
+            total += values[0] * 101253L + values[3] * 301253L;
+            total += values[1] * 101254L + values[4] * 301254L;
+            total += values[2] * 101255L + values[5] * 301255L;
+            total += values[3] * 101256L + values[6] * 301256L;
+            total += values[4] * 101257L + values[0] * 301257L;
+            total += values[5] * 101258L + values[1] * 301258L;
+            total += values[6] * 101259L + values[2] * 301259L;
+            total += values[0] * 101260L + values[3] * 301260L;
+            total += values[1] * 101261L + values[4] * 301261L;
+            total += values[2] * 101262L + values[5] * 301262L;
+            total += values[3] * 101263L + values[6] * 301263L;
+            total += values[4] * 101264L + values[0] * 301264L;
+            total += values[5] * 101265L + values[1] * 301265L;
+            total += values[6] * 101266L + values[2] * 301266L;
+            total += values[0] * 101267L + values[3] * 301267L;
+            total += values[1] * 101268L + values[4] * 301268L;
+            total += values[2] * 101269L + values[5] * 301269L;
+            total += values[3] * 101270L + values[6] * 301270L;
+            total += values[4] * 101271L + values[0] * 301271L;
+            total += values[5] * 101272L + values[1] * 301272L;
+            total += values[6] * 101273L + values[2] * 301273L;
+            total += values[0] * 101274L + values[3] * 301274L;
+            total += values[1] * 101275L + values[4] * 301275L;
+            total += values[2] * 101276L + values[5] * 301276L;
+            total += values[3] * 101277L + values[6] * 301277L;
+            total += values[4] * 101278L + values[0] * 301278L;
+            total += values[5] * 101279L + values[1] * 301279L;
+            total += values[6] * 101280L + values[2] * 301280L;
+            total += values[0] * 101281L + values[3] * 301281L;
+            total += values[1] * 101282L + values[4] * 301282L;
+            total += values[2] * 101283L + values[5] * 301283L;
+            total += values[3] * 101284L + values[6] * 301284L;
+            total += values[4] * 101285L + values[0] * 301285L;
+            total += values[5] * 101286L + values[1] * 301286L;
+            total += values[6] * 101287L + values[2] * 301287L;
+            total += values[0] * 101288L + values[3] * 301288L;
+            total += values[1] * 101289L + values[4] * 301289L;
+            total += values[2] * 101290L + values[5] * 301290L;
+            total += values[3] * 101291L + values[6] * 301291L;
+            total += values[4] * 101292L + values[0] * 301292L;
+            total += values[5] * 101293L + values[1] * 301293L;
+            total += values[6] * 101294L + values[2] * 301294L;
+            total += values[0] * 101295L + values[3] * 301295L;
+            total += values[1] * 101296L + values[4] * 301296L;
+            total += values[2] * 101297L + values[5] * 301297L;
+            total += values[3] * 101298L + values[6] * 301298L;
+            total += values[4] * 101299L + values[0] * 301299L;
+            total += values[5] * 101300L + values[1] * 301300L;
+            total += values[6] * 101301L + values[2] * 301301L;
+            total += values[0] * 101302L + values[3] * 301302L;
+            total += values[1] * 101303L + values[4] * 301303L;
+            total += values[2] * 101304L + values[5] * 301304L;
+            total += values[3] * 101305L + values[6] * 301305L;
+            total += values[4] * 101306L + values[0] * 301306L;
+            total += values[5] * 101307L + values[1] * 301307L;
+            total += values[6] * 101308L + values[2] * 301308L;
+            total += values[0] * 101309L + values[3] * 301309L;
+            total += values[1] * 101310L + values[4] * 301310L;
+            total += values[2] * 101311L + values[5] * 301311L;
+            total += values[3] * 101312L + values[6] * 301312L;
+            total += values[4] * 101313L + values[0] * 301313L;
+            total += values[5] * 101314L + values[1] * 301314L;
+            total += values[6] * 101315L + values[2] * 301315L;
+            total += values[0] * 101316L + values[3] * 301316L;
+            total += values[1] * 101317L + values[4] * 301317L;
+            total += values[2] * 101318L + values[5] * 301318L;
+            total += values[3] * 101319L + values[6] * 301319L;
+            total += values[4] * 101320L + values[0] * 301320L;
+            total += values[5] * 101321L + values[1] * 301321L;
+            total += values[6] * 101322L + values[2] * 301322L;
+            total += values[0] * 101323L + values[3] * 301323L;
+            total += values[1] * 101324L + values[4] * 301324L;
+            total += values[2] * 101325L + values[5] * 301325L;
+            total += values[3] * 101326L + values[6] * 301326L;
+            total += values[4] * 101327L + values[0] * 301327L;
+            total += values[5] * 101328L + values[1] * 301328L;
+            total += values[6] * 101329L + values[2] * 301329L;
+            total += values[0] * 101330L + values[3] * 301330L;
+            total += values[1] * 101331L + values[4] * 301331L;
+            total += values[2] * 101332L + values[5] * 301332L;
+            total += values[3] * 101333L + values[6] * 301333L;
+            total += values[4] * 101334L + values[0] * 301334L;
+            total += values[5] * 101335L + values[1] * 301335L;
+            total += values[6] * 101336L + values[2] * 301336L;
+            total += values[0] * 101337L + values[3] * 301337L;
+            total += values[1] * 101338L + values[4] * 301338L;
+            total += values[2] * 101339L + values[5] * 301339L;
+            total += values[3] * 101340L + values[6] * 301340L;
+            total += values[4] * 101341L + values[0] * 301341L;
+            total += values[5] * 101342L + values[1] * 301342L;
+            total += values[6] * 101343L + values[2] * 301343L;
+            total += values[0] * 101344L + values[3] * 301344L;
+            total += values[1] * 101345L + values[4] * 301345L;
+            total += values[2] * 101346L + values[5] * 301346L;
+            total += values[3] * 101347L + values[6] * 301347L;
+            total += values[4] * 101348L + values[0] * 301348L;
+            total += values[5] * 101349L + values[1] * 301349L;
+            total += values[6] * 101350L + values[2] * 301350L;
+            total += values[0] * 101351L + values[3] * 301351L;
+            total += values[1] * 101352L + values[4] * 301352L;
+            total += values[2] * 101353L + values[5] * 301353L;
+            total += values[3] * 101354L + values[6] * 301354L;
+            total += values[4] * 101355L + values[0] * 301355L;
+            total += values[5] * 101356L + values[1] * 301356L;
+            total += values[6] * 101357L + values[2] * 301357L;
+            total += values[0] * 101358L + values[3] * 301358L;
+            total += values[1] * 101359L + values[4] * 301359L;
+            total += values[2] * 101360L + values[5] * 301360L;
+            total += values[3] * 101361L + values[6] * 301361L;
+            total += values[4] * 101362L + values[0] * 301362L;
+            total += values[5] * 101363L + values[1] * 301363L;
+            total += values[6] * 101364L + values[2] * 301364L;
+            total += values[0] * 101365L + values[3] * 301365L;
+            total += values[1] * 101366L + values[4] * 301366L;
+            total += values[2] * 101367L + values[5] * 301367L;
+            total += values[3] * 101368L + values[6] * 301368L;
+            total += values[4] * 101369L + values[0] * 301369L;
+            total += values[5] * 101370L + values[1] * 301370L;
+            total += values[6] * 101371L + values[2] * 301371L;
+            total += values[0] * 101372L + values[3] * 301372L;
+            total += values[1] * 101373L + values[4] * 301373L;
+            total += values[2] * 101374L + values[5] * 301374L;
+            total += values[3] * 101375L + values[6] * 301375L;
+            total += values[4] * 101376L + values[0] * 301376L;
+            total += values[5] * 101377L + values[1] * 301377L;
+            total += values[6] * 101378L + values[2] * 301378L;
+            total += values[0] * 101379L + values[3] * 301379L;
+            total += values[1] * 101380L + values[4] * 301380L;
+            total += values[2] * 101381L + values[5] * 301381L;
+            total += values[3] * 101382L + values[6] * 301382L;
+            total += values[4] * 101383L + values[0] * 301383L;
+            total += values[5] * 101384L + values[1] * 301384L;
+            total += values[6] * 101385L + values[2] * 301385L;
+            total += values[0] * 101386L + values[3] * 301386L;
+            total += values[1] * 101387L + values[4] * 301387L;
+            total += values[2] * 101388L + values[5] * 301388L;
+            total += values[3] * 101389L + values[6] * 301389L;
+            total += values[4] * 101390L + values[0] * 301390L;
+            total += values[5] * 101391L + values[1] * 301391L;
+            total += values[6] * 101392L + values[2] * 301392L;
+            total += values[0] * 101393L + values[3] * 301393L;
+            total += values[1] * 101394L + values[4] * 301394L;
+            total += values[2] * 101395L + values[5] * 301395L;
+            total += values[3] * 101396L + values[6] * 301396L;
+            total += values[4] * 101397L + values[0] * 301397L;
+            total += values[5] * 101398L + values[1] * 301398L;
+            total += values[6] * 101399L + values[2] * 301399L;
+            total += values[0] * 101400L + values[3] * 301400L;
+            total += values[1] * 101401L + values[4] * 301401L;
+            total += values[2] * 101402L + values[5] * 301402L;
+            total += values[3] * 101403L + values[6] * 301403L;
+            total += values[4] * 101404L + values[0] * 301404L;
+            total += values[5] * 101405L + values[1] * 301405L;
+            total += values[6] * 101406L + values[2] * 301406L;
+            total += values[0] * 101407L + values[3] * 301407L;
+            total += values[1] * 101408L + values[4] * 301408L;
+            total += values[2] * 101409L + values[5] * 301409L;
+            total += values[3] * 101410L + values[6] * 301410L;
+            total += values[4] * 101411L + values[0] * 301411L;
+            total += values[5] * 101412L + values[1] * 301412L;
+            total += values[6] * 101413L + values[2] * 301413L;
+            total += values[0] * 101414L + values[3] * 301414L;
+            total += values[1] * 101415L + values[4] * 301415L;
+            total += values[2] * 101416L + values[5] * 301416L;
+            total += values[3] * 101417L + values[6] * 301417L;
+            total += values[4] * 101418L + values[0] * 301418L;
+            total += values[5] * 101419L + values[1] * 301419L;
+            total += values[6] * 101420L + values[2] * 301420L;
+            total += values[0] * 101421L + values[3] * 301421L;
+            total += values[1] * 101422L + values[4] * 301422L;
+            total += values[2] * 101423L + values[5] * 301423L;
+            total += values[3] * 101424L + values[6] * 301424L;
+            total += values[4] * 101425L + values[0] * 301425L;
+            total += values[5] * 101426L + values[1] * 301426L;
+            total += values[6] * 101427L + values[2] * 301427L;
+            total += values[0] * 101428L + values[3] * 301428L;
+            total += values[1] * 101429L + values[4] * 301429L;
+            total += values[2] * 101430L + values[5] * 301430L;
+            total += values[3] * 101431L + values[6] * 301431L;
+            total += values[4] * 101432L + values[0] * 301432L;
+            total += values[5] * 101433L + values[1] * 301433L;
+            total += values[6] * 101434L + values[2] * 301434L;
+            total += values[0] * 101435L + values[3] * 301435L;
+            total += values[1] * 101436L + values[4] * 301436L;
+            total += values[2] * 101437L + values[5] * 301437L;
+            total += values[3] * 101438L + values[6] * 301438L;
+            total += values[4] * 101439L + values[0] * 301439L;
+            total += values[5] * 101440L + values[1] * 301440L;
+            total += values[6] * 101441L + values[2] * 301441L;
+            total += values[0] * 101442L + values[3] * 301442L;
+            total += values[1] * 101443L + values[4] * 301443L;
+            total += values[2] * 101444L + values[5] * 301444L;
+            total += values[3] * 101445L + values[6] * 301445L;
+            total += values[4] * 101446L + values[0] * 301446L;
+            total += values[5] * 101447L + values[1] * 301447L;
+            total += values[6] * 101448L + values[2] * 301448L;
+            total += values[0] * 101449L + values[3] * 301449L;
+            total += values[1] * 101450L + values[4] * 301450L;
+            total += values[2] * 101451L + values[5] * 301451L;
+            total += values[3] * 101452L + values[6] * 301452L;
+            total += values[4] * 101453L + values[0] * 301453L;
+            total += values[5] * 101454L + values[1] * 301454L;
+            total += values[6] * 101455L + values[2] * 301455L;
+            total += values[0] * 101456L + values[3] * 301456L;
+            total += values[1] * 101457L + values[4] * 301457L;
+            total += values[2] * 101458L + values[5] * 301458L;
+            total += values[3] * 101459L + values[6] * 301459L;
+            total += values[4] * 101460L + values[0] * 301460L;
+            total += values[5] * 101461L + values[1] * 301461L;
+            total += values[6] * 101462L + values[2] * 301462L;
+            total += values[0] * 101463L + values[3] * 301463L;
+            total += values[1] * 101464L + values[4] * 301464L;
+            total += values[2] * 101465L + values[5] * 301465L;
+            total += values[3] * 101466L + values[6] * 301466L;
+            total += values[4] * 101467L + values[0] * 301467L;
+            total += values[5] * 101468L + values[1] * 301468L;
+            total += values[6] * 101469L + values[2] * 301469L;
+            total += values[0] * 101470L + values[3] * 301470L;
+            total += values[1] * 101471L + values[4] * 301471L;
+            total += values[2] * 101472L + values[5] * 301472L;
+            total += values[3] * 101473L + values[6] * 301473L;
+            total += values[4] * 101474L + values[0] * 301474L;
+            total += values[5] * 101475L + values[1] * 301475L;
+            total += values[6] * 101476L + values[2] * 301476L;
+            total += values[0] * 101477L + values[3] * 301477L;
+            total += values[1] * 101478L + values[4] * 301478L;
+            total += values[2] * 101479L + values[5] * 301479L;
+            total += values[3] * 101480L + values[6] * 301480L;
+            total += values[4] * 101481L + values[0] * 301481L;
+            total += values[5] * 101482L + values[1] * 301482L;
+            total += values[6] * 101483L + values[2] * 301483L;
+            total += values[0] * 101484L + values[3] * 301484L;
+            total += values[1] * 101485L + values[4] * 301485L;
+            total += values[2] * 101486L + values[5] * 301486L;
+            total += values[3] * 101487L + values[6] * 301487L;
+            total += values[4] * 101488L + values[0] * 301488L;
+            total += values[5] * 101489L + values[1] * 301489L;
+            total += values[6] * 101490L + values[2] * 301490L;
+            total += values[0] * 101491L + values[3] * 301491L;
+            total += values[1] * 101492L + values[4] * 301492L;
+            total += values[2] * 101493L + values[5] * 301493L;
+            total += values[3] * 101494L + values[6] * 301494L;
+            total += values[4] * 101495L + values[0] * 301495L;
+            total += values[5] * 101496L + values[1] * 301496L;
+            total += values[6] * 101497L + values[2] * 301497L;
+            total += values[0] * 101498L + values[3] * 301498L;
+            total += values[1] * 101499L + values[4] * 301499L;
+            total += values[2] * 101500L + values[5] * 301500L;
+            total += values[3] * 101501L + values[6] * 301501L;
+            total += values[4] * 101502L + values[0] * 301502L;
+            total += values[5] * 101503L + values[1] * 301503L;
+            total += values[6] * 101504L + values[2] * 301504L;
+            total += values[0] * 101505L + values[3] * 301505L;
+            total += values[1] * 101506L + values[4] * 301506L;
+            total += values[2] * 101507L + values[5] * 301507L;
+            total += values[3] * 101508L + values[6] * 301508L;
+            total += values[4] * 101509L + values[0] * 301509L;
+            total += values[5] * 101510L + values[1] * 301510L;
+            total += values[6] * 101511L + values[2] * 301511L;
+            total += values[0] * 101512L + values[3] * 301512L;
+            total += values[1] * 101513L + values[4] * 301513L;
+            total += values[2] * 101514L + values[5] * 301514L;
+            total += values[3] * 101515L + values[6] * 301515L;
+            total += values[4] * 101516L + values[0] * 301516L;
+            total += values[5] * 101517L + values[1] * 301517L;
+            total += values[6] * 101518L + values[2] * 301518L;
+            total += values[0] * 101519L + values[3] * 301519L;
+            total += values[1] * 101520L + values[4] * 301520L;
+            total += values[2] * 101521L + values[5] * 301521L;
+            total += values[3] * 101522L + values[6] * 301522L;
+            total += values[4] * 101523L + values[0] * 301523L;
+            total += values[5] * 101524L + values[1] * 301524L;
+            total += values[6] * 101525L + values[2] * 301525L;
+            total += values[0] * 101526L + values[3] * 301526L;
+            total += values[1] * 101527L + values[4] * 301527L;
+            total += values[2] * 101528L + values[5] * 301528L;
+            total += values[3] * 101529L + values[6] * 301529L;
+            total += values[4] * 101530L + values[0] * 301530L;
+            total += values[5] * 101531L + values[1] * 301531L;
+            total += values[6] * 101532L + values[2] * 301532L;
+            total += values[0] * 101533L + values[3] * 301533L;
+            total += values[1] * 101534L + values[4] * 301534L;
+            total += values[2] * 101535L + values[5] * 301535L;
+            total += values[3] * 101536L + values[6] * 301536L;
+            total += values[4] * 101537L + values[0] * 301537L;
+            total += values[5] * 101538L + values[1] * 301538L;
+            total += values[6] * 101539L + values[2] * 301539L;
+            total += values[0] * 101540L + values[3] * 301540L;
+            total += values[1] * 101541L + values[4] * 301541L;
+            total += values[2] * 101542L + values[5] * 301542L;
+            total += values[3] * 101543L + values[6] * 301543L;
+            total += values[4] * 101544L + values[0] * 301544L;
+            total += values[5] * 101545L + values[1] * 301545L;
+            total += values[6] * 101546L + values[2] * 301546L;
+            total += values[0] * 101547L + values[3] * 301547L;
+            total += values[1] * 101548L + values[4] * 301548L;
+            total += values[2] * 101549L + values[5] * 301549L;
+            total += values[3] * 101550L + values[6] * 301550L;
+            total += values[4] * 101551L + values[0] * 301551L;
+            total += values[5] * 101552L + values[1] * 301552L;
+            total += values[6] * 101553L + values[2] * 301553L;
+            total += values[0] * 101554L + values[3] * 301554L;
+            total += values[1] * 101555L + values[4] * 301555L;
+            total += values[2] * 101556L + values[5] * 301556L;
+            total += values[3] * 101557L + values[6] * 301557L;
+            total += values[4] * 101558L + values[0] * 301558L;
+            total += values[5] * 101559L + values[1] * 301559L;
+            total += values[6] * 101560L + values[2] * 301560L;
+            total += values[0] * 101561L + values[3] * 301561L;
+            total += values[1] * 101562L + values[4] * 301562L;
+            total += values[2] * 101563L + values[5] * 301563L;
+            total += values[3] * 101564L + values[6] * 301564L;
+            total += values[4] * 101565L + values[0] * 301565L;
+            total += values[5] * 101566L + values[1] * 301566L;
+            total += values[6] * 101567L + values[2] * 301567L;
+            total += values[0] * 101568L + values[3] * 301568L;
+            total += values[1] * 101569L + values[4] * 301569L;
+            total += values[2] * 101570L + values[5] * 301570L;
+            total += values[3] * 101571L + values[6] * 301571L;
+            total += values[4] * 101572L + values[0] * 301572L;
+            total += values[5] * 101573L + values[1] * 301573L;
+            total += values[6] * 101574L + values[2] * 301574L;
+            total += values[0] * 101575L + values[3] * 301575L;
+            total += values[1] * 101576L + values[4] * 301576L;
+            total += values[2] * 101577L + values[5] * 301577L;
+            total += values[3] * 101578L + values[6] * 301578L;
+            total += values[4] * 101579L + values[0] * 301579L;
+            total += values[5] * 101580L + values[1] * 301580L;
+            total += values[6] * 101581L + values[2] * 301581L;
+            total += values[0] * 101582L + values[3] * 301582L;
+            total += values[1] * 101583L + values[4] * 301583L;
+            total += values[2] * 101584L + values[5] * 301584L;
+            total += values[3] * 101585L + values[6] * 301585L;
+            total += values[4] * 101586L + values[0] * 301586L;
+            total += values[5] * 101587L + values[1] * 301587L;
+            total += values[6] * 101588L + values[2] * 301588L;
+            total += values[0] * 101589L + values[3] * 301589L;
+            total += values[1] * 101590L + values[4] * 301590L;
+            total += values[2] * 101591L + values[5] * 301591L;
+            total += values[3] * 101592L + values[6] * 301592L;
+            total += values[4] * 101593L + values[0] * 301593L;
+            total += values[5] * 101594L + values[1] * 301594L;
+            total += values[6] * 101595L + values[2] * 301595L;
+            total += values[0] * 101596L + values[3] * 301596L;
+            total += values[1] * 101597L + values[4] * 301597L;
+            total += values[2] * 101598L + values[5] * 301598L;
+            total += values[3] * 101599L + values[6] * 301599L;
+            total += values[4] * 101600L + values[0] * 301600L;
+            total += values[5] * 101601L + values[1] * 301601L;
+            total += values[6] * 101602L + values[2] * 301602L;
+            total += values[0] * 101603L + values[3] * 301603L;
+            total += values[1] * 101604L + values[4] * 301604L;
+            total += values[2] * 101605L + values[5] * 301605L;
+            total += values[3] * 101606L + values[6] * 301606L;
+            total += values[4] * 101607L + values[0] * 301607L;
+            total += values[5] * 101608L + values[1] * 301608L;
+            total += values[6] * 101609L + values[2] * 301609L;
+            total += values[0] * 101610L + values[3] * 301610L;
+            total += values[1] * 101611L + values[4] * 301611L;
+            total += values[2] * 101612L + values[5] * 301612L;
+            total += values[3] * 101613L + values[6] * 301613L;
+            total += values[4] * 101614L + values[0] * 301614L;
+            total += values[5] * 101615L + values[1] * 301615L;
+            total += values[6] * 101616L + values[2] * 301616L;
+            total += values[0] * 101617L + values[3] * 301617L;
+            total += values[1] * 101618L + values[4] * 301618L;
+            total += values[2] * 101619L + values[5] * 301619L;
+            total += values[3] * 101620L + values[6] * 301620L;
+            total += values[4] * 101621L + values[0] * 301621L;
+            total += values[5] * 101622L + values[1] * 301622L;
+            total += values[6] * 101623L + values[2] * 301623L;
+            total += values[0] * 101624L + values[3] * 301624L;
+            total += values[1] * 101625L + values[4] * 301625L;
+            total += values[2] * 101626L + values[5] * 301626L;
+            total += values[3] * 101627L + values[6] * 301627L;
+            total += values[4] * 101628L + values[0] * 301628L;
+            total += values[5] * 101629L + values[1] * 301629L;
+            total += values[6] * 101630L + values[2] * 301630L;
+            total += values[0] * 101631L + values[3] * 301631L;
+            total += values[1] * 101632L + values[4] * 301632L;
+            total += values[2] * 101633L + values[5] * 301633L;
+            total += values[3] * 101634L + values[6] * 301634L;
+            total += values[4] * 101635L + values[0] * 301635L;
+            total += values[5] * 101636L + values[1] * 301636L;
+            total += values[6] * 101637L + values[2] * 301637L;
+            total += values[0] * 101638L + values[3] * 301638L;
+            total += values[1] * 101639L + values[4] * 301639L;
+            total += values[2] * 101640L + values[5] * 301640L;
+            total += values[3] * 101641L + values[6] * 301641L;
+            total += values[4] * 101642L + values[0] * 301642L;
+            total += values[5] * 101643L + values[1] * 301643L;
+            total += values[6] * 101644L + values[2] * 301644L;
+            total += values[0] * 101645L + values[3] * 301645L;
+            total += values[1] * 101646L + values[4] * 301646L;
+            total += values[2] * 101647L + values[5] * 301647L;
+            total += values[3] * 101648L + values[6] * 301648L;
+            total += values[4] * 101649L + values[0] * 301649L;
+            total += values[5] * 101650L + values[1] * 301650L;
+            total += values[6] * 101651L + values[2] * 301651L;
+            total += values[0] * 101652L + values[3] * 301652L;
+            total += values[1] * 101653L + values[4] * 301653L;
+            total += values[2] * 101654L + values[5] * 301654L;
+            total += values[3] * 101655L + values[6] * 301655L;
+            total += values[4] * 101656L + values[0] * 301656L;
+            total += values[5] * 101657L + values[1] * 301657L;
+            total += values[6] * 101658L + values[2] * 301658L;
+            total += values[0] * 101659L + values[3] * 301659L;
+            total += values[1] * 101660L + values[4] * 301660L;
+            total += values[2] * 101661L + values[5] * 301661L;
+            total += values[3] * 101662L + values[6] * 301662L;
+            total += values[4] * 101663L + values[0] * 301663L;
+            total += values[5] * 101664L + values[1] * 301664L;
+            total += values[6] * 101665L + values[2] * 301665L;
+            total += values[0] * 101666L + values[3] * 301666L;
+            total += values[1] * 101667L + values[4] * 301667L;
+            total += values[2] * 101668L + values[5] * 301668L;
+            total += values[3] * 101669L + values[6] * 301669L;
+            total += values[4] * 101670L + values[0] * 301670L;
+            total += values[5] * 101671L + values[1] * 301671L;
+            total += values[6] * 101672L + values[2] * 301672L;
+            total += values[0] * 101673L + values[3] * 301673L;
+            total += values[1] * 101674L + values[4] * 301674L;
+            total += values[2] * 101675L + values[5] * 301675L;
+            total += values[3] * 101676L + values[6] * 301676L;
+            total += values[4] * 101677L + values[0] * 301677L;
+            total += values[5] * 101678L + values[1] * 301678L;
+            total += values[6] * 101679L + values[2] * 301679L;
+            total += values[0] * 101680L + values[3] * 301680L;
+            total += values[1] * 101681L + values[4] * 301681L;
+            total += values[2] * 101682L + values[5] * 301682L;
+            total += values[3] * 101683L + values[6] * 301683L;
+            total += values[4] * 101684L + values[0] * 301684L;
+            total += values[5] * 101685L + values[1] * 301685L;
+            total += values[6] * 101686L + values[2] * 301686L;
+            total += values[0] * 101687L + values[3] * 301687L;
+            total += values[1] * 101688L + values[4] * 301688L;
+            total += values[2] * 101689L + values[5] * 301689L;
+            total += values[3] * 101690L + values[6] * 301690L;
+            total += values[4] * 101691L + values[0] * 301691L;
+            total += values[5] * 101692L + values[1] * 301692L;
+            total += values[6] * 101693L + values[2] * 301693L;
+            total += values[0] * 101694L + values[3] * 301694L;
+            total += values[1] * 101695L + values[4] * 301695L;
+            total += values[2] * 101696L + values[5] * 301696L;
+            total += values[3] * 101697L + values[6] * 301697L;
+            total += values[4] * 101698L + values[0] * 301698L;
+            total += values[5] * 101699L + values[1] * 301699L;
+        }
+        return total;
+    }
+}
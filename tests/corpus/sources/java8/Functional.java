@@ -0,0 +1,76 @@
+import java.lang.annotation.ElementType;
+import java.lang.annotation.Retention;
+import java.lang.annotation.RetentionPolicy;
+import java.lang.annotation.Target;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.List;
+import java.util.function.Function;
+import java.util.function.Supplier;
+
+/** Lambdas, method references, default methods and annotations. */
+public class Functional {
+    @Retention(RetentionPolicy.RUNTIME)
+    @Target({ElementType.TYPE, ElementType.METHOD, ElementType.FIELD, ElementType.PARAMETER})
+    @interface Info {
+        String name() default "none";
+        int count() default 1;
+        long big() default 1L << 40;
+        byte b() default 7;
+        short s() default -3;
+        char c() default 'q';
+        boolean flag() default false;
+        float f() default 0.5f;
+        double d() default 0.25;
+        Class<?> type() default Object.class;
+        ElementType kind() default ElementType.TYPE;
+        Retention nested() default @Retention(RetentionPolicy.CLASS);
+        String[] tags() default {"a", "b"};
+    }
+
+    @Retention(RetentionPolicy.CLASS)
+    @interface Hidden {}
+
+    @Target({ElementType.TYPE_USE, ElementType.TYPE_PARAMETER})
+    @Retention(RetentionPolicy.RUNTIME)
+    @interface Checked {}
+
+    interface Shape {
+        double area();
+
+        default String describe() {
+            return "area " + area();
+        }
+
+        static Shape unit() {
+            return () -> 1.0;
+        }
+    }
+
+    @Info(name = "field", tags = {})
+    @Hidden
+    List<@Checked String> names = new ArrayList<>();
+
+    @Info(count = 3, type = String[].class, kind = ElementType.METHOD)
+    <@Checked T extends @Checked Comparable<T>> T max(@Info @Hidden List<? extends T> items, @Checked Comparator<? super T> order) {
+        T best = null;
+        for (T item : items) {
+            if (best == null || order.compare(item, best) > 0) {
+                best = item;
+            }
+        }
+        @Checked Object cast = (@Checked Object) best;
+        return best;
+    }
+
+    Function<String, Integer> references() {
+        Function<String, Integer> length = String::length;
+        Supplier<List<String>> factory = ArrayList::new;
+        Function<Integer, int[]> arrays = int[]::new;
+        return length.andThen(n -> n + factory.get().size() + arrays.apply(n).length);
+    }
+
+    Runnable capture(int base) {
+        return () -> System.out.println(base + names.size());
+    }
+}
@@ -0,0 +1,10 @@
+import java.util.function.*;
+public class Lambdas {
+    public static void main(String[] args) {
+        Supplier<String> s = () -> "x";
+        Function<Integer, Integer> f = i -> i + 1;
+        Runnable r = System.out::println;
+        System.out.println(s.get() + f.apply(1));
+        r.run();
+    }
+}
@@ -0,0 +1,10 @@
+/** A module declaration exercising every directive. */
+module org.example {
+    requires transitive java.logging;
+    requires static java.sql;
+    exports org.example.api;
+    exports org.example.impl to java.base;
+    opens org.example.impl;
+    uses org.example.api.Service;
+    provides org.example.api.Service with org.example.impl.DefaultService;
+}
@@ -0,0 +1,10 @@
+package org.example.impl;
+
+import org.example.api.Service;
+
+public class DefaultService implements Service {
+    @Override
+    public String name() {
+        return "default";
+    }
+}
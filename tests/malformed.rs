@@ -0,0 +1,190 @@
+//! Negative tests: `Custom.class` from the corpus, damaged one byte at a
+//! time. Its layout, by offset:
+//!
+//! - 8: `constant_pool_count`, 16
+//! - 138: `access_flags`, then `this_class` at 140
+//! - 166: the `Code` attribute of method 0, 29 bytes long from 172
+//! - 189: its `LineNumberTable`, 6 bytes long from 195
+//! - 203: the `SourceFile` attribute, 2 bytes long from 209
+//! - 213: the `Custom` attribute, 23 bytes long from 219
+
+use std::fs;
+
+use class_file_parser::{
+    constant_pool::ConstantPoolTag, parse, parse_with_mode, Attribute, ClassFileError, ErrorKind,
+    Mode,
+};
+
+fn custom() -> Vec<u8> {
+    fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/corpus/classes/crafted/Custom.class"
+    ))
+    .unwrap()
+}
+
+fn patched(at: usize, byte: u8) -> Vec<u8> {
+    let mut bytes = custom();
+    bytes[at] = byte;
+    bytes
+}
+
+fn error(bytes: &[u8], mode: Mode) -> ClassFileError {
+    parse_with_mode(bytes, mode).unwrap_err()
+}
+
+fn error_at(kind: ErrorKind, offset: usize, path: &[&str]) -> ClassFileError {
+    ClassFileError {
+        kind,
+        offset,
+        path: path.iter().map(|segment| segment.to_string()).collect(),
+    }
+}
+
+#[test]
+fn every_truncation_fails() {
+    let bytes = custom();
+    for len in 0..bytes.len() {
+        for mode in [Mode::Strict, Mode::Lenient] {
+            let err = error(&bytes[..len], mode);
+            assert_eq!(err.kind, ErrorKind::Truncated, "{} bytes", len);
+            assert!(err.offset <= len, "{} bytes: {}", len, err);
+        }
+    }
+    assert_eq!(
+        error(&bytes[..100], Mode::Strict),
+        error_at(ErrorKind::Truncated, 87, &["constant pool entry #12"])
+    );
+}
+
+#[test]
+fn bad_magic_and_trailing_bytes_fail() {
+    assert_eq!(
+        error(&patched(3, 0xbf), Mode::Strict),
+        error_at(ErrorKind::BadMagic(0xcafe_babf), 0, &[])
+    );
+    let mut bytes = custom();
+    bytes.push(0);
+    assert_eq!(
+        error(&bytes, Mode::Lenient),
+        error_at(ErrorKind::TrailingBytes, 240, &[])
+    );
+}
+
+#[test]
+fn bad_attribute_names_fail_while_parsing() {
+    assert_eq!(
+        error(&patched(204, 99), Mode::Lenient),
+        error_at(ErrorKind::BadConstantPoolIndex(99), 203, &[])
+    );
+    assert_eq!(
+        error(&patched(204, 2), Mode::Lenient),
+        error_at(
+            ErrorKind::WrongConstantPoolEntry {
+                index: 2,
+                expected: ConstantPoolTag::Utf8,
+                found: ConstantPoolTag::Class,
+            },
+            203,
+            &[]
+        )
+    );
+}
+
+#[test]
+fn bad_indices_elsewhere_fail_when_resolved() {
+    let bytes = patched(141, 99);
+    let class_file = parse(&bytes).unwrap();
+    assert_eq!(
+        class_file.view().name().unwrap_err(),
+        ErrorKind::BadConstantPoolIndex(99)
+    );
+    let bytes = patched(141, 8);
+    let class_file = parse(&bytes).unwrap();
+    assert_eq!(
+        class_file.view().name().unwrap_err(),
+        ErrorKind::WrongConstantPoolEntry {
+            index: 8,
+            expected: ConstantPoolTag::Class,
+            found: ConstantPoolTag::Utf8,
+        }
+    );
+}
+
+#[test]
+fn attributes_must_match_their_length() {
+    assert_eq!(
+        error(&patched(208, 3), Mode::Strict),
+        error_at(
+            ErrorKind::AttributeUnderread {
+                declared: 3,
+                consumed: 2,
+            },
+            211,
+            &["SourceFile"]
+        )
+    );
+    assert_eq!(
+        error(&patched(208, 1), Mode::Strict),
+        error_at(
+            ErrorKind::AttributeOverread { declared: 1 },
+            209,
+            &["SourceFile"]
+        )
+    );
+    assert_eq!(
+        error(&patched(194, 4), Mode::Strict),
+        error_at(
+            ErrorKind::AttributeOverread { declared: 4 },
+            199,
+            &["method 0", "Code", "LineNumberTable"]
+        )
+    );
+    assert_eq!(
+        error(&patched(171, 0x1f), Mode::Strict),
+        error_at(
+            ErrorKind::AttributeUnderread {
+                declared: 31,
+                consumed: 29,
+            },
+            201,
+            &["method 0", "Code"]
+        )
+    );
+}
+
+#[test]
+fn lenient_parsing_resumes_after_a_bad_length() {
+    let bytes = patched(194, 4);
+    let (class_file, warnings) = parse_with_mode(&bytes, Mode::Lenient).unwrap();
+    assert_eq!(
+        warnings,
+        [
+            error_at(
+                ErrorKind::AttributeOverread { declared: 4 },
+                199,
+                &["method 0", "Code", "LineNumberTable"]
+            ),
+            error_at(
+                ErrorKind::AttributeUnderread {
+                    declared: 29,
+                    consumed: 27,
+                },
+                199,
+                &["method 0", "Code"]
+            ),
+        ]
+    );
+
+    // Everything after the damaged attribute is intact.
+    let code = class_file.view().methods().next().unwrap().code().unwrap();
+    assert_eq!(code.code, [0x2a, 0xb7, 0x00, 0x01, 0xb1]);
+    assert_eq!(
+        class_file.view().source_file().unwrap().unwrap(),
+        "Simple.java"
+    );
+    match &class_file.attributes[1] {
+        Attribute::Unknown { info, .. } => assert_eq!(info.len(), 23),
+        attribute => panic!("expected the Custom attribute, found {:?}", attribute),
+    }
+}
@@ -0,0 +1,166 @@
+use std::{borrow::Cow, fs};
+
+use class_file_parser::{descriptor::FieldType, parse, ClassFile, Constant};
+
+fn read(path: &str) -> Vec<u8> {
+    fs::read(format!(
+        "{}/tests/corpus/classes/release-17/{}.class",
+        env!("CARGO_MANIFEST_DIR"),
+        path
+    ))
+    .unwrap()
+}
+
+fn object(name: &str) -> FieldType {
+    FieldType::Object(name.to_string())
+}
+
+#[test]
+fn classes_name_their_supertypes() {
+    let bytes = read("java7/Basics");
+    let mut class_file = parse(&bytes).unwrap();
+    let class = class_file.view();
+    assert_eq!(class.name().unwrap(), "Basics");
+    assert_eq!(
+        class.super_name().unwrap().unwrap(),
+        "java/util/AbstractList"
+    );
+    assert_eq!(
+        class.interface_names().unwrap(),
+        ["java/io/Serializable", "java/lang/Cloneable"]
+    );
+    assert_eq!(class.source_file().unwrap().unwrap(), "Basics.java");
+    let annotations = class.annotations();
+    assert_eq!(annotations.len(), 1);
+    assert_eq!(
+        annotations[0].descriptor().unwrap(),
+        object("java/lang/Deprecated")
+    );
+    assert!(annotations[0].is_visible());
+
+    // Only java/lang/Object and modules have no superclass.
+    class_file.super_class = 0;
+    assert_eq!(class_file.view().super_name().unwrap(), None);
+    let bytes = read("module/module-info");
+    assert_eq!(parse(&bytes).unwrap().view().super_name().unwrap(), None);
+}
+
+#[test]
+fn methods_are_found_by_name_and_descriptor() {
+    let bytes = read("java7/Basics");
+    let class_file = parse(&bytes).unwrap();
+    let class = class_file.view();
+    let method = class
+        .method("arithmetic", "(JFDLjava/lang/Object;)J")
+        .unwrap()
+        .unwrap();
+    assert_eq!(method.name().unwrap(), "arithmetic");
+    assert_eq!(method.descriptor().unwrap().param_slots(), 6);
+    assert_eq!(method.exceptions().unwrap(), ["java/lang/Exception"]);
+    assert!(class.method("arithmetic", "()J").unwrap().is_none());
+    assert!(class.method("missing", "()V").unwrap().is_none());
+
+    // The erasure of a thrown type variable is listed.
+    let thrower = class.method("thrower", "()V").unwrap().unwrap();
+    assert_eq!(
+        thrower.exceptions().unwrap(),
+        ["java/lang/Exception", "java/io/IOException"]
+    );
+    assert!(class
+        .method("strict", "(D)D")
+        .unwrap()
+        .unwrap()
+        .exceptions()
+        .unwrap()
+        .is_empty());
+
+    // Abstract and native methods have no code.
+    assert!(class
+        .method("nat", "()V")
+        .unwrap()
+        .unwrap()
+        .code()
+        .is_none());
+}
+
+#[test]
+fn finally_blocks_catch_everything() {
+    let bytes = read("java7/Basics");
+    let class_file = parse(&bytes).unwrap();
+    let code = class_file
+        .view()
+        .method("arithmetic", "(JFDLjava/lang/Object;)J")
+        .unwrap()
+        .unwrap()
+        .code()
+        .unwrap();
+    let handlers = code.handlers().unwrap();
+    let summary = handlers
+        .iter()
+        .map(|handler| {
+            (
+                handler.start_pc,
+                handler.end_pc,
+                handler.handler_pc,
+                handler.catch_type.as_deref(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (96, 113, 122, Some("java/lang/ArithmeticException")),
+            (96, 113, 122, Some("java/lang/IllegalStateException")),
+            (96, 113, 138, None),
+            (122, 129, 138, None),
+            (138, 140, 138, None),
+            (154, 165, 168, None),
+            (168, 173, 168, None),
+        ]
+    );
+}
+
+#[test]
+fn annotations_know_their_retention() {
+    let bytes = read("java8/Functional");
+    let class_file = parse(&bytes).unwrap();
+    let field = class_file.view().field("names").unwrap().unwrap();
+    let annotations = field.annotations();
+    let summary = annotations
+        .iter()
+        .map(|annotation| (annotation.descriptor().unwrap(), annotation.is_visible()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (object("Functional$Info"), true),
+            (object("Functional$Hidden"), false),
+        ]
+    );
+
+    let elements = annotations[0].elements().unwrap();
+    let names = elements
+        .iter()
+        .map(|(name, _)| name.as_ref())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["name", "tags"]);
+    assert_eq!(
+        elements[0].1.constant(&class_file.constant_pool).unwrap(),
+        Some(Constant::String(Cow::Borrowed("field")))
+    );
+    assert_eq!(
+        elements[1].1.constant(&class_file.constant_pool).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn views_of_owned_class_files_match() {
+    let bytes = read("java7/Basics");
+    let class_file: ClassFile<'static> = parse(&bytes).unwrap().into_owned();
+    drop(bytes);
+    let class = class_file.view();
+    assert_eq!(class.name().unwrap(), "Basics");
+    assert_eq!(class.fields().count(), class_file.fields.len());
+    assert_eq!(class.methods().count(), class_file.methods.len());
+}